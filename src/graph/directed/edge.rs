@@ -0,0 +1,59 @@
+use crate::graph::edge::{Edge, EdgeConstructor};
+
+///
+/// edge oriented from vertex from to vertex to
+///
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Ord, PartialOrd)]
+pub struct DirectedEdge {
+    from: usize,
+    to: usize,
+    color: u8,
+}
+
+impl Edge for DirectedEdge {
+    fn from(&self) -> usize {
+        self.from
+    }
+
+    fn to(&self) -> usize {
+        self.to
+    }
+
+    fn color(&self) -> u8 {
+        self.color
+    }
+
+    fn set_color(&mut self, color: u8) {
+        self.color = color;
+    }
+}
+
+impl EdgeConstructor for DirectedEdge {
+    fn new(from: usize, to: usize) -> Self {
+        DirectedEdge { from, to, color: 0 }
+    }
+
+    fn new_with_colour(from: usize, to: usize, colour: u8) -> Self {
+        DirectedEdge {
+            from,
+            to,
+            color: colour,
+        }
+    }
+}
+
+impl<'a> Edge for &'a DirectedEdge {
+    fn from(&self) -> usize {
+        (*self).from()
+    }
+
+    fn to(&self) -> usize {
+        (*self).to()
+    }
+
+    fn color(&self) -> u8 {
+        (*self).color()
+    }
+
+    fn set_color(&mut self, _color: u8) {}
+}
@@ -1 +1,30 @@
+use crate::graph::directed::edge::DirectedEdge;
+use crate::graph::graph::Graph;
 
+pub mod edge;
+pub mod orientation;
+pub mod simple_graph;
+pub mod vertex;
+
+///
+/// Graph with oriented edges - edges_of_vertex and neighbors_of_vertex of Graph give edges
+/// starting in vertex and their end vertices
+///
+pub trait DirectedGraph: Graph<E = DirectedEdge> {
+    fn in_edges_of_vertex<'a>(
+        &'a self,
+        vertex: usize,
+    ) -> Box<dyn Iterator<Item = &'a DirectedEdge> + 'a>;
+    fn in_neighbors_of_vertex(&self, vertex: usize) -> Vec<usize>;
+
+    fn out_degree(&self, vertex: usize) -> usize {
+        self.edges_of_vertex(vertex).count()
+    }
+
+    fn in_degree(&self, vertex: usize) -> usize {
+        self.in_edges_of_vertex(vertex).count()
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,127 @@
+use crate::graph::directed::simple_graph::graph::SimpleDirectedGraph;
+use crate::graph::directed::DirectedGraph;
+use crate::graph::edge::Edge;
+use crate::graph::graph::{Graph, GraphConstructor};
+use crate::graph::vertex::Vertex;
+use std::collections::HashSet;
+
+///
+/// Orientation of undirected graph - each edge {from, to} (from < to) is oriented from -> to if
+/// forward(from, to) is true, to -> from otherwise. All vertices of graph are kept.
+///
+pub fn orient<G, F>(graph: &G, forward: F) -> SimpleDirectedGraph
+where
+    G: Graph,
+    F: Fn(usize, usize) -> bool,
+{
+    let mut directed = empty_with_vertices_of(graph);
+    for edge in graph.edges() {
+        let (from, to) = normalized(edge.from(), edge.to());
+        if forward(from, to) {
+            directed.add_edge(from, to);
+        } else {
+            directed.add_edge(to, from);
+        }
+    }
+    directed
+}
+
+///
+/// each edge oriented from its smaller to its bigger vertex - acyclic orientation
+///
+pub fn default_orientation<G: Graph>(graph: &G) -> SimpleDirectedGraph {
+    orient(graph, |_from, _to| true)
+}
+
+///
+/// Strongly connected orientation of graph (Robbins) - DFS tree edges are oriented away from
+/// root, other edges towards root. Exists only for connected bridgeless graphs, None otherwise.
+///
+pub fn strong_orientation<G: Graph>(graph: &G) -> Option<SimpleDirectedGraph> {
+    let mut directed = empty_with_vertices_of(graph);
+    let root = match graph.vertices().next() {
+        Some(vertex) => vertex.index(),
+        None => return Some(directed),
+    };
+
+    let mut visited = vec![false; graph.size()];
+    let mut oriented = HashSet::new();
+    // vertex and its neighbors not processed yet
+    let mut stack = vec![(root, graph.neighbors_of_vertex(root))];
+    visited[root] = true;
+    while let Some((vertex, neighbors)) = stack.last_mut() {
+        let vertex = *vertex;
+        let neighbor = match neighbors.pop() {
+            Some(neighbor) => neighbor,
+            None => {
+                stack.pop();
+                continue;
+            }
+        };
+        if !oriented.insert(normalized(vertex, neighbor)) {
+            continue;
+        }
+        // tree edge leads to new vertex, back edge leads to ancestor
+        directed.add_edge(vertex, neighbor);
+        if !visited[neighbor] {
+            visited[neighbor] = true;
+            stack.push((neighbor, graph.neighbors_of_vertex(neighbor)));
+        }
+    }
+
+    if is_strongly_connected(&directed) {
+        return Some(directed);
+    }
+    None
+}
+
+///
+/// every vertex is reachable from every other vertex
+///
+pub fn is_strongly_connected<G: DirectedGraph>(graph: &G) -> bool {
+    let root = match graph.vertices().next() {
+        Some(vertex) => vertex.index(),
+        None => return true,
+    };
+    let vertices = graph.vertices().count();
+    let out_reachable = reachable_from(root, graph.size(), |vertex| {
+        graph.neighbors_of_vertex(vertex)
+    });
+    let in_reachable = reachable_from(root, graph.size(), |vertex| {
+        graph.in_neighbors_of_vertex(vertex)
+    });
+    out_reachable == vertices && in_reachable == vertices
+}
+
+fn reachable_from<F: Fn(usize) -> Vec<usize>>(root: usize, size: usize, neighbors: F) -> usize {
+    let mut visited = vec![false; size];
+    visited[root] = true;
+    let mut reachable = 1;
+    let mut to_visit = vec![root];
+    while let Some(vertex) = to_visit.pop() {
+        for neighbor in neighbors(vertex) {
+            if !visited[neighbor] {
+                visited[neighbor] = true;
+                reachable += 1;
+                to_visit.push(neighbor);
+            }
+        }
+    }
+    reachable
+}
+
+fn empty_with_vertices_of<G: Graph>(graph: &G) -> SimpleDirectedGraph {
+    let mut directed = SimpleDirectedGraph::with_vertices_capacity(graph.size());
+    for vertex in graph.vertices() {
+        directed.add_vertex_with_index(vertex.index());
+    }
+    directed
+}
+
+fn normalized(from: usize, to: usize) -> (usize, usize) {
+    if from > to {
+        (to, from)
+    } else {
+        (from, to)
+    }
+}
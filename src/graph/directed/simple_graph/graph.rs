@@ -0,0 +1,241 @@
+use crate::graph::directed::edge::DirectedEdge;
+use crate::graph::directed::vertex::DirectedVertex;
+use crate::graph::directed::DirectedGraph;
+use crate::graph::edge::Edge;
+use crate::graph::graph::{Graph, GraphConstructor};
+use crate::graph::vertex::{Vertex, VertexConstructor};
+use std::fmt;
+
+///
+/// directed, without loops or multiple edges with same orientation (edges (u, v) and (v, u) can be
+/// both present)
+///
+#[derive(Debug, Clone)]
+pub struct SimpleDirectedGraph {
+    pub vertices: Vec<DirectedVertex>,
+}
+
+impl DirectedGraph for SimpleDirectedGraph {
+    fn in_edges_of_vertex<'a>(
+        &'a self,
+        vertex: usize,
+    ) -> Box<dyn Iterator<Item = &'a DirectedEdge> + 'a> {
+        Box::new(self.vertices[vertex].in_edges.iter())
+    }
+
+    fn in_neighbors_of_vertex(&self, vertex: usize) -> Vec<usize> {
+        if vertex >= self.vertices.len() {
+            return vec![];
+        }
+        self.vertices[vertex].in_neighbors()
+    }
+}
+
+impl Graph for SimpleDirectedGraph {
+    type V = DirectedVertex;
+    type E = DirectedEdge;
+
+    fn size(&self) -> usize {
+        self.vertices.len()
+    }
+
+    fn has_edge(&self, from: usize, to: usize) -> bool {
+        if from >= self.vertices.len() || to >= self.vertices.len() {
+            return false;
+        }
+        self.vertices[from]
+            .out_edges
+            .iter()
+            .any(|edge| edge.to() == to)
+    }
+
+    fn add_vertex(&mut self) {
+        self.vertices.push(DirectedVertex::new(self.vertices.len()));
+    }
+
+    fn add_edge(&mut self, from: usize, to: usize) {
+        if from == to {
+            return;
+        }
+        if self.has_edge(from, to) {
+            return;
+        }
+        while self.vertices.len() <= from.max(to) {
+            self.add_non_active_vertex();
+        }
+        let from_vertex = &mut self.vertices[from];
+        from_vertex.add_out_edge(to, 0);
+        from_vertex.set_active(true);
+        let to_vertex = &mut self.vertices[to];
+        to_vertex.add_in_edge(from, 0);
+        to_vertex.set_active(true);
+    }
+
+    fn remove_edge(&mut self, from: usize, to: usize) {
+        if from >= self.vertices.len() || to >= self.vertices.len() {
+            return;
+        }
+        self.vertices[from].out_edges.retain(|edge| edge.to() != to);
+        self.vertices[to]
+            .in_edges
+            .retain(|edge| edge.from() != from);
+    }
+
+    ///
+    /// removes edges starting and ending in vertex
+    ///
+    fn remove_edges_of_vertex(&mut self, vertex: usize) {
+        if vertex >= self.vertices.len() {
+            return;
+        }
+        for to in self.vertices[vertex].out_neighbors() {
+            self.vertices[to]
+                .in_edges
+                .retain(|edge| edge.from() != vertex);
+        }
+        for from in self.vertices[vertex].in_neighbors() {
+            self.vertices[from]
+                .out_edges
+                .retain(|edge| edge.to() != vertex);
+        }
+        self.vertices[vertex].out_edges = vec![];
+        self.vertices[vertex].in_edges = vec![];
+    }
+
+    fn remove_vertex(&mut self, vertex_index: usize) {
+        self.remove_edges_of_vertex(vertex_index)
+    }
+
+    fn vertices<'a>(&'a self) -> Box<dyn Iterator<Item = &'a DirectedVertex> + 'a> {
+        Box::new(self.vertices.iter().filter(|vertex| vertex.active()))
+    }
+
+    fn edges<'a>(&'a self) -> Box<dyn Iterator<Item = &'a DirectedEdge> + 'a> {
+        Box::new(
+            self.vertices
+                .iter()
+                .flat_map(|vertex| vertex.out_edges.iter()),
+        )
+    }
+
+    ///
+    /// edges starting in vertex
+    ///
+    fn edges_of_vertex<'a>(
+        &'a self,
+        vertex: usize,
+    ) -> Box<dyn Iterator<Item = &'a DirectedEdge> + 'a> {
+        Box::new(self.vertices[vertex].out_edges.iter())
+    }
+
+    ///
+    /// end vertices of edges starting in vertex
+    ///
+    fn neighbors_of_vertex(&self, vertex: usize) -> Vec<usize> {
+        if vertex >= self.vertices.len() {
+            return vec![];
+        }
+        self.vertices[vertex].out_neighbors()
+    }
+}
+
+impl GraphConstructor for SimpleDirectedGraph {
+    fn new() -> Self {
+        Self::with_vertices_capacity(20)
+    }
+
+    fn with_capacity(vertices: usize, _edges: usize) -> Self {
+        Self::with_vertices_capacity(vertices)
+    }
+
+    fn with_vertices_capacity(vertices: usize) -> Self {
+        SimpleDirectedGraph {
+            vertices: Vec::with_capacity(vertices),
+        }
+    }
+}
+
+impl SimpleDirectedGraph {
+    pub fn add_vertex_with_index(&mut self, vertex: usize) {
+        while self.size() < vertex + 1 {
+            self.add_non_active_vertex();
+        }
+        self.vertices[vertex].set_active(true);
+    }
+
+    pub fn has_vertex(&self, vertex: usize) -> bool {
+        if vertex >= self.size() {
+            return false;
+        }
+        self.vertices[vertex].active()
+    }
+
+    pub fn first_vertex(&self) -> Option<&DirectedVertex> {
+        self.vertices().next()
+    }
+
+    #[allow(dead_code)]
+    pub fn vertex(&self, index: usize) -> Option<&DirectedVertex> {
+        if !self.has_vertex(index) {
+            return None;
+        }
+        Some(&self.vertices[index])
+    }
+
+    pub fn remove_vertex(&mut self, vertex: usize) {
+        if vertex >= self.vertices.len() {
+            return;
+        }
+        self.remove_edges_of_vertex(vertex);
+        self.vertices[vertex].set_active(false);
+    }
+
+    ///
+    /// same graph with all edges reversed
+    ///
+    pub fn reversed(&self) -> Self {
+        let mut reversed = SimpleDirectedGraph::with_vertices_capacity(self.size());
+        for vertex in self.vertices() {
+            reversed.add_vertex_with_index(vertex.index());
+        }
+        for edge in self.edges() {
+            reversed.add_edge(edge.to(), edge.from());
+        }
+        reversed
+    }
+
+    fn add_non_active_vertex(&mut self) {
+        self.vertices
+            .push(DirectedVertex::new_non_active(self.vertices.len()));
+    }
+}
+
+impl fmt::Display for SimpleDirectedGraph {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for vertex in &self.vertices {
+            write!(f, "{}: ", vertex.index())?;
+            let mut separ = String::from("");
+            for neighbor in vertex.out_neighbors() {
+                write!(f, "{}{}", separ, neighbor)?;
+                separ = String::from(", ");
+            }
+
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+impl PartialEq for SimpleDirectedGraph {
+    fn eq(&self, other: &Self) -> bool {
+        if self.size() != other.size() {
+            return false;
+        }
+        if self.vertices[..] != other.vertices[..] {
+            return false;
+        }
+        true
+    }
+}
+
+impl Eq for SimpleDirectedGraph {}
@@ -0,0 +1,4 @@
+pub mod graph;
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,114 @@
+use crate::graph::directed::edge::DirectedEdge;
+use crate::graph::directed::simple_graph::graph::SimpleDirectedGraph;
+use crate::graph::directed::DirectedGraph;
+use crate::graph::edge::EdgeConstructor;
+use crate::graph::graph::{Graph, GraphConstructor};
+use crate::graph::vertex::Vertex;
+
+fn get_graph() -> SimpleDirectedGraph {
+    let mut graph = SimpleDirectedGraph::with_capacity(3, 4);
+    graph.add_vertex();
+    graph.add_vertex();
+    graph.add_vertex();
+
+    graph.add_edge(2, 0);
+    graph.add_edge(2, 1);
+    graph.add_edge(0, 1);
+    graph.add_edge(1, 0);
+    graph
+}
+
+#[test]
+fn simple_directed_graph() {
+    let mut graph = SimpleDirectedGraph::with_vertices_capacity(10);
+
+    graph.add_edge(0, 1);
+    graph.add_edge(2, 5);
+    graph.add_edge(2, 4);
+    graph.add_edge(2, 4);
+    graph.add_edge(3, 3);
+
+    assert_eq!(graph.has_edge(0, 1), true);
+    assert_eq!(graph.has_edge(1, 0), false);
+    assert_eq!(graph.has_edge(2, 4), true);
+    assert_eq!(graph.has_edge(4, 2), false);
+    assert_eq!(graph.has_edge(2, 6), false);
+    assert_eq!(graph.edges().count(), 3);
+
+    assert_eq!(graph.size(), 6);
+}
+
+#[test]
+fn should_iter_edges() {
+    let graph = get_graph();
+    let mut edges = vec![];
+    edges.push(DirectedEdge::new(0, 1));
+    edges.push(DirectedEdge::new(1, 0));
+    edges.push(DirectedEdge::new(2, 0));
+    edges.push(DirectedEdge::new(2, 1));
+
+    let mut index = 0;
+    for edge in graph.edges() {
+        edges.retain(|edge_temp| edge_temp != edge);
+        index += 1;
+    }
+    assert_eq!(index, 4);
+    assert_eq!(edges.len(), 0);
+}
+
+#[test]
+fn should_iter_in_and_out_edges_of_vertex() {
+    let graph = get_graph();
+    assert_eq!(
+        graph.edges_of_vertex(1).collect::<Vec<_>>(),
+        vec![&DirectedEdge::new(1, 0)]
+    );
+    assert_eq!(
+        graph.in_edges_of_vertex(1).collect::<Vec<_>>(),
+        vec![&DirectedEdge::new(0, 1), &DirectedEdge::new(2, 1)]
+    );
+    assert_eq!(graph.neighbors_of_vertex(2), vec![0, 1]);
+    assert_eq!(graph.in_neighbors_of_vertex(0), vec![1, 2]);
+    assert_eq!(graph.out_degree(2), 2);
+    assert_eq!(graph.in_degree(2), 0);
+}
+
+#[test]
+fn should_remove_edges_of_vertex() {
+    let mut graph = get_graph();
+    graph.remove_edge(2, 1);
+    assert_eq!(graph.has_edge(2, 1), false);
+    assert_eq!(graph.in_degree(1), 1);
+
+    graph.remove_edges_of_vertex(0);
+    assert_eq!(graph.edges().count(), 0);
+    assert_eq!(graph.in_degree(1), 0);
+    assert_eq!(graph.out_degree(2), 0);
+}
+
+#[test]
+fn should_have_first_vertex() {
+    let graph = get_graph();
+    let first_vertex = graph.first_vertex();
+    assert_eq!(first_vertex.is_some(), true);
+    assert_eq!(first_vertex.unwrap().index(), 0);
+
+    let mut graph = SimpleDirectedGraph::new();
+    assert_eq!(graph.first_vertex().is_none(), true);
+
+    graph.add_vertex();
+    graph.add_vertex();
+    graph.remove_vertex(0);
+    assert_eq!(graph.first_vertex().unwrap().index(), 1);
+    assert_eq!(graph.has_vertex(0), false);
+}
+
+#[test]
+fn should_reverse_edges() {
+    let graph = get_graph();
+    let reversed = graph.reversed();
+    assert_eq!(reversed.edges().count(), 4);
+    assert_eq!(reversed.has_edge(0, 2), true);
+    assert_eq!(reversed.has_edge(2, 0), false);
+    assert_eq!(reversed.reversed(), graph);
+}
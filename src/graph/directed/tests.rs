@@ -0,0 +1,55 @@
+use crate::graph::directed::orientation;
+use crate::graph::directed::DirectedGraph;
+use crate::graph::graph::{Graph, GraphConstructor};
+use crate::graph::undirected::simple_graph::graph::SimpleGraph;
+use crate::service::io::reader_g6::G6Reader;
+use crate::tests::test_data::test_data;
+
+#[test]
+fn should_orient_each_edge_once() {
+    let graph = test_data::get_petersen_graph();
+    let directed = orientation::orient(&graph, |from, to| (from + to) % 2 == 0);
+    assert_eq!(directed.size(), 10);
+    assert_eq!(directed.edges().count(), 15);
+    for from in 0..10 {
+        for to in (from + 1)..10 {
+            let expected = graph.has_edge(from, to);
+            let forward = (from + to) % 2 == 0;
+            assert_eq!(directed.has_edge(from, to), expected && forward);
+            assert_eq!(directed.has_edge(to, from), expected && !forward);
+        }
+    }
+
+    let acyclic = orientation::default_orientation(&graph);
+    assert_eq!(acyclic.in_degree(0), 0);
+    assert_eq!(orientation::is_strongly_connected(&acyclic), false);
+}
+
+#[test]
+fn should_find_strong_orientation_of_bridgeless_graph() {
+    for graph in vec![
+        test_data::get_petersen_graph(),
+        G6Reader::<SimpleGraph>::read_graph(test_data::NO_SNARK_IN_G6_18).unwrap(),
+    ] {
+        let directed = orientation::strong_orientation(&graph).unwrap();
+        assert_eq!(directed.edges().count(), graph.edges().count());
+        assert_eq!(orientation::is_strongly_connected(&directed), true);
+    }
+}
+
+#[test]
+fn should_not_find_strong_orientation_of_graph_with_bridge() {
+    // two triangles joined by bridge 2-3
+    let mut graph = SimpleGraph::with_capacity(6, 7);
+    graph.add_edge(0, 1);
+    graph.add_edge(1, 2);
+    graph.add_edge(2, 0);
+    graph.add_edge(2, 3);
+    graph.add_edge(3, 4);
+    graph.add_edge(4, 5);
+    graph.add_edge(5, 3);
+    assert_eq!(orientation::strong_orientation(&graph).is_none(), true);
+
+    graph.remove_edge(2, 3);
+    assert_eq!(orientation::strong_orientation(&graph).is_none(), true);
+}
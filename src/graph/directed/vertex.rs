@@ -0,0 +1,69 @@
+use crate::graph::directed::edge::DirectedEdge;
+use crate::graph::edge::{Edge, EdgeConstructor};
+use crate::graph::vertex::{Vertex, VertexConstructor};
+
+#[derive(Debug, Hash, Eq, PartialEq, Clone)]
+pub struct DirectedVertex {
+    index: usize,
+    active: bool,
+    // edges starting in vertex
+    pub out_edges: Vec<DirectedEdge>,
+    // edges ending in vertex
+    pub in_edges: Vec<DirectedEdge>,
+}
+
+impl Vertex for DirectedVertex {
+    fn index(&self) -> usize {
+        self.index
+    }
+}
+
+impl VertexConstructor for DirectedVertex {
+    fn new(index: usize) -> Self {
+        DirectedVertex {
+            index,
+            active: true,
+            out_edges: vec![],
+            in_edges: vec![],
+        }
+    }
+}
+
+impl DirectedVertex {
+    pub fn new_non_active(index: usize) -> Self {
+        DirectedVertex {
+            index,
+            active: false,
+            out_edges: vec![],
+            in_edges: vec![],
+        }
+    }
+
+    pub fn add_out_edge(&mut self, to: usize, colour: u8) {
+        self.out_edges
+            .push(DirectedEdge::new_with_colour(self.index, to, colour));
+        self.out_edges.sort();
+    }
+
+    pub fn add_in_edge(&mut self, from: usize, colour: u8) {
+        self.in_edges
+            .push(DirectedEdge::new_with_colour(from, self.index, colour));
+        self.in_edges.sort();
+    }
+
+    pub fn out_neighbors(&self) -> Vec<usize> {
+        self.out_edges.iter().map(|edge| edge.to()).collect()
+    }
+
+    pub fn in_neighbors(&self) -> Vec<usize> {
+        self.in_edges.iter().map(|edge| edge.from()).collect()
+    }
+
+    pub fn active(&self) -> bool {
+        self.active
+    }
+
+    pub fn set_active(&mut self, active: bool) {
+        self.active = active;
+    }
+}
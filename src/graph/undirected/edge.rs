@@ -17,7 +17,7 @@ impl Edge for UndirectedEdge {
     }
 
     fn color(&self) -> u8 {
-        unimplemented!()
+        self.color
     }
 
     fn set_color(&mut self, color: u8) {
@@ -149,6 +149,13 @@ impl MultiGraph {
         result
     }
 
+    pub fn add_vertex_with_index(&mut self, vertex: usize) {
+        while self.size() < vertex + 1 {
+            self.add_non_active_vertex();
+        }
+        self.vertices[vertex].set_active(true);
+    }
+
     ///
     /// for now has_vertex for vertex v is true if graph contains edge connected to vertex v
     ///
@@ -2,11 +2,17 @@ use std::time::Instant;
 
 use structopt::StructOpt;
 
+use crate::graph::graph::GraphConstructor;
+use crate::graph::undirected::multi_graph::graph::MultiGraph;
+use crate::graph::undirected::simple_edge_graph::graph::SimpleEdgeGraph;
 use crate::graph::undirected::simple_graph::graph::SimpleGraph;
+use crate::graph::undirected::UndirectedGraph;
+use crate::procedure::configuration;
 use crate::procedure::configuration::Configuration;
 use crate::procedure::procedure::GraphProperties;
 use crate::procedure::procedure_chain::ProcedureChain;
 use crate::procedure::procedure_registry::ProcedureRegistry;
+use crate::procedure::run_report::RunReport;
 
 mod graph;
 mod procedure;
@@ -21,6 +27,9 @@ struct Cli {
     /// The path to the configuration file - e.g. 'snark-tool.yml'
     #[structopt(parse(from_os_str))]
     config_file_path: std::path::PathBuf,
+    /// Resume interrupted run - graphs found in checkpoint files of procedures are skipped
+    #[structopt(long)]
+    resume: bool,
 }
 
 fn parse_yaml_config(source: &String) -> Configuration {
@@ -31,6 +40,54 @@ fn parse_yaml_config(source: &String) -> Configuration {
     config
 }
 
+fn run<G: UndirectedGraph + GraphConstructor + Clone + 'static>(
+    config: Configuration,
+    begin: Instant,
+) {
+    let registry = ProcedureRegistry::new_basic();
+    // add builder of own procedure impl to registry as shown below
+    // registry.insert("read".to_string(), ReadProcedureBuilder{});
+
+    let chain = ProcedureChain::from_procedures_config(registry, config.procedures);
+    if chain.is_err() {
+        eprintln!("Error: {}", chain.err().unwrap());
+        return;
+    }
+
+    let mut chain = chain.unwrap();
+    let result = match config.streaming {
+        Some(streaming) => chain.run_streaming(streaming.chunk_size),
+        None => {
+            let mut graphs_with_properties: Vec<(G, GraphProperties)> = vec![];
+            chain.run(&mut graphs_with_properties)
+        }
+    };
+    let mut errors = vec![];
+    match result {
+        Err(error) => {
+            eprintln!("Error: {}", error);
+            errors.push(format!("{}", error));
+        }
+        Ok(()) => {}
+    }
+    let procedures = chain.reports();
+    for procedure in procedures.iter() {
+        println!("{}: {}ms", procedure.proc_type, procedure.duration_ms());
+    }
+    println!("elapsed: {}ms", begin.elapsed().as_millis());
+
+    if let Some(report_config) = config.report {
+        let report = RunReport::new(procedures, begin.elapsed(), errors);
+        let written = match report_config.file {
+            Some(file) => report.write_to_file(&file),
+            None => report.to_json_string().map(|report| println!("{}", report)),
+        };
+        if let Err(error) = written {
+            eprintln!("Error: {}", error);
+        }
+    }
+}
+
 fn main() {
     let args = Cli::from_args();
 
@@ -40,27 +97,22 @@ fn main() {
 
             let config_str =
                 std::fs::read_to_string(&args.config_file_path).expect("could not read file");
-            let config = parse_yaml_config(&config_str);
-
-            let registry = ProcedureRegistry::new_basic();
-            // add builder of own procedure impl to registry as shown below
-            // registry.insert("read".to_string(), ReadProcedureBuilder{});
-
-            let chain = ProcedureChain::from_procedures_config(registry, config.procedures);
-            if chain.is_err() {
-                eprintln!("Error: {}", chain.err().unwrap());
+            let mut config = parse_yaml_config(&config_str);
+            if args.resume {
+                config.resume();
+            }
+            if let Err(error) = config.check_graph_type() {
+                eprintln!("Error: {}", error);
                 return;
             }
 
-            let chain = chain.unwrap();
-            let mut graphs_with_properties: Vec<(SimpleGraph, GraphProperties)> = vec![];
-            match chain.run(&mut graphs_with_properties) {
-                Err(error) => {
-                    eprintln!("Error: {}", error);
-                }
-                Ok(()) => {}
+            let graph_type = config.graph_type().to_string();
+            match graph_type.as_str() {
+                configuration::SIMPLE_GRAPH => run::<SimpleGraph>(config, begin),
+                configuration::MULTI_GRAPH => run::<MultiGraph>(config, begin),
+                configuration::SIMPLE_EDGE_GRAPH => run::<SimpleEdgeGraph>(config, begin),
+                graph_type => eprintln!("Error: unknown graph type: {}", graph_type),
             }
-            println!("elapsed: {}ms", begin.elapsed().as_millis());
         }
         _ => {
             println!("Unknown command");
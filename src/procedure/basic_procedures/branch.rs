@@ -0,0 +1,201 @@
+use crate::graph::graph::GraphConstructor;
+use crate::graph::undirected::UndirectedGraph;
+use crate::procedure::basic_procedures::filter_expression::FilterExpression;
+use crate::procedure::configuration::ProcedureConfig;
+use crate::procedure::helpers::config_helper;
+use crate::procedure::procedure::{GraphProperties, Procedure, Result};
+use crate::procedure::procedure_builder::{ConfigMap, ProcedureBuilder};
+use crate::procedure::procedure_registry::ProcedureRegistry;
+use serde::Deserialize;
+use std::collections::HashMap;
+
+// config keys
+pub const BRANCHES: &str = "branches";
+const MERGE: &str = "merge";
+
+///
+/// Routes each graph to first branch whose filter it matches and runs procedures of branch over
+/// graphs routed to it. Graphs matched by no branch continue in chain, graphs of branches are
+/// appended to them if merge is set - input order of graphs is not kept, unmatched graphs go
+/// first followed by graphs of branches in order of branches.
+///
+struct BranchProcedure<G: UndirectedGraph> {
+    branches: Vec<Branch<G>>,
+    merge: bool,
+}
+
+struct Branch<G: UndirectedGraph> {
+    name: String,
+    filter: Option<FilterExpression>,
+    procedures: Vec<Box<dyn Procedure<G>>>,
+}
+
+pub struct BranchProcedureConfig {
+    branches: Vec<BranchConfig>,
+    merge: bool,
+}
+
+///
+/// Branch without filter receives all graphs not routed to previous branches
+///
+#[derive(Debug, Deserialize)]
+pub struct BranchConfig {
+    pub name: String,
+    #[serde(default)]
+    pub filter: Option<String>,
+    #[serde(default)]
+    pub procedures: Vec<ProcedureConfig>,
+}
+
+pub struct BranchProcedureBuilder {}
+
+impl<G: UndirectedGraph> Procedure<G> for BranchProcedure<G> {
+    fn run(&self, graphs: &mut Vec<(G, GraphProperties)>) -> Result<()> {
+        println!("running branch procedure");
+        let mut routed = self.route(graphs);
+        for (branch, branch_graphs) in self.branches.iter().zip(routed.iter_mut()) {
+            println!("branch {}: {} graphs", branch.name, branch_graphs.len());
+            for procedure in branch.procedures.iter() {
+                procedure.run(branch_graphs)?;
+            }
+        }
+        self.merge(graphs, routed);
+        Ok(())
+    }
+
+    fn run_chunk(&mut self, graphs: &mut Vec<(G, GraphProperties)>) -> Result<()> {
+        let mut routed = self.route(graphs);
+        for (branch, branch_graphs) in self.branches.iter_mut().zip(routed.iter_mut()) {
+            for procedure in branch.procedures.iter_mut() {
+                if branch_graphs.is_empty() {
+                    break;
+                }
+                procedure.run_chunk(branch_graphs)?;
+            }
+        }
+        self.merge(graphs, routed);
+        Ok(())
+    }
+
+    fn finish(&mut self) -> Result<()> {
+        for branch in self.branches.iter_mut() {
+            for procedure in branch.procedures.iter_mut() {
+                procedure.finish()?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<G: UndirectedGraph> BranchProcedure<G> {
+    ///
+    /// moves graphs to their branches, graphs matched by no branch are left in graphs
+    ///
+    fn route(&self, graphs: &mut Vec<(G, GraphProperties)>) -> Vec<Vec<(G, GraphProperties)>> {
+        let mut routed: Vec<Vec<(G, GraphProperties)>> =
+            self.branches.iter().map(|_| vec![]).collect();
+        let mut unmatched = vec![];
+        for graph in graphs.drain(..) {
+            let branch = self
+                .branches
+                .iter()
+                .position(|branch| match &branch.filter {
+                    Some(filter) => filter.evaluate(&graph.1),
+                    None => true,
+                });
+            match branch {
+                Some(branch) => routed[branch].push(graph),
+                None => unmatched.push(graph),
+            }
+        }
+        *graphs = unmatched;
+        routed
+    }
+
+    fn merge(
+        &self,
+        graphs: &mut Vec<(G, GraphProperties)>,
+        routed: Vec<Vec<(G, GraphProperties)>>,
+    ) {
+        if self.merge {
+            for branch_graphs in routed {
+                graphs.extend(branch_graphs);
+            }
+        }
+    }
+}
+
+impl BranchProcedureConfig {
+    pub const PROC_TYPE: &'static str = "branch";
+
+    pub fn new(branches: Vec<BranchConfig>, merge: bool) -> Self {
+        BranchProcedureConfig { branches, merge }
+    }
+
+    pub fn from_proc_config(config: &HashMap<String, serde_json::Value>) -> Result<Self> {
+        let branches = config_helper::resolve_value(&config, BRANCHES, Self::PROC_TYPE)?;
+        let merge =
+            config_helper::resolve_value_or_default(&config, MERGE, false, Self::PROC_TYPE)?;
+
+        let result = BranchProcedureConfig { branches, merge };
+        Ok(result)
+    }
+
+    pub fn branches(&self) -> &Vec<BranchConfig> {
+        &self.branches
+    }
+
+    pub fn merge(&self) -> bool {
+        self.merge
+    }
+}
+
+impl<G: UndirectedGraph + GraphConstructor + Clone + 'static> ProcedureBuilder<G>
+    for BranchProcedureBuilder
+{
+    ///
+    /// procedures of branches are created by basic procedure registry
+    ///
+    fn build_from_map(&self, config: ConfigMap) -> Result<Box<dyn Procedure<G>>> {
+        let proc_config = BranchProcedureConfig::from_proc_config(&config)?;
+        Self::build(proc_config, &ProcedureRegistry::new_basic())
+    }
+
+    ///
+    /// procedures of branches are created by registry of chain
+    ///
+    fn build_from_map_with_registry(
+        &self,
+        config: ConfigMap,
+        registry: &ProcedureRegistry<G>,
+    ) -> Result<Box<dyn Procedure<G>>> {
+        let proc_config = BranchProcedureConfig::from_proc_config(&config)?;
+        Self::build(proc_config, registry)
+    }
+}
+
+impl BranchProcedureBuilder {
+    pub fn build<G: UndirectedGraph + GraphConstructor + Clone + 'static>(
+        config: BranchProcedureConfig,
+        registry: &ProcedureRegistry<G>,
+    ) -> Result<Box<dyn Procedure<G>>> {
+        let mut branches = vec![];
+        let merge = config.merge();
+        for branch_config in config.branches {
+            let filter = match &branch_config.filter {
+                Some(filter) => Some(FilterExpression::parse(filter)?),
+                None => None,
+            };
+            let mut procedures = vec![];
+            for procedure_config in branch_config.procedures {
+                procedures.push(registry.create_procedure(procedure_config)?);
+            }
+            branches.push(Branch {
+                name: branch_config.name,
+                filter,
+                procedures,
+            });
+        }
+        Ok(Box::new(BranchProcedure { branches, merge }))
+    }
+}
@@ -0,0 +1,133 @@
+use crate::procedure::basic_procedures::chrom_props::config::{CheckpointConfig, GRAPH_INDEX};
+use crate::procedure::procedure::GraphProperties;
+use crate::service::chromatic_properties::error::ChromaticPropertiesError;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs;
+use std::io::Write;
+use std::path::Path;
+use std::result;
+
+type Result<T> = result::Result<T, ChromaticPropertiesError>;
+
+///
+/// Properties of finished graphs appended to checkpoint file as JSON lines keyed by index of graph
+/// in whole input. On resume, graphs found in checkpoint file are not computed again.
+///
+pub struct Checkpoint {
+    config: CheckpointConfig,
+    started: bool,
+    finished: HashMap<usize, GraphProperties>,
+    pending: Vec<CheckpointEntry>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+struct CheckpointEntry {
+    graph_index: usize,
+    properties: GraphProperties,
+}
+
+impl Checkpoint {
+    pub fn new(config: CheckpointConfig) -> Self {
+        Checkpoint {
+            config,
+            started: false,
+            finished: HashMap::new(),
+            pending: vec![],
+        }
+    }
+
+    ///
+    /// properties of graph finished in previous run (only when resuming)
+    ///
+    pub fn finished(&mut self, graph_index: usize) -> Result<Option<&GraphProperties>> {
+        self.start()?;
+        Ok(self.finished.get(&graph_index))
+    }
+
+    ///
+    /// checkpoint file is written once per interval of added graphs
+    ///
+    pub fn add(&mut self, graph_index: usize, properties: &GraphProperties) -> Result<()> {
+        self.start()?;
+        let mut properties = properties.clone();
+        // graph index is index of graph in chunk, checkpoint is keyed by index in whole input
+        properties.remove(GRAPH_INDEX);
+        self.pending.push(CheckpointEntry {
+            graph_index,
+            properties,
+        });
+        if self.pending.len() >= self.config.interval {
+            self.flush()?;
+        }
+        Ok(())
+    }
+
+    pub fn flush(&mut self) -> Result<()> {
+        if self.pending.is_empty() {
+            return Ok(());
+        }
+        let mut lines = String::new();
+        for entry in self.pending.iter() {
+            lines.push_str(&serde_json::to_string(entry)?);
+            lines.push('\n');
+        }
+        let mut file = fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.config.file)
+            .map_err(|error| self.io_error(error))?;
+        file.write_all(lines.as_bytes())
+            .and_then(|_| file.flush())
+            .map_err(|error| self.io_error(error))?;
+        self.pending.clear();
+        Ok(())
+    }
+
+    ///
+    /// loads finished graphs when resuming, otherwise starts new checkpoint file
+    ///
+    fn start(&mut self) -> Result<()> {
+        if self.started {
+            return Ok(());
+        }
+        self.started = true;
+        if !self.config.resume {
+            return fs::write(&self.config.file, "").map_err(|error| self.io_error(error));
+        }
+        if !Path::new(&self.config.file).exists() {
+            return Ok(());
+        }
+        let content =
+            fs::read_to_string(&self.config.file).map_err(|error| self.io_error(error))?;
+        // last line may be incomplete if previous run crashed while writing it - it is cut off,
+        // so that new entries are appended on new line
+        let complete = match content.rfind('\n') {
+            Some(end) => &content[..=end],
+            None => "",
+        };
+        if complete.len() < content.len() {
+            fs::write(&self.config.file, complete).map_err(|error| self.io_error(error))?;
+        }
+        for line in complete.lines() {
+            if line.trim().is_empty() {
+                continue;
+            }
+            let entry: CheckpointEntry = serde_json::from_str(line)?;
+            self.finished.insert(entry.graph_index, entry.properties);
+        }
+        println!(
+            "resuming from checkpoint: {} finished graphs",
+            self.finished.len()
+        );
+        Ok(())
+    }
+
+    fn io_error(&self, error: std::io::Error) -> ChromaticPropertiesError {
+        ChromaticPropertiesError::new(format!(
+            "checkpoint file {} error: {}",
+            self.config.file, error
+        ))
+    }
+}
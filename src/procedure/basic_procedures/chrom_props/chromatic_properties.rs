@@ -1,10 +1,14 @@
 use crate::graph::undirected::simple_graph::graph::SimpleGraph;
 use crate::graph::undirected::UndirectedGraph;
+use crate::procedure::basic_procedures::chrom_props::checkpoint::Checkpoint;
 use crate::procedure::basic_procedures::chrom_props::config::{
     ChromaticPropertiesToCompute, ChromaticPropsProcedureConfig, ParallelizationType, ACRITICAL,
-    COCRITICAL, COSTABLE, CRITICAL, CYCLIC_EDGE_CONNECTIVITY, EDGE_RESISTIBILITIES,
-    EDGE_RESISTIBILITY_INDEX, EDGE_SUBCRITICAL, GIRTH, GRAPH_INDEX, ODDNESS, RESISTANCE, STABLE,
-    VERTEX_RESISTIBILITIES, VERTEX_RESISTIBILITY_INDEX, VERTEX_SUBCRITICAL,
+    AUTOMORPHISM_GROUP_ORDER, CDC, CIRCULAR_FLOW_NUMBER, COCRITICAL, COLOURINGS_COUNT, COSTABLE,
+    CRITICAL, CYCLIC_EDGE_CONNECTIVITY, EDGE_ORBITS, EDGE_RESISTIBILITIES,
+    EDGE_RESISTIBILITY_INDEX, EDGE_SUBCRITICAL, FIVE_CDC, FLOW_NUMBER, FULKERSON_COVER, GIRTH,
+    GRAPH_INDEX, ODDNESS, ODDNESS_WITNESS, PERFECT_MATCHING_INDEX, RESISTANCE, RESISTANCE_WITNESS,
+    STABLE, VERTEX_ORBITS, VERTEX_RESISTIBILITIES, VERTEX_RESISTIBILITY_INDEX, VERTEX_SUBCRITICAL,
+    WEAK_ODDNESS, WEAK_ODDNESS_WITNESS,
 };
 use crate::procedure::basic_procedures::colour::ColouriserType;
 use crate::procedure::helpers::serialize_helper;
@@ -14,19 +18,28 @@ use crate::procedure::procedure_builder::{ConfigMap, ProcedureBuilder};
 use crate::service::chromatic_properties::critical_prop::CriticalPropertiesSolver;
 use crate::service::chromatic_properties::critical_prop_parallel::CriticalPropertiesParallelSolver;
 use crate::service::chromatic_properties::error::ChromaticPropertiesError;
-use crate::service::chromatic_properties::resistance::Resistance;
+use crate::service::chromatic_properties::resistance_sat::SATResistance;
 use crate::service::chromatic_properties::resistibility::Resistibility;
 use crate::service::chromatic_properties::stable_and_critical_prop::StableAndCriticalPropertiesSolver;
 use crate::service::chromatic_properties::stable_and_critical_prop_parallel::StableAndCriticalPropertiesParallelSolver;
 use crate::service::chromatic_properties::CriticalProperties;
 use crate::service::colour::colouriser::Colouriser;
+use crate::service::colour::enumeration::tait_colourings::TaitColourings;
 use crate::service::colour::recursive::dfs_improved::DFSColourizer;
 use crate::service::colour::sat::sat::SATColourizer;
+use crate::service::flow::flow_number::{
+    flow_number, CircularFlowBounds, CIRCULAR_FLOW_MAX_DENOMINATOR,
+};
+use crate::service::property::cycle_double_cover::CycleDoubleCoverSolver;
 use crate::service::property::cyclic_connectivity::cyclic_edge_connectivity;
 use crate::service::property::girth::girth;
-use crate::service::property::oddness::Oddness;
+use crate::service::property::oddness::{Oddness, WeakOddness};
+use crate::service::property::perfect_matching_cover::{FulkersonCover, PerfectMatchingIndex};
+use crate::service::symmetry::automorphisms::AutomorphismGroup;
 use std::borrow::Borrow;
+use std::cell::RefCell;
 use std::collections::HashMap;
+use std::convert::TryFrom;
 use std::sync::mpsc;
 use std::{marker, result, thread};
 use crate::service::colour::cvd::cvd_sat::CvdSatColourizer;
@@ -36,6 +49,9 @@ pub type Result<T> = result::Result<T, ChromaticPropertiesError>;
 
 struct ChromaticPropsProcedure<G> {
     config: ChromaticPropsProcedureConfig,
+    // graphs processed in previous chunks in streaming mode
+    processed: usize,
+    checkpoint: Option<RefCell<Checkpoint>>,
     _ph: marker::PhantomData<G>,
 }
 
@@ -47,9 +63,44 @@ impl<G: UndirectedGraph + Clone> Procedure<G> for ChromaticPropsProcedure<G> {
         self.chromatic_properties(graphs)?;
         Ok(())
     }
+
+    fn run_chunk(&mut self, graphs: &mut Vec<(G, GraphProperties)>) -> procedure::Result<()> {
+        let indexed: Vec<bool> = graphs
+            .iter()
+            .map(|graph| graph.1.contains_key(GRAPH_INDEX))
+            .collect();
+        self.chromatic_properties(graphs)?;
+        // graph index is index of graph in whole input, not in chunk
+        for (graph, indexed) in graphs.iter_mut().zip(indexed) {
+            if indexed {
+                continue;
+            }
+            if let Some(index) = graph.1.get(GRAPH_INDEX).and_then(|value| value.as_u64()) {
+                let index = index as usize + self.processed;
+                graph
+                    .1
+                    .insert(GRAPH_INDEX.to_string(), serde_json::to_value(index)?);
+            }
+        }
+        self.processed += graphs.len();
+        Ok(())
+    }
 }
 
 impl<G: UndirectedGraph + Clone> ChromaticPropsProcedure<G> {
+    fn new(config: ChromaticPropsProcedureConfig) -> Self {
+        let checkpoint = config
+            .checkpoint
+            .clone()
+            .map(|checkpoint| RefCell::new(Checkpoint::new(checkpoint)));
+        ChromaticPropsProcedure {
+            config,
+            processed: 0,
+            checkpoint,
+            _ph: marker::PhantomData,
+        }
+    }
+
     fn chromatic_properties(&self, graphs: &mut Vec<(G, GraphProperties)>) -> Result<()> {
         let colouriser_type = self.config.colouriser_type();
         let parallelization = self.config.parallelization();
@@ -64,6 +115,62 @@ impl<G: UndirectedGraph + Clone> ChromaticPropsProcedure<G> {
                 self.chromatic_properties_sequential(graphs, colouriser_type)?;
             }
         }
+        if let Some(checkpoint) = &self.checkpoint {
+            checkpoint.borrow_mut().flush()?;
+        }
+        Ok(())
+    }
+
+    ///
+    /// key of graph in checkpoint - graph index set by previous procedure (e.g. original index
+    /// set by read), otherwise position of graph in whole input, index - index of graph in chunk
+    ///
+    fn checkpoint_index(&self, graph_properties: &GraphProperties, index: usize) -> usize {
+        match graph_properties
+            .get(GRAPH_INDEX)
+            .and_then(|value| value.as_u64())
+        {
+            Some(graph_index) => graph_index as usize,
+            None => self.processed + index,
+        }
+    }
+
+    ///
+    /// restores properties of graph finished in previous run from checkpoint, index - index of
+    /// graph in chunk
+    ///
+    fn restore(&self, graph: &mut (G, GraphProperties), index: usize) -> Result<bool> {
+        let checkpoint = match &self.checkpoint {
+            Some(checkpoint) => checkpoint,
+            None => return Ok(false),
+        };
+        let checkpoint_index = self.checkpoint_index(&graph.1, index);
+        let mut checkpoint = checkpoint.borrow_mut();
+        match checkpoint.finished(checkpoint_index)? {
+            Some(properties) => {
+                graph.1.extend(properties.clone());
+                if !graph.1.contains_key(GRAPH_INDEX) {
+                    graph
+                        .1
+                        .insert(GRAPH_INDEX.to_string(), serde_json::to_value(index)?);
+                }
+                Ok(true)
+            }
+            None => Ok(false),
+        }
+    }
+
+    fn add_to_checkpoint(
+        &self,
+        graph_properties: &GraphProperties,
+        index: usize,
+        properties: &GraphProperties,
+    ) -> Result<()> {
+        if let Some(checkpoint) = &self.checkpoint {
+            checkpoint
+                .borrow_mut()
+                .add(self.checkpoint_index(graph_properties, index), properties)?;
+        }
         Ok(())
     }
 
@@ -73,19 +180,26 @@ impl<G: UndirectedGraph + Clone> ChromaticPropsProcedure<G> {
         colouriser_type: &ColouriserType,
     ) -> Result<()> {
         let mut threads = HashMap::new();
-        let mut index = 0;
         let (tx, rx) = mpsc::channel();
 
         let cpus_count = self.config.max_threads;
         let to_compute = &self.config.properties_to_compute;
 
+        // graphs finished in previous run are not computed again
+        let mut pending = vec![];
+        for (index, graph) in graphs.iter_mut().enumerate() {
+            if !self.restore(graph, index)? {
+                pending.push(index);
+            }
+        }
+
         // init first threads
-        let mut graphs_iter = graphs.iter();
-        let mut next_graph = graphs_iter.next();
-        while next_graph.is_some() {
-            let graph = next_graph.unwrap();
+        let mut pending_iter = pending.into_iter();
+        let mut next_index = pending_iter.next();
+        while next_index.is_some() {
+            let index = next_index.unwrap();
             // if graph is bigger could cause performance issues
-            let graph_local = SimpleGraph::from_graph(&graph.0);
+            let graph_local = SimpleGraph::from_graph(&graphs[index].0);
             let tx_cloned = mpsc::Sender::clone(&tx);
             let handle = Self::spawn_thread_for_graph(
                 graph_local,
@@ -95,11 +209,14 @@ impl<G: UndirectedGraph + Clone> ChromaticPropsProcedure<G> {
                 tx_cloned,
             );
             threads.insert(index, handle);
-            index += 1;
-            if index >= cpus_count {
+            if threads.len() >= cpus_count {
                 break;
             }
-            next_graph = graphs_iter.next();
+            next_index = pending_iter.next();
+        }
+        // all graphs were restored from checkpoint
+        if threads.is_empty() {
+            return Ok(());
         }
         let mut results = Vec::with_capacity(graphs.len());
 
@@ -114,12 +231,15 @@ impl<G: UndirectedGraph + Clone> ChromaticPropsProcedure<G> {
             if thread_opt.is_some() {
                 let _result = thread_opt.unwrap().join();
             }
+            if let Ok(properties) = &received {
+                self.add_to_checkpoint(&graphs[received_index].1, received_index, properties)?;
+            }
             results.push(received);
 
-            next_graph = graphs_iter.next();
-            if next_graph.is_some() {
-                let graph = next_graph.unwrap();
-                let graph_local = SimpleGraph::from_graph(&graph.0);
+            next_index = pending_iter.next();
+            if next_index.is_some() {
+                let index = next_index.unwrap();
+                let graph_local = SimpleGraph::from_graph(&graphs[index].0);
                 let tx_cloned = mpsc::Sender::clone(&tx);
                 let handle = Self::spawn_thread_for_graph(
                     graph_local,
@@ -129,7 +249,6 @@ impl<G: UndirectedGraph + Clone> ChromaticPropsProcedure<G> {
                     tx_cloned,
                 );
                 threads.insert(index, handle);
-                index += 1;
             } else {
                 break;
             }
@@ -139,6 +258,11 @@ impl<G: UndirectedGraph + Clone> ChromaticPropsProcedure<G> {
 
         // receive remaining results
         for received in rx {
+            if let Ok(properties) = &received {
+                let index_value = properties.get(GRAPH_INDEX).unwrap();
+                let received_index: usize = serde_json::from_value(index_value.clone())?;
+                self.add_to_checkpoint(&graphs[received_index].1, received_index, properties)?;
+            }
             results.push(received);
         }
         for result in results {
@@ -158,6 +282,10 @@ impl<G: UndirectedGraph + Clone> ChromaticPropsProcedure<G> {
     ) -> Result<()> {
         let mut index = 0;
         for graph in graphs {
+            if self.restore(graph, index)? {
+                index += 1;
+                continue;
+            }
             let properties = Self::compute_properties_by_colouriser_parallel(
                 &graph.0,
                 colouriser_type,
@@ -165,6 +293,7 @@ impl<G: UndirectedGraph + Clone> ChromaticPropsProcedure<G> {
                 &self.config.properties_to_compute,
                 self.config.max_threads,
             )?;
+            self.add_to_checkpoint(&graph.1, index, &properties)?;
             self.write_properties(graph, properties)?;
             index += 1;
         }
@@ -178,18 +307,124 @@ impl<G: UndirectedGraph + Clone> ChromaticPropsProcedure<G> {
     ) -> Result<()> {
         let mut index = 0;
         for graph in graphs {
+            if self.restore(graph, index)? {
+                index += 1;
+                continue;
+            }
             let properties = Self::compute_properties_by_colouriser(
                 &graph.0,
                 colouriser_type,
                 index,
                 &self.config.properties_to_compute,
             )?;
+            self.add_to_checkpoint(&graph.1, index, &properties)?;
             self.write_properties(graph, properties)?;
             index += 1;
         }
         Ok(())
     }
 
+    fn colourings_count<Gr: UndirectedGraph>(
+        graph: &Gr,
+        properties: &mut GraphProperties,
+    ) -> Result<()> {
+        let colourings_count = TaitColourings::count(graph);
+        properties.insert(
+            COLOURINGS_COUNT.to_string(),
+            serde_json::to_value(colourings_count)?,
+        );
+        Ok(())
+    }
+
+    fn automorphisms<Gr: UndirectedGraph>(
+        graph: &Gr,
+        to_compute: &ChromaticPropertiesToCompute,
+        properties: &mut GraphProperties,
+    ) -> Result<()> {
+        let group = AutomorphismGroup::of_graph(graph);
+        if to_compute.automorphism_group_order {
+            // order does not have to fit into json number
+            let order = match u64::try_from(group.order()) {
+                Ok(order) => serde_json::to_value(order)?,
+                Err(_) => serde_json::to_value(group.order().to_string())?,
+            };
+            properties.insert(AUTOMORPHISM_GROUP_ORDER.to_string(), order);
+        }
+        if to_compute.vertex_orbits {
+            properties.insert(
+                VERTEX_ORBITS.to_string(),
+                serde_json::to_value(group.vertex_orbits())?,
+            );
+        }
+        if to_compute.edge_orbits {
+            properties.insert(
+                EDGE_ORBITS.to_string(),
+                serde_json::to_value(group.edge_orbits())?,
+            );
+        }
+        Ok(())
+    }
+
+    fn oddness<Gr: UndirectedGraph>(
+        graph: &Gr,
+        to_compute: &ChromaticPropertiesToCompute,
+        properties: &mut GraphProperties,
+    ) -> Result<()> {
+        let witness = Oddness::witness(graph);
+        if to_compute.oddness {
+            // graphs without perfect matching have no oddness - null
+            let oddness = witness.as_ref().map(|witness| witness.oddness());
+            properties.insert(ODDNESS.to_string(), serde_json::to_value(oddness)?);
+        }
+        if to_compute.oddness_witness {
+            // graphs without perfect matching have no witness
+            let witness = match witness {
+                Some(witness) => witness.to_json_value(),
+                None => serde_json::Value::Null,
+            };
+            properties.insert(ODDNESS_WITNESS.to_string(), witness);
+        }
+        Ok(())
+    }
+
+    fn weak_oddness<Gr: UndirectedGraph>(
+        graph: &Gr,
+        to_compute: &ChromaticPropertiesToCompute,
+        properties: &mut GraphProperties,
+    ) -> Result<()> {
+        let witness = WeakOddness::witness(graph);
+        if to_compute.weak_oddness {
+            properties.insert(
+                WEAK_ODDNESS.to_string(),
+                serde_json::to_value(witness.weak_oddness())?,
+            );
+        }
+        if to_compute.weak_oddness_witness {
+            properties.insert(WEAK_ODDNESS_WITNESS.to_string(), witness.to_json_value());
+        }
+        Ok(())
+    }
+
+    fn flow_number<Gr: UndirectedGraph>(
+        graph: &Gr,
+        properties: &mut GraphProperties,
+    ) -> Result<()> {
+        // graphs with bridge have no nowhere-zero flow - both properties are null
+        let flow_number = flow_number(graph);
+        properties.insert(FLOW_NUMBER.to_string(), serde_json::to_value(flow_number)?);
+        let circular_flow_number = match flow_number {
+            Some(flow_number) => CircularFlowBounds::with_flow_number(
+                graph,
+                flow_number,
+                CIRCULAR_FLOW_MAX_DENOMINATOR,
+            )
+            .to_json_value(),
+            None => serde_json::Value::Null,
+        };
+        properties.insert(CIRCULAR_FLOW_NUMBER.to_string(), circular_flow_number);
+        Ok(())
+    }
+
     fn handle_parallel_result(
         &self,
         graphs: &mut Vec<(G, GraphProperties)>,
@@ -200,8 +435,7 @@ impl<G: UndirectedGraph + Clone> ChromaticPropsProcedure<G> {
         if graph_index_opt_value.is_some() {
             let graph_index_value = graph_index_opt_value.unwrap();
             let graph_index: usize = serde_json::from_value(graph_index_value.clone())?;
-            graphs[graph_index].1.extend(result_props);
-            return Ok(());
+            return self.write_properties(&mut graphs[graph_index], result_props);
         }
         Err(ChromaticPropertiesError::new(
             "graph index is missing in parallel result",
@@ -343,36 +577,7 @@ impl<G: UndirectedGraph + Clone> ChromaticPropsProcedure<G> {
             Self::critical_properties(graph, &colouriser, properties_to_compute, &mut properties)?;
         }
 
-        if to_compute.resistance {
-            // compute resistence and add result to properties
-            Self::resistance(graph, &colouriser, &mut properties)?;
-        }
-        if to_compute.vertex_resistibility {
-            // compute vertex resistibility and add result to properties
-            Self::vertex_resistibility(graph, &colouriser, &mut properties)?;
-        }
-        if to_compute.edge_resistibility {
-            // compute edge resistibility and add result to properties
-            Self::edge_resistibility(graph, &colouriser, &mut properties)?;
-        }
-        if to_compute.girth {
-            // compute girth and add result to properties
-            let girth = girth(graph);
-            properties.insert(GIRTH.to_string(), serde_json::to_value(girth)?);
-        }
-        if to_compute.cyclic_connectivity {
-            // compute cyclic connectivity and add result to properties
-            let cyclic_edge_connectivity = cyclic_edge_connectivity(graph);
-            properties.insert(
-                CYCLIC_EDGE_CONNECTIVITY.to_string(),
-                serde_json::to_value(cyclic_edge_connectivity)?,
-            );
-        }
-        if to_compute.oddness {
-            // compute cyclic connectivity and add result to properties
-            let oddness = Oddness::of_graph(graph);
-            properties.insert(ODDNESS.to_string(), serde_json::to_value(oddness)?);
-        }
+        Self::properties_after_critical(graph, &colouriser, to_compute, &mut properties)?;
 
         Ok(properties)
     }
@@ -411,38 +616,96 @@ impl<G: UndirectedGraph + Clone> ChromaticPropsProcedure<G> {
             )?;
         }
 
-        if to_compute.resistance {
-            // compute resistence and add result to properties
-            Self::resistance(graph, &colouriser, &mut properties)?;
+        Self::properties_after_critical(graph, &colouriser, to_compute, &mut properties)?;
+
+        Ok(properties)
+    }
+
+    ///
+    /// properties other than critical and stable ones - shared by sequential and parallel
+    /// computation
+    ///
+    fn properties_after_critical<Gr: UndirectedGraph + Clone, C: Colouriser>(
+        graph: &Gr,
+        colouriser: &C,
+        to_compute: &ChromaticPropertiesToCompute,
+        properties_computed: &mut GraphProperties,
+    ) -> Result<()> {
+        if to_compute.resistance || to_compute.resistance_witness {
+            // compute resistence and its witness and add them to properties
+            Self::resistance(graph, to_compute, properties_computed)?;
         }
         if to_compute.vertex_resistibility {
             // compute vertex resistibility and add result to properties
-            Self::vertex_resistibility(graph, &colouriser, &mut properties)?;
+            Self::vertex_resistibility(graph, colouriser, properties_computed)?;
         }
         if to_compute.edge_resistibility {
             // compute edge resistibility and add result to properties
-            Self::edge_resistibility(graph, &colouriser, &mut properties)?;
+            Self::edge_resistibility(graph, colouriser, properties_computed)?;
         }
         if to_compute.girth {
             // compute girth and add result to properties
             let girth = girth(graph);
-            properties.insert(GIRTH.to_string(), serde_json::to_value(girth)?);
+            properties_computed.insert(GIRTH.to_string(), serde_json::to_value(girth)?);
         }
         if to_compute.cyclic_connectivity {
             // compute cyclic connectivity and add result to properties
             let cyclic_edge_connectivity = cyclic_edge_connectivity(graph);
-            properties.insert(
+            properties_computed.insert(
                 CYCLIC_EDGE_CONNECTIVITY.to_string(),
                 serde_json::to_value(cyclic_edge_connectivity)?,
             );
         }
-        if to_compute.oddness {
-            // compute cyclic connectivity and add result to properties
-            let oddness = Oddness::of_graph(graph);
-            properties.insert(ODDNESS.to_string(), serde_json::to_value(oddness)?);
+        if to_compute.oddness || to_compute.oddness_witness {
+            // compute oddness and its witnessing 2-factor and add them to properties
+            Self::oddness(graph, to_compute, properties_computed)?;
         }
-
-        Ok(properties)
+        if to_compute.weak_oddness || to_compute.weak_oddness_witness {
+            // compute weak oddness and its witnessing even factor and add them to properties
+            Self::weak_oddness(graph, to_compute, properties_computed)?;
+        }
+        if to_compute.colourings_count {
+            // count colourings up to permutation of colours and add result to properties
+            Self::colourings_count(graph, properties_computed)?;
+        }
+        if to_compute.automorphism_group_order || to_compute.vertex_orbits || to_compute.edge_orbits
+        {
+            // compute automorphism group and add its order and orbits to properties
+            Self::automorphisms(graph, to_compute, properties_computed)?;
+        }
+        if to_compute.flow_number {
+            // compute flow number and bounds of circular flow number and add them to properties
+            Self::flow_number(graph, properties_computed)?;
+        }
+        if to_compute.perfect_matching_index {
+            // compute minimal number of perfect matchings covering all edges and add it to properties
+            let perfect_matching_index = PerfectMatchingIndex::of_graph(graph);
+            properties_computed.insert(
+                PERFECT_MATCHING_INDEX.to_string(),
+                serde_json::to_value(perfect_matching_index)?,
+            );
+        }
+        if to_compute.fulkerson_cover {
+            // decide whether six perfect matchings cover each edge exactly twice
+            let fulkerson_cover = FulkersonCover::exists(graph);
+            properties_computed.insert(
+                FULKERSON_COVER.to_string(),
+                serde_json::to_value(fulkerson_cover)?,
+            );
+        }
+        if to_compute.cdc {
+            // search for cycle double cover and add result to properties (null for graph with
+            // vertex of degree greater than 3)
+            let cdc = CycleDoubleCoverSolver::has_cdc(graph);
+            properties_computed.insert(CDC.to_string(), serde_json::to_value(cdc)?);
+        }
+        if to_compute.five_cdc {
+            // search for cycle double cover by five even subgraphs and add result to properties
+            // (null for graph with vertex of degree greater than 3)
+            let five_cdc = CycleDoubleCoverSolver::has_five_cdc(graph);
+            properties_computed.insert(FIVE_CDC.to_string(), serde_json::to_value(five_cdc)?);
+        }
+        Ok(())
     }
 
     fn critical_and_stable_properties<Gr: UndirectedGraph + Clone, C: Colouriser>(
@@ -558,22 +821,21 @@ impl<G: UndirectedGraph + Clone> ChromaticPropsProcedure<G> {
         Ok(())
     }
 
-    fn resistance<Gr: UndirectedGraph + Clone, C: Colouriser>(
+    fn resistance<Gr: UndirectedGraph>(
         graph: &Gr,
-        _colouriser: &C,
+        to_compute: &ChromaticPropertiesToCompute,
         properties_computed: &mut GraphProperties,
     ) -> Result<()> {
-        let resistance = Resistance::new_with_colouriser(C::new());
-        let resistance = resistance.vertex_resistance(graph);
-        if resistance.is_some() {
-            properties_computed.insert(
-                RESISTANCE.to_string(),
-                serde_json::to_value(resistance.unwrap())?,
-            );
-        } else {
+        // minimal set of vertices whose removal makes graph colourable
+        let witness = SATResistance::vertex_resistance_witness(graph);
+        if to_compute.resistance {
+            properties_computed
+                .insert(RESISTANCE.to_string(), serde_json::to_value(witness.len())?);
+        }
+        if to_compute.resistance_witness {
             properties_computed.insert(
-                RESISTANCE.to_string(),
-                serde_json::Value::String("None".to_string()),
+                RESISTANCE_WITNESS.to_string(),
+                serde_json::to_value(witness)?,
             );
         }
         Ok(())
@@ -625,8 +887,12 @@ impl<G: UndirectedGraph + Clone> ChromaticPropsProcedure<G> {
     fn write_properties(
         &self,
         graph: &mut (G, GraphProperties),
-        props: GraphProperties,
+        mut props: GraphProperties,
     ) -> Result<()> {
+        // graph index set by previous procedure (e.g. original index set by read) is kept
+        if graph.1.contains_key(GRAPH_INDEX) {
+            props.remove(GRAPH_INDEX);
+        }
         graph.1.extend(props);
         Ok(())
     }
@@ -635,10 +901,7 @@ impl<G: UndirectedGraph + Clone> ChromaticPropsProcedure<G> {
 impl<G: UndirectedGraph + Clone + 'static> ProcedureBuilder<G> for ChromaticPropsProcedureBuilder {
     fn build_from_map(&self, config: ConfigMap) -> procedure::Result<Box<dyn Procedure<G>>> {
         let proc_config = ChromaticPropsProcedureConfig::from_proc_config(&config)?;
-        Ok(Box::new(ChromaticPropsProcedure {
-            config: proc_config,
-            _ph: marker::PhantomData,
-        }))
+        Ok(Box::new(ChromaticPropsProcedure::new(proc_config)))
     }
 }
 
@@ -646,9 +909,6 @@ impl ChromaticPropsProcedureBuilder {
     pub fn build<G: UndirectedGraph + Clone + 'static>(
         config: ChromaticPropsProcedureConfig,
     ) -> Box<dyn Procedure<G>> {
-        Box::new(ChromaticPropsProcedure {
-            config,
-            _ph: marker::PhantomData,
-        })
+        Box::new(ChromaticPropsProcedure::new(config))
     }
 }
@@ -17,11 +17,25 @@ pub const ACRITICAL: &str = "acritical";
 pub const STABLE: &str = "stable";
 pub const COSTABLE: &str = "costable";
 pub const RESISTANCE: &str = "resistance";
+pub const RESISTANCE_WITNESS: &str = "resistance-witness";
 pub const GIRTH: &str = "girth";
 pub const CYCLIC_EDGE_CONNECTIVITY: &str = "cyclic-edge-connectivity";
 pub const EDGE_RESISTIBILITY: &str = "edge-resistibility";
 pub const VERTEX_RESISTIBILITY: &str = "vertex-resistibility";
 pub const ODDNESS: &str = "oddness";
+pub const ODDNESS_WITNESS: &str = "oddness-witness";
+pub const WEAK_ODDNESS: &str = "weak-oddness";
+pub const WEAK_ODDNESS_WITNESS: &str = "weak-oddness-witness";
+pub const COLOURINGS_COUNT: &str = "colourings-count";
+pub const AUTOMORPHISM_GROUP_ORDER: &str = "automorphism-group-order";
+pub const VERTEX_ORBITS: &str = "vertex-orbits";
+pub const EDGE_ORBITS: &str = "edge-orbits";
+pub const FLOW_NUMBER: &str = "flow-number";
+pub const CIRCULAR_FLOW_NUMBER: &str = "circular-flow-number";
+pub const PERFECT_MATCHING_INDEX: &str = "perfect-matching-index";
+pub const FULKERSON_COVER: &str = "fulkerson-cover";
+pub const CDC: &str = "cdc";
+pub const FIVE_CDC: &str = "5-cdc";
 
 pub const VERTEX_RESISTIBILITIES: &str = "vertex-resistibilities";
 pub const VERTEX_RESISTIBILITY_INDEX: &str = "vertex-resistibility-index";
@@ -34,12 +48,29 @@ pub const PARALLELIZATION: &str = "parallelization";
 pub const PROPERTIES: &str = "properties";
 pub const GRAPH_INDEX: &str = "graph-index";
 pub const MAX_THREADS: &str = "max-threads";
+pub const CHECKPOINT_FILE: &str = "checkpoint-file";
+pub const CHECKPOINT_INTERVAL: &str = "checkpoint-interval";
+pub const RESUME: &str = "resume";
+
+pub const DEFAULT_CHECKPOINT_INTERVAL: usize = 10;
 
 pub struct ChromaticPropsProcedureConfig {
     pub colouriser_type: ColouriserType,
     pub parallelization: ParallelizationType,
     pub properties_to_compute: ChromaticPropertiesToCompute,
     pub max_threads: usize,
+    pub checkpoint: Option<CheckpointConfig>,
+}
+
+///
+/// file - properties of finished graphs are appended to it every interval graphs
+/// resume - graphs found in file are not computed again
+///
+#[derive(Clone)]
+pub struct CheckpointConfig {
+    pub file: String,
+    pub interval: usize,
+    pub resume: bool,
 }
 
 impl ChromaticPropsProcedureConfig {
@@ -56,6 +87,7 @@ impl ChromaticPropsProcedureConfig {
             parallelization,
             properties_to_compute,
             max_threads,
+            checkpoint: None,
         }
     }
 
@@ -85,12 +117,40 @@ impl ChromaticPropsProcedureConfig {
             Self::PROC_TYPE,
         )?;
 
+        let checkpoint_file: Option<String> = config_helper::resolve_value_or_default(
+            &config,
+            CHECKPOINT_FILE,
+            None,
+            Self::PROC_TYPE,
+        )?;
+        let checkpoint_interval = config_helper::resolve_value_or_default(
+            &config,
+            CHECKPOINT_INTERVAL,
+            DEFAULT_CHECKPOINT_INTERVAL,
+            Self::PROC_TYPE,
+        )?;
+        let resume =
+            config_helper::resolve_value_or_default(&config, RESUME, false, Self::PROC_TYPE)?;
+        if checkpoint_interval == 0 {
+            return Err(Error::ConfigError(format!(
+                "field '{}' for procedure '{}' has to be greater than 0",
+                CHECKPOINT_INTERVAL,
+                Self::PROC_TYPE
+            )));
+        }
+        let checkpoint = checkpoint_file.map(|file| CheckpointConfig {
+            file,
+            interval: checkpoint_interval,
+            resume,
+        });
+
         let mut result = ChromaticPropsProcedureConfig {
             colouriser_type: ColouriserType::from_string(&colouriser_type)?,
             // parallel,
             parallelization: ParallelizationType::from_string(parallelization_str)?,
             properties_to_compute,
             max_threads: max_cpus,
+            checkpoint,
         };
         result.resolve_properties_to_compute(properties);
         Ok(result)
@@ -129,6 +189,9 @@ impl ChromaticPropsProcedureConfig {
                 RESISTANCE => {
                     self.properties_to_compute.resistance = true;
                 }
+                RESISTANCE_WITNESS => {
+                    self.properties_to_compute.resistance_witness = true;
+                }
                 EDGE_RESISTIBILITY => {
                     self.properties_to_compute.edge_resistibility = true;
                 }
@@ -138,6 +201,42 @@ impl ChromaticPropsProcedureConfig {
                 ODDNESS => {
                     self.properties_to_compute.oddness = true;
                 }
+                ODDNESS_WITNESS => {
+                    self.properties_to_compute.oddness_witness = true;
+                }
+                WEAK_ODDNESS => {
+                    self.properties_to_compute.weak_oddness = true;
+                }
+                WEAK_ODDNESS_WITNESS => {
+                    self.properties_to_compute.weak_oddness_witness = true;
+                }
+                COLOURINGS_COUNT => {
+                    self.properties_to_compute.colourings_count = true;
+                }
+                AUTOMORPHISM_GROUP_ORDER => {
+                    self.properties_to_compute.automorphism_group_order = true;
+                }
+                VERTEX_ORBITS => {
+                    self.properties_to_compute.vertex_orbits = true;
+                }
+                EDGE_ORBITS => {
+                    self.properties_to_compute.edge_orbits = true;
+                }
+                FLOW_NUMBER => {
+                    self.properties_to_compute.flow_number = true;
+                }
+                PERFECT_MATCHING_INDEX => {
+                    self.properties_to_compute.perfect_matching_index = true;
+                }
+                FULKERSON_COVER => {
+                    self.properties_to_compute.fulkerson_cover = true;
+                }
+                CDC => {
+                    self.properties_to_compute.cdc = true;
+                }
+                FIVE_CDC => {
+                    self.properties_to_compute.five_cdc = true;
+                }
                 _ => {}
             }
         }
@@ -163,11 +262,24 @@ pub struct ChromaticPropertiesToCompute {
     pub costable: bool,
 
     pub resistance: bool,
+    pub resistance_witness: bool,
     pub edge_resistibility: bool,
     pub vertex_resistibility: bool,
     pub girth: bool,
     pub cyclic_connectivity: bool,
     pub oddness: bool,
+    pub oddness_witness: bool,
+    pub weak_oddness: bool,
+    pub weak_oddness_witness: bool,
+    pub colourings_count: bool,
+    pub automorphism_group_order: bool,
+    pub vertex_orbits: bool,
+    pub edge_orbits: bool,
+    pub flow_number: bool,
+    pub perfect_matching_index: bool,
+    pub fulkerson_cover: bool,
+    pub cdc: bool,
+    pub five_cdc: bool,
 }
 
 impl ChromaticPropertiesToCompute {
@@ -181,11 +293,24 @@ impl ChromaticPropertiesToCompute {
             stable: false,
             costable: false,
             resistance: false,
+            resistance_witness: false,
             edge_resistibility: false,
             vertex_resistibility: false,
             girth: false,
             cyclic_connectivity: false,
             oddness: false,
+            oddness_witness: false,
+            weak_oddness: false,
+            weak_oddness_witness: false,
+            colourings_count: false,
+            automorphism_group_order: false,
+            vertex_orbits: false,
+            edge_orbits: false,
+            flow_number: false,
+            perfect_matching_index: false,
+            fulkerson_cover: false,
+            cdc: false,
+            five_cdc: false,
         }
     }
 }
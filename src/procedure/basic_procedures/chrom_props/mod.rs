@@ -1,2 +1,3 @@
+pub mod checkpoint;
 pub mod chromatic_properties;
 pub mod config;
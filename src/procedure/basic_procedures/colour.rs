@@ -1,11 +1,16 @@
 use std::collections::HashMap;
-use std::marker;
+use std::path::Path;
+use std::time::Instant;
+use std::{fs, marker};
 
 use crate::graph::undirected::UndirectedGraph;
-use crate::procedure::error::Error;
+use crate::procedure::basic_procedures::chrom_props::config::GRAPH_INDEX;
+use crate::procedure::error::{Error, ProcedureError};
 use crate::procedure::helpers::config_helper;
 use crate::procedure::procedure::{GraphProperties, Procedure, Result};
 use crate::procedure::procedure_builder::{ConfigMap, ProcedureBuilder};
+use crate::service::colour::colouring;
+use crate::service::colour::colouring::EdgeColouring;
 use crate::service::colour::colouriser::Colouriser;
 use crate::service::colour::cvd::cvd;
 use crate::service::colour::cvd::cvd_dfs::CvdDfsColourizer;
@@ -13,12 +18,20 @@ use crate::service::colour::cvd::cvd_sat::CvdSatColourizer;
 use crate::service::colour::matchings::matching_col::MatchingColouriser;
 use crate::service::colour::recursive::dfs_improved::DFSColourizer;
 use crate::service::colour::sat::sat::SATColourizer;
+use crate::service::colour::sat::sat_cadical::SATColourizerCadical;
+use crate::service::io::error::WriteError;
 
 // config params
 const COLOURISER_TYPE: &str = "colouriser-type";
+const WITH_COLOURING: &str = "with-colouring";
+const PROOF_DIR: &str = "proof-dir";
+const WITH_TIME: &str = "with-time";
 
 // result properties
 const COLOURABLE: &str = "colourable";
+const COLOURING: &str = "colouring";
+const UNSAT_PROOF: &str = "unsat-proof";
+const COLOUR_TIME_US: &str = "colour-time-us";
 
 // coloriser types
 const DFS: &str = "dfs";
@@ -26,6 +39,7 @@ const SAT: &str = "sat";
 const CVD: &str = "cvd";
 const CVD_DFS: &str = "cvd-dfs";
 const CVD_SAT: &str = "cvd-sat";
+const SAT_CADICAL: &str = "sat-cadical";
 const MATCHING: &str = "matching";
 const AUTO: &str = "auto";
 
@@ -33,6 +47,7 @@ const AUTO: &str = "auto";
 pub enum ColouriserType {
     Dfs,
     Sat,
+    SatCadical,
     Cvd,
     CvdDfs,
     CvdSat,
@@ -46,6 +61,7 @@ impl ColouriserType {
         match string.as_str() {
             DFS => col_type = ColouriserType::Dfs,
             SAT => col_type = ColouriserType::Sat,
+            SAT_CADICAL => col_type = ColouriserType::SatCadical,
             CVD => col_type = ColouriserType::Cvd,
             CVD_DFS => col_type = ColouriserType::CvdDfs,
             CVD_SAT => col_type = ColouriserType::CvdSat,
@@ -53,8 +69,8 @@ impl ColouriserType {
             AUTO => col_type = ColouriserType::Auto,
             &_ => {
                 return Err(Error::ConfigError(String::from(format!(
-                    "unknown colouriser type: {}, did you mean {}, {}, {}, {}, {}, {} or {}?",
-                    string, DFS, SAT, MATCHING, CVD, CVD_DFS, CVD_SAT, AUTO
+                    "unknown colouriser type: {}, did you mean {}, {}, {}, {}, {}, {}, {} or {}?",
+                    string, DFS, SAT, SAT_CADICAL, MATCHING, CVD, CVD_DFS, CVD_SAT, AUTO
                 ))));
             }
         }
@@ -64,11 +80,16 @@ impl ColouriserType {
 
 struct ColourProcedure<G: UndirectedGraph> {
     config: ColourProcedureConfig,
+    // graphs coloured in previous chunks in streaming mode
+    processed: usize,
     _ph: marker::PhantomData<G>,
 }
 
 pub struct ColourProcedureConfig {
     colouriser_type: ColouriserType,
+    with_colouring: bool,
+    proof_dir: Option<String>,
+    with_time: bool,
 }
 
 pub struct ColourProcedureBuilder {}
@@ -76,31 +97,83 @@ pub struct ColourProcedureBuilder {}
 impl<G: UndirectedGraph> Procedure<G> for ColourProcedure<G> {
     fn run(&self, graphs: &mut Vec<(G, GraphProperties)>) -> Result<()> {
         println!("running colour procedure");
-        self.colour_graph(graphs)
+        self.colour_graph(graphs, 0)
+    }
+
+    fn run_chunk(&mut self, graphs: &mut Vec<(G, GraphProperties)>) -> Result<()> {
+        self.colour_graph(graphs, self.processed)?;
+        self.processed += graphs.len();
+        Ok(())
     }
 }
 
 impl<G: UndirectedGraph> ColourProcedure<G> {
-    pub fn colour_graph(&self, graphs: &mut Vec<(G, GraphProperties)>) -> Result<()> {
+    fn new(config: ColourProcedureConfig) -> Self {
+        ColourProcedure {
+            config,
+            processed: 0,
+            _ph: marker::PhantomData,
+        }
+    }
+
+    ///
+    /// first_index - index of first of graphs in whole input (chunks in streaming mode)
+    ///
+    pub fn colour_graph(
+        &self,
+        graphs: &mut Vec<(G, GraphProperties)>,
+        first_index: usize,
+    ) -> Result<()> {
         let colouriser_type = self.config.colouriser_type();
+        let with_colouring = self.config.with_colouring();
+        let with_time = self.config.with_time();
         match colouriser_type {
             ColouriserType::Dfs => {
-                Self::color_by_colourizer::<DFSColourizer>(graphs);
+                Self::color_by_colourizer::<DFSColourizer>(graphs, with_colouring, with_time)?;
             }
             ColouriserType::Sat => {
-                Self::color_by_colourizer::<SATColourizer>(graphs);
+                if let Some(proof_dir) = self.config.proof_dir() {
+                    Self::color_by_sat_with_proofs(
+                        graphs,
+                        false,
+                        with_colouring,
+                        with_time,
+                        proof_dir,
+                        first_index,
+                    )?;
+                } else {
+                    Self::color_by_colourizer::<SATColourizer>(graphs, with_colouring, with_time)?;
+                }
+            }
+            ColouriserType::SatCadical => {
+                if let Some(proof_dir) = self.config.proof_dir() {
+                    Self::color_by_sat_with_proofs(
+                        graphs,
+                        true,
+                        with_colouring,
+                        with_time,
+                        proof_dir,
+                        first_index,
+                    )?;
+                } else {
+                    Self::color_by_colourizer::<SATColourizerCadical>(
+                        graphs,
+                        with_colouring,
+                        with_time,
+                    )?;
+                }
             }
             ColouriserType::CvdDfs => {
-                Self::color_by_colourizer::<CvdDfsColourizer>(graphs);
+                Self::color_by_colourizer::<CvdDfsColourizer>(graphs, with_colouring, with_time)?;
             }
             ColouriserType::CvdSat => {
-                Self::color_by_colourizer::<CvdSatColourizer>(graphs);
+                Self::color_by_colourizer::<CvdSatColourizer>(graphs, with_colouring, with_time)?;
             }
             ColouriserType::Matching => {
-                Self::color_by_colourizer::<MatchingColouriser>(graphs);
+                Self::color_by_colourizer::<MatchingColouriser>(graphs, with_colouring, with_time)?;
             }
             ColouriserType::Cvd => {
-                Self::color_by_colourizer_cvd(graphs);
+                Self::color_by_colourizer_cvd(graphs, with_colouring, with_time)?;
             }
             _ => {
                 return Err(Error::ConfigError(String::from(
@@ -111,40 +184,164 @@ impl<G: UndirectedGraph> ColourProcedure<G> {
         Ok(())
     }
 
-    fn color_by_colourizer<C: Colouriser>(graphs: &mut Vec<(G, GraphProperties)>) {
+    fn color_by_colourizer<C: Colouriser>(
+        graphs: &mut Vec<(G, GraphProperties)>,
+        with_colouring: bool,
+        with_time: bool,
+    ) -> Result<()> {
         for graph in graphs {
+            let begin = Instant::now();
+            if with_colouring {
+                let colouring = C::colouring(&graph.0);
+                Self::insert_time(graph, begin, with_time);
+                Self::insert_colouring(graph, colouring.as_ref())?;
+                graph.1.insert(
+                    COLOURABLE.to_string(),
+                    serde_json::Value::Bool(colouring.is_some()),
+                );
+                continue;
+            }
             let result = C::is_colorable(&graph.0);
+            Self::insert_time(graph, begin, with_time);
             graph
                 .1
                 .insert(COLOURABLE.to_string(), serde_json::Value::Bool(result));
         }
+        Ok(())
     }
 
     ///
     /// color by heuristic
     ///
-    fn color_by_colourizer_cvd(graphs: &mut Vec<(G, GraphProperties)>) {
+    fn color_by_colourizer_cvd(
+        graphs: &mut Vec<(G, GraphProperties)>,
+        with_colouring: bool,
+        with_time: bool,
+    ) -> Result<()> {
         for graph in graphs {
-            let result_option = cvd::is_colorable(&graph.0);
-            if let Some(result) = result_option {
+            let begin = Instant::now();
+            let colouring = cvd::colouring(&graph.0);
+            Self::insert_time(graph, begin, with_time);
+            if colouring.is_some() {
                 graph
                     .1
-                    .insert(COLOURABLE.to_string(), serde_json::Value::Bool(result));
+                    .insert(COLOURABLE.to_string(), serde_json::Value::Bool(true));
+                if with_colouring {
+                    Self::insert_colouring(graph, colouring.as_ref())?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    ///
+    /// for each non colourable graph writes its formula (DIMACS CNF) and DRAT proof of
+    /// unsatisfiability to proof_dir, nothing is written for colourable graphs
+    ///
+    fn color_by_sat_with_proofs(
+        graphs: &mut Vec<(G, GraphProperties)>,
+        cadical: bool,
+        with_colouring: bool,
+        with_time: bool,
+        proof_dir: &String,
+        first_index: usize,
+    ) -> Result<()> {
+        fs::create_dir_all(proof_dir).map_err(WriteError::from)?;
+        for (index, graph) in graphs.iter_mut().enumerate() {
+            let graph_index = match graph.1.get(GRAPH_INDEX).and_then(|value| value.as_u64()) {
+                Some(graph_index) => graph_index as usize,
+                None => first_index + index,
+            };
+            let cnf_path = Path::new(proof_dir).join(format!("graph-{}.cnf", graph_index));
+            let proof_path = Path::new(proof_dir).join(format!("graph-{}.drat", graph_index));
+            let mut cnf = vec![];
+            let mut proof = vec![];
+            let begin = Instant::now();
+            let colouring = if cadical {
+                SATColourizerCadical::colouring_with_proof(&graph.0, &mut cnf, &mut proof)
+            } else {
+                SATColourizer::colouring_with_proof(&graph.0, &mut cnf, &mut proof)
+            }
+            .map_err(WriteError::from)?;
+            Self::insert_time(graph, begin, with_time);
+            graph.1.insert(
+                COLOURABLE.to_string(),
+                serde_json::Value::Bool(colouring.is_some()),
+            );
+            if colouring.is_some() {
+                if with_colouring {
+                    Self::insert_colouring(graph, colouring.as_ref())?;
+                }
+                continue;
+            }
+
+            fs::write(&cnf_path, cnf).map_err(WriteError::from)?;
+            fs::write(&proof_path, proof).map_err(WriteError::from)?;
+            let unsat_proof = serde_json::json!({
+                "cnf": cnf_path.to_string_lossy(),
+                "drat": proof_path.to_string_lossy()
+            });
+            graph.1.insert(UNSAT_PROOF.to_string(), unsat_proof);
+        }
+        Ok(())
+    }
+
+    ///
+    /// time of colouring in microseconds, verification of colouring is not included
+    ///
+    fn insert_time(graph: &mut (G, GraphProperties), begin: Instant, with_time: bool) {
+        if with_time {
+            let time = begin.elapsed().as_micros() as u64;
+            graph
+                .1
+                .insert(COLOUR_TIME_US.to_string(), serde_json::Value::from(time));
+        }
+    }
+
+    ///
+    /// colouring is verified before it is stored
+    ///
+    fn insert_colouring(
+        graph: &mut (G, GraphProperties),
+        colouring: Option<&EdgeColouring>,
+    ) -> Result<()> {
+        if let Some(colouring) = colouring {
+            if !colouring::is_valid_colouring(&graph.0, colouring) {
+                return Err(Error::ProcedureError(ProcedureError {
+                    message: "colouriser returned invalid colouring".to_string(),
+                }));
             }
+            graph
+                .1
+                .insert(COLOURING.to_string(), colouring.to_json_value());
         }
+        Ok(())
     }
 }
 
 impl ColourProcedureConfig {
     pub const PROC_TYPE: &'static str = "colour";
 
-    pub fn new(colouriser_type: ColouriserType) -> Self {
-        ColourProcedureConfig { colouriser_type }
+    pub fn new(
+        colouriser_type: ColouriserType,
+        with_colouring: bool,
+        proof_dir: Option<String>,
+        with_time: bool,
+    ) -> Self {
+        ColourProcedureConfig {
+            colouriser_type,
+            with_colouring,
+            proof_dir,
+            with_time,
+        }
     }
 
     pub fn default() -> Self {
         ColourProcedureConfig {
             colouriser_type: ColouriserType::Dfs,
+            with_colouring: false,
+            proof_dir: None,
+            with_time: false,
         }
     }
 
@@ -152,6 +349,28 @@ impl ColourProcedureConfig {
         &self.colouriser_type
     }
 
+    pub fn with_colouring(&self) -> bool {
+        self.with_colouring
+    }
+
+    pub fn proof_dir(&self) -> Option<&String> {
+        self.proof_dir.as_ref()
+    }
+
+    pub fn with_time(&self) -> bool {
+        self.with_time
+    }
+
+    ///
+    /// only sat colourisers keep parallel edges apart
+    ///
+    pub fn handles_parallel_edges(&self) -> bool {
+        matches!(
+            self.colouriser_type,
+            ColouriserType::Sat | ColouriserType::SatCadical
+        )
+    }
+
     pub fn from_proc_config(config: &HashMap<String, serde_json::Value>) -> Result<Self> {
         let colouriser_type = config_helper::resolve_value_or_default(
             &config,
@@ -160,8 +379,38 @@ impl ColourProcedureConfig {
             Self::PROC_TYPE,
         )?;
 
+        let with_colouring = config_helper::resolve_value_or_default(
+            &config,
+            WITH_COLOURING,
+            false,
+            Self::PROC_TYPE,
+        )?;
+
+        let proof_dir: Option<String> =
+            config_helper::resolve_value_or_default(&config, PROOF_DIR, None, Self::PROC_TYPE)?;
+        let with_time =
+            config_helper::resolve_value_or_default(&config, WITH_TIME, false, Self::PROC_TYPE)?;
+        let colouriser_type = ColouriserType::from_string(&colouriser_type)?;
+        if proof_dir.is_some()
+            && !matches!(
+                colouriser_type,
+                ColouriserType::Sat | ColouriserType::SatCadical
+            )
+        {
+            return Err(Error::ConfigError(format!(
+                "field '{}' for procedure '{}' is supported only for colouriser types {} and {}",
+                PROOF_DIR,
+                Self::PROC_TYPE,
+                SAT,
+                SAT_CADICAL
+            )));
+        }
+
         let result = ColourProcedureConfig {
-            colouriser_type: ColouriserType::from_string(&colouriser_type)?,
+            colouriser_type,
+            with_colouring,
+            proof_dir,
+            with_time,
         };
         Ok(result)
     }
@@ -170,10 +419,7 @@ impl ColourProcedureConfig {
 impl<G: UndirectedGraph + 'static> ProcedureBuilder<G> for ColourProcedureBuilder {
     fn build_from_map(&self, config: ConfigMap) -> Result<Box<dyn Procedure<G>>> {
         let proc_config = ColourProcedureConfig::from_proc_config(&config)?;
-        Ok(Box::new(ColourProcedure {
-            config: proc_config,
-            _ph: marker::PhantomData,
-        }))
+        Ok(Box::new(ColourProcedure::new(proc_config)))
     }
 }
 
@@ -181,9 +427,6 @@ impl ColourProcedureBuilder {
     pub fn build<G: UndirectedGraph + 'static>(
         config: ColourProcedureConfig,
     ) -> Box<dyn Procedure<G>> {
-        Box::new(ColourProcedure {
-            config,
-            _ph: marker::PhantomData,
-        })
+        Box::new(ColourProcedure::new(config))
     }
 }
@@ -1,5 +1,10 @@
-use crate::graph::graph::GraphConstructor;
+use crate::graph::edge::{Edge, EdgeConstructor};
+use crate::graph::graph::{Graph, GraphConstructor};
+use crate::graph::undirected::edge::UndirectedEdge;
+use crate::graph::undirected::simple_graph::graph::SimpleGraph;
 use crate::graph::undirected::UndirectedGraph;
+use crate::procedure::basic_procedures::chrom_props::config::GRAPH_INDEX;
+use crate::procedure::error::Error;
 use crate::procedure::helpers::config_helper;
 use crate::procedure::procedure;
 use crate::procedure::procedure::{GraphProperties, Procedure};
@@ -9,21 +14,56 @@ use crate::service::colour::recursive::dfs_improved::DFSColourizer;
 use crate::service::constructions::dot_product::DotProducts;
 use crate::service::constructions::error::ConstructionError;
 use crate::service::constructions::i_extension::IExtensions;
+use crate::service::constructions::multipole::Multipole;
+use crate::service::constructions::superposition::{Superedge, Superposition, Supervertex};
 use crate::service::constructions::y_extension::YExtensions;
-use std::collections::HashMap;
+use crate::service::io::reader_g6::G6Reader;
+use crate::service::symmetry::canonical;
+use serde::Deserialize;
+use std::collections::{HashMap, HashSet};
 use std::str::FromStr;
-use std::{marker, result};
+use std::{fmt, marker, result};
 
 pub type Result<T> = result::Result<T, ConstructionError>;
 
 // config fields
 const CONSTRUCTION_TYPE: &str = "construction-type";
+const MAX_EXTENSIONS: &str = "max-extensions";
+const REJECT_ISOMORPHIC: &str = "reject-isomorphic";
+const SUPERVERTICES: &str = "supervertices";
+const SUPEREDGES: &str = "superedges";
+
+// properties
+pub const CONSTRUCTION: &str = "construction";
+pub const PARENT_GRAPH_INDEX: &str = "parent-graph-index";
+pub const CONSTRUCTION_EDGES: &str = "construction-edges";
+pub const CONSTRUCTION_VERTICES: &str = "construction-vertices";
+pub const EXTENSIONS_COUNT: &str = "extensions-count";
+
+// construction types
+const DOT_PRODUCT: &str = "dot-product";
+const I_EXTENSION: &str = "i-extension";
+const Y_EXTENSION: &str = "y-extension";
+const SUPERPOSITION: &str = "superposition";
 
 #[derive(Debug)]
 pub enum ConstructionType {
     DotProduct,
     IExtension,
     YExtension,
+    Superposition,
+}
+
+impl fmt::Display for ConstructionType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            ConstructionType::DotProduct => DOT_PRODUCT,
+            ConstructionType::IExtension => I_EXTENSION,
+            ConstructionType::YExtension => Y_EXTENSION,
+            ConstructionType::Superposition => SUPERPOSITION,
+        };
+        write!(f, "{}", name)
+    }
 }
 
 impl FromStr for ConstructionType {
@@ -31,9 +71,10 @@ impl FromStr for ConstructionType {
 
     fn from_str(input: &str) -> Result<Self> {
         match input {
-            "dot-product" => Ok(ConstructionType::DotProduct),
-            "i-extension" => Ok(ConstructionType::IExtension),
-            "y-extension" => Ok(ConstructionType::YExtension),
+            DOT_PRODUCT => Ok(ConstructionType::DotProduct),
+            I_EXTENSION => Ok(ConstructionType::IExtension),
+            Y_EXTENSION => Ok(ConstructionType::YExtension),
+            SUPERPOSITION => Ok(ConstructionType::Superposition),
             _ => Err(ConstructionError::new(format!(
                 "unknown construction type: {}",
                 input
@@ -44,11 +85,61 @@ impl FromStr for ConstructionType {
 
 struct ConstructionProcedure<G> {
     config: ConstructionProcedureConfig,
+    // canonical forms of constructed graphs if reject-isomorphic is set, kept between chunks in
+    // streaming mode - grows with number of constructed graphs
+    constructed: HashSet<String>,
+    // graphs processed in previous chunks in streaming mode
+    processed: usize,
     _ph: marker::PhantomData<G>,
 }
 
 pub struct ConstructionProcedureConfig {
     construction_type: ConstructionType,
+    max_extensions: Option<usize>,
+    reject_isomorphic: bool,
+    supervertices: Vec<(usize, Supervertex)>,
+    superedges: Vec<((usize, usize), Superedge)>,
+}
+
+///
+/// Multipole made of graph (in g6 format) by removing vertex - dangling edges are edges of
+/// removed vertex in order of its neighbors - or by removing edges - each removed edge
+/// (from, to) gives dangling edge of from and dangling edge of to
+///
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct MultipoleConfig {
+    pub graph: String,
+    #[serde(default)]
+    pub removed_vertex: Option<usize>,
+    #[serde(default)]
+    pub removed_edges: Option<Vec<(usize, usize)>>,
+}
+
+///
+/// Multipole replacing vertex of superposed graph - by default each dangling edge forms its own
+/// connector, i-th connector is joined towards i-th neighbor of vertex
+///
+#[derive(Debug, Deserialize)]
+pub struct SupervertexConfig {
+    pub vertex: usize,
+    #[serde(flatten)]
+    pub multipole: MultipoleConfig,
+    #[serde(default)]
+    pub connectors: Option<Vec<Vec<usize>>>,
+}
+
+///
+/// Multipole replacing edge of superposed graph - by default multipole has two dangling edges,
+/// first is joined towards first vertex of edge, second towards second one
+///
+#[derive(Debug, Deserialize)]
+pub struct SuperedgeConfig {
+    pub edge: (usize, usize),
+    #[serde(flatten)]
+    pub multipole: MultipoleConfig,
+    #[serde(default)]
+    pub connectors: Option<[Vec<usize>; 2]>,
 }
 
 pub struct ConstructionProcedureBuilder {}
@@ -59,60 +150,271 @@ impl<G: UndirectedGraph + GraphConstructor + Clone> Procedure<G> for Constructio
             "running {} procedure",
             ConstructionProcedureConfig::PROC_TYPE
         );
-        self.construct(graphs)?;
+        let mut constructed = HashSet::new();
+        self.construct(graphs, &mut constructed, 0)?;
+        Ok(())
+    }
+
+    fn run_chunk(&mut self, graphs: &mut Vec<(G, GraphProperties)>) -> procedure::Result<()> {
+        let mut constructed = std::mem::take(&mut self.constructed);
+        let parents = graphs.len();
+        let result = self.construct(graphs, &mut constructed, self.processed);
+        self.constructed = constructed;
+        self.processed += parents;
+        result?;
         Ok(())
     }
 }
 
 impl<G: UndirectedGraph + GraphConstructor + Clone> ConstructionProcedure<G> {
-    pub fn construct(&self, graphs: &mut Vec<(G, GraphProperties)>) -> Result<()> {
-        // for now just constructing fist possible extension of given graph
+    fn new(config: ConstructionProcedureConfig) -> Self {
+        ConstructionProcedure {
+            config,
+            constructed: HashSet::new(),
+            processed: 0,
+            _ph: marker::PhantomData,
+        }
+    }
 
+    ///
+    /// appends all (at most max_extensions per graph) constructions of each graph to graphs,
+    /// graphs without any construction are left as they are
+    ///
+    pub fn construct(
+        &self,
+        graphs: &mut Vec<(G, GraphProperties)>,
+        constructed: &mut HashSet<String>,
+        first_index: usize,
+    ) -> Result<()> {
         let mut extended_graphs = vec![];
-        for graph in graphs.iter() {
+        for (index, graph) in graphs.iter_mut().enumerate() {
+            let parent_index = match graph.1.get(GRAPH_INDEX).and_then(|value| value.as_u64()) {
+                Some(parent_index) => parent_index as usize,
+                None => first_index + index,
+            };
+            let mut extensions = 0;
+            let mut add = |extended: G, provenance: GraphProperties| -> Result<bool> {
+                if self.config.reject_isomorphic()
+                    && !constructed.insert(canonical::canonical_string(&extended))
+                {
+                    return Ok(true);
+                }
+                let mut properties = provenance;
+                properties.insert(
+                    CONSTRUCTION.to_string(),
+                    serde_json::to_value(self.config.construction_type().to_string())?,
+                );
+                properties.insert(
+                    PARENT_GRAPH_INDEX.to_string(),
+                    serde_json::to_value(parent_index)?,
+                );
+                extended_graphs.push((extended, properties));
+                extensions += 1;
+                Ok(self
+                    .config
+                    .max_extensions()
+                    .map(|max_extensions| extensions < max_extensions)
+                    .unwrap_or(true))
+            };
             match self.config.construction_type {
                 ConstructionType::DotProduct => {
                     let mut dot_products = DotProducts::new(&graph.0, &graph.0);
-                    let extended = dot_products.next().unwrap();
-                    extended_graphs.push((extended, GraphProperties::new()));
+                    while let Some((extended, edges, vertices)) = dot_products.next_with_parts() {
+                        let mut provenance = Self::edges_provenance(&edges)?;
+                        provenance.insert(
+                            CONSTRUCTION_VERTICES.to_string(),
+                            serde_json::to_value(vertices)?,
+                        );
+                        if !add(extended, provenance)? {
+                            break;
+                        }
+                    }
                 }
                 ConstructionType::IExtension => {
                     let colouriser = DFSColourizer::new();
                     let mut i_extensions = IExtensions::new(&graph.0, &colouriser);
-                    let extended = i_extensions.next().unwrap();
-                    extended_graphs.push((extended, GraphProperties::new()));
+                    while let Some((extended, edges)) = i_extensions.next_with_edges() {
+                        if !add(extended, Self::edges_provenance(&edges)?)? {
+                            break;
+                        }
+                    }
                 }
                 ConstructionType::YExtension => {
                     let colouriser = DFSColourizer::new();
                     let mut y_extensions = YExtensions::new(&graph.0, &colouriser);
-                    let extended = y_extensions.next().unwrap();
-                    extended_graphs.push((extended, GraphProperties::new()));
+                    while let Some((extended, edges)) = y_extensions.next_with_edges() {
+                        if !add(extended, Self::edges_provenance(&edges)?)? {
+                            break;
+                        }
+                    }
+                }
+                ConstructionType::Superposition => {
+                    let (extended, provenance) = self.superpose(&graph.0)?;
+                    add(extended, provenance)?;
                 }
             }
+            graph.1.insert(
+                EXTENSIONS_COUNT.to_string(),
+                serde_json::to_value(extensions)?,
+            );
         }
         graphs.append(&mut extended_graphs);
         Ok(())
     }
+
+    ///
+    /// superposition of graph by supervertices and superedges of config, replaced vertices and
+    /// edges are kept as provenance
+    ///
+    fn superpose(&self, graph: &G) -> Result<(G, GraphProperties)> {
+        let mut superposition = Superposition::new(graph);
+        for (vertex, supervertex) in self.config.supervertices.iter() {
+            superposition.replace_vertex(*vertex, supervertex.clone())?;
+        }
+        for ((from, to), superedge) in self.config.superedges.iter() {
+            superposition.replace_edge(*from, *to, superedge.clone())?;
+        }
+        let superposed = superposition.superpose()?;
+        let mut extended = G::with_vertices_capacity(superposed.size());
+        for edge in superposed.edges() {
+            extended.add_edge(edge.from(), edge.to());
+        }
+
+        let vertices: Vec<usize> = self
+            .config
+            .supervertices
+            .iter()
+            .map(|(vertex, _)| *vertex)
+            .collect();
+        let edges: Vec<UndirectedEdge> = self
+            .config
+            .superedges
+            .iter()
+            .map(|((from, to), _)| UndirectedEdge::new(*from, *to))
+            .collect();
+        let mut provenance = Self::edges_provenance(&edges)?;
+        provenance.insert(
+            CONSTRUCTION_VERTICES.to_string(),
+            serde_json::to_value(vertices)?,
+        );
+        Ok((extended, provenance))
+    }
+
+    fn edges_provenance(edges: &[UndirectedEdge]) -> Result<GraphProperties> {
+        let edges: Vec<[usize; 2]> = edges.iter().map(|edge| [edge.from(), edge.to()]).collect();
+        let mut properties = GraphProperties::new();
+        properties.insert(CONSTRUCTION_EDGES.to_string(), serde_json::to_value(edges)?);
+        Ok(properties)
+    }
 }
 
 impl ConstructionProcedureConfig {
     pub const PROC_TYPE: &'static str = "construction";
 
     pub fn new(construction_type: ConstructionType) -> Self {
-        ConstructionProcedureConfig { construction_type }
+        ConstructionProcedureConfig {
+            construction_type,
+            max_extensions: None,
+            reject_isomorphic: true,
+            supervertices: vec![],
+            superedges: vec![],
+        }
     }
 
     pub fn from_proc_config(config: &HashMap<String, serde_json::Value>) -> Result<Self> {
         let construction_type_string: String =
             config_helper::resolve_value(&config, CONSTRUCTION_TYPE, Self::PROC_TYPE)?;
         let construction_type = ConstructionType::from_str(&construction_type_string)?;
-        let result = ConstructionProcedureConfig { construction_type };
+        let max_extensions = config_helper::resolve_value_or_default(
+            &config,
+            MAX_EXTENSIONS,
+            None,
+            Self::PROC_TYPE,
+        )?;
+        if max_extensions == Some(0) {
+            return Err(ConstructionError::new(format!(
+                "field '{}' for procedure '{}' has to be greater than 0",
+                MAX_EXTENSIONS,
+                Self::PROC_TYPE
+            )));
+        }
+        let reject_isomorphic = config_helper::resolve_value_or_default(
+            &config,
+            REJECT_ISOMORPHIC,
+            true,
+            Self::PROC_TYPE,
+        )?;
+        let supervertex_configs: Vec<SupervertexConfig> = config_helper::resolve_value_or_default(
+            &config,
+            SUPERVERTICES,
+            vec![],
+            Self::PROC_TYPE,
+        )?;
+        let superedge_configs: Vec<SuperedgeConfig> =
+            config_helper::resolve_value_or_default(&config, SUPEREDGES, vec![], Self::PROC_TYPE)?;
+        if let ConstructionType::Superposition = construction_type {
+            if supervertex_configs.is_empty() && superedge_configs.is_empty() {
+                return Err(ConstructionError::new(format!(
+                    "construction '{}' needs field '{}' or '{}'",
+                    SUPERPOSITION, SUPERVERTICES, SUPEREDGES
+                )));
+            }
+        }
+        let mut supervertices = vec![];
+        for supervertex_config in supervertex_configs {
+            let multipole = supervertex_config.multipole.to_multipole()?;
+            let connectors = supervertex_config.connectors.unwrap_or_else(|| {
+                (0..multipole.dangling_edges().len())
+                    .map(|dangling_edge| vec![dangling_edge])
+                    .collect()
+            });
+            let supervertex = Supervertex::new(multipole, connectors)?;
+            supervertices.push((supervertex_config.vertex, supervertex));
+        }
+        let mut superedges = vec![];
+        for superedge_config in superedge_configs {
+            let multipole = superedge_config.multipole.to_multipole()?;
+            let connectors = superedge_config
+                .connectors
+                .unwrap_or_else(|| [vec![0], vec![1]]);
+            let superedge = Superedge::new(multipole, connectors)?;
+            superedges.push((superedge_config.edge, superedge));
+        }
+
+        let result = ConstructionProcedureConfig {
+            construction_type,
+            max_extensions,
+            reject_isomorphic,
+            supervertices,
+            superedges,
+        };
         Ok(result)
     }
 
     pub fn construction_type(&self) -> &ConstructionType {
         &self.construction_type
     }
+
+    pub fn max_extensions(&self) -> Option<usize> {
+        self.max_extensions
+    }
+
+    pub fn reject_isomorphic(&self) -> bool {
+        self.reject_isomorphic
+    }
+}
+
+impl MultipoleConfig {
+    fn to_multipole(&self) -> Result<Multipole> {
+        let graph: SimpleGraph = G6Reader::read_graph(&self.graph).map_err(Error::from)?;
+        match (self.removed_vertex, &self.removed_edges) {
+            (Some(vertex), None) => Multipole::from_graph_without_vertex(&graph, vertex),
+            (None, Some(edges)) => Multipole::from_graph_without_edges(&graph, edges),
+            _ => Err(ConstructionError::new(
+                "multipole needs exactly one of fields 'removed-vertex' and 'removed-edges'",
+            )),
+        }
+    }
 }
 
 impl<G: UndirectedGraph + 'static + GraphConstructor + Clone> ProcedureBuilder<G>
@@ -120,10 +422,7 @@ impl<G: UndirectedGraph + 'static + GraphConstructor + Clone> ProcedureBuilder<G
 {
     fn build_from_map(&self, config: ConfigMap) -> procedure::Result<Box<dyn Procedure<G>>> {
         let proc_config = ConstructionProcedureConfig::from_proc_config(&config)?;
-        Ok(Box::new(ConstructionProcedure {
-            config: proc_config,
-            _ph: marker::PhantomData,
-        }))
+        Ok(Box::new(ConstructionProcedure::new(proc_config)))
     }
 }
 
@@ -131,9 +430,6 @@ impl ConstructionProcedureBuilder {
     pub fn build<G: UndirectedGraph + GraphConstructor + Clone + 'static>(
         config: ConstructionProcedureConfig,
     ) -> Box<dyn Procedure<G>> {
-        Box::new(ConstructionProcedure {
-            config,
-            _ph: marker::PhantomData,
-        })
+        Box::new(ConstructionProcedure::new(config))
     }
 }
@@ -8,6 +8,8 @@ use crate::procedure::procedure_builder::{ConfigMap, ProcedureBuilder};
 
 struct CounterProcedure<G: UndirectedGraph> {
     config: CounterProcedureConfig,
+    // counts accumulated over chunks in streaming mode
+    counts: HashMap<(String, String), usize>,
     _ph: marker::PhantomData<G>,
 }
 
@@ -21,35 +23,54 @@ impl<G: UndirectedGraph> Procedure<G> for CounterProcedure<G> {
     fn run(&self, graphs: &mut Vec<(G, GraphProperties)>) -> Result<()> {
         println!("running counter procedure");
         let mut props = HashMap::new();
+        Self::count_properties(graphs, &mut props);
+        self.print_counts(&props);
+        Ok(())
+    }
+
+    fn run_chunk(&mut self, graphs: &mut Vec<(G, GraphProperties)>) -> Result<()> {
+        Self::count_properties(graphs, &mut self.counts);
+        Ok(())
+    }
+
+    fn finish(&mut self) -> Result<()> {
+        println!("running counter procedure");
+        self.print_counts(&self.counts);
+        Ok(())
+    }
+}
+
+impl<G: UndirectedGraph> CounterProcedure<G> {
+    fn new(config: CounterProcedureConfig) -> Self {
+        CounterProcedure {
+            config,
+            counts: HashMap::new(),
+            _ph: marker::PhantomData,
+        }
+    }
+
+    fn count_properties(
+        graphs: &[(G, GraphProperties)],
+        props: &mut HashMap<(String, String), usize>,
+    ) {
         for graph in graphs {
             for property in graph.1.iter() {
                 if property.0 == "graph-index" {
                     continue;
                 }
-                let property_hash = (property.0, property.1.to_string());
-
-                // like this because borrow checker
-                let count_opt = props.get(&property_hash);
-                let mut count_opt_copy: Option<usize> = None;
-                if let Some(count) = count_opt {
-                    count_opt_copy = Some(*count);
-                }
-
-                // if let Some(count) = props.get(&property_hash) {
-                if let Some(count) = count_opt_copy {
-                    props.insert(property_hash, count + 1);
-                } else {
-                    props.insert(property_hash, 1);
-                }
+                let property_hash = (property.0.clone(), property.1.to_string());
+                *props.entry(property_hash).or_insert(0) += 1;
             }
         }
+    }
+
+    fn print_counts(&self, props: &HashMap<(String, String), usize>) {
         if self.config.print() {
             println!("count: ");
             for prop in props.iter() {
                 println!("      {:?} : {}", prop.0, prop.1);
             }
         }
-        Ok(())
     }
 }
 
@@ -80,10 +101,7 @@ impl CounterProcedureConfig {
 impl<G: UndirectedGraph + 'static> ProcedureBuilder<G> for CounterProcedureBuilder {
     fn build_from_map(&self, config: ConfigMap) -> Result<Box<dyn Procedure<G>>> {
         let proc_config = CounterProcedureConfig::from_proc_config(&config)?;
-        Ok(Box::new(CounterProcedure {
-            config: proc_config,
-            _ph: marker::PhantomData,
-        }))
+        Ok(Box::new(CounterProcedure::new(proc_config)))
     }
 }
 
@@ -91,9 +109,6 @@ impl CounterProcedureBuilder {
     pub fn build<G: UndirectedGraph + 'static>(
         config: CounterProcedureConfig,
     ) -> Box<dyn Procedure<G>> {
-        Box::new(CounterProcedure {
-            config,
-            _ph: marker::PhantomData,
-        })
+        Box::new(CounterProcedure::new(config))
     }
 }
@@ -0,0 +1,193 @@
+use std::collections::{HashMap, HashSet};
+use std::marker;
+
+use crate::graph::undirected::UndirectedGraph;
+use crate::procedure::error::Error;
+use crate::procedure::helpers::config_helper;
+use crate::procedure::procedure::{GraphProperties, Procedure, Result};
+use crate::procedure::procedure_builder::{ConfigMap, ProcedureBuilder};
+use crate::service::symmetry::canonical;
+
+// config params
+const KEEP: &str = "keep";
+const DUPLICATES_COUNT: &str = "duplicates-count";
+
+// keep options
+pub const KEEP_FIRST: &str = "first";
+pub const KEEP_LAST: &str = "last";
+pub const KEEP_MERGED: &str = "merged";
+
+// graph property
+const DUPLICATES_COUNT_PROPERTY: &str = "duplicates-count";
+
+struct DedupProcedure<G: UndirectedGraph> {
+    config: DedupProcedureConfig,
+    // canonical strings of graphs already passed in streaming mode
+    seen: HashSet<String>,
+    _ph: marker::PhantomData<G>,
+}
+
+pub struct DedupProcedureConfig {
+    keep: String,
+    duplicates_count: bool,
+}
+
+pub struct DedupProcedureBuilder {}
+
+impl<G: UndirectedGraph> Procedure<G> for DedupProcedure<G> {
+    fn run(&self, graphs: &mut Vec<(G, GraphProperties)>) -> Result<()> {
+        println!("running dedup procedure");
+        self.dedup(graphs)
+    }
+
+    fn run_chunk(&mut self, graphs: &mut Vec<(G, GraphProperties)>) -> Result<()> {
+        // graphs of previous chunks are gone already - only first copy can be kept
+        if self.config.keep() != KEEP_FIRST || self.config.duplicates_count() {
+            return Err(Error::ConfigError(format!(
+                "procedure: {} supports only '{}: {}' without '{}' in streaming mode",
+                DedupProcedureConfig::PROC_TYPE,
+                KEEP,
+                KEEP_FIRST,
+                DUPLICATES_COUNT
+            )));
+        }
+        let seen = &mut self.seen;
+        graphs.retain(|graph| seen.insert(canonical::canonical_string(&graph.0)));
+        Ok(())
+    }
+}
+
+impl<G: UndirectedGraph> DedupProcedure<G> {
+    fn new(config: DedupProcedureConfig) -> Self {
+        DedupProcedure {
+            config,
+            seen: HashSet::new(),
+            _ph: marker::PhantomData,
+        }
+    }
+
+    ///
+    /// removes isomorphic copies of graphs - surviving copy stays at position of first copy
+    ///
+    fn dedup(&self, graphs: &mut Vec<(G, GraphProperties)>) -> Result<()> {
+        // canonical string -> position of surviving copy in deduplicated graphs
+        let mut positions: HashMap<String, usize> = HashMap::new();
+        let mut deduplicated: Vec<(G, GraphProperties)> = vec![];
+        let mut duplicates: Vec<usize> = vec![];
+        for graph in graphs.drain(..) {
+            let key = canonical::canonical_string(&graph.0);
+            let position = match positions.get(&key) {
+                Some(position) => *position,
+                None => {
+                    positions.insert(key, deduplicated.len());
+                    deduplicated.push(graph);
+                    duplicates.push(0);
+                    continue;
+                }
+            };
+            duplicates[position] += 1;
+            match self.config.keep().as_str() {
+                KEEP_FIRST => {}
+                KEEP_LAST => deduplicated[position] = graph,
+                KEEP_MERGED => {
+                    // properties of earlier copies take precedence
+                    for (name, value) in graph.1 {
+                        deduplicated[position].1.entry(name).or_insert(value);
+                    }
+                }
+                _ => {
+                    return Err(Error::ConfigError(format!(
+                        "unknown value '{}' of '{}' for procedure: {}",
+                        self.config.keep(),
+                        KEEP,
+                        DedupProcedureConfig::PROC_TYPE
+                    )));
+                }
+            }
+        }
+
+        if self.config.duplicates_count() {
+            for (graph, count) in deduplicated.iter_mut().zip(duplicates.iter()) {
+                graph.1.insert(
+                    DUPLICATES_COUNT_PROPERTY.to_string(),
+                    serde_json::Value::from(*count),
+                );
+            }
+        }
+        *graphs = deduplicated;
+        Ok(())
+    }
+}
+
+impl DedupProcedureConfig {
+    pub const PROC_TYPE: &'static str = "dedup";
+
+    pub fn new(keep: String, duplicates_count: bool) -> Self {
+        DedupProcedureConfig {
+            keep,
+            duplicates_count,
+        }
+    }
+
+    pub fn default() -> Self {
+        DedupProcedureConfig {
+            keep: KEEP_FIRST.to_string(),
+            duplicates_count: false,
+        }
+    }
+
+    pub fn from_proc_config(config: &HashMap<String, serde_json::Value>) -> Result<Self> {
+        let keep = config_helper::resolve_value_or_default(
+            &config,
+            KEEP,
+            KEEP_FIRST.to_string(),
+            Self::PROC_TYPE,
+        )?;
+        match keep.as_str() {
+            KEEP_FIRST | KEEP_LAST | KEEP_MERGED => {}
+            _ => {
+                return Err(Error::ConfigError(format!(
+                    "unknown value '{}' of '{}' for procedure: {}",
+                    keep,
+                    KEEP,
+                    Self::PROC_TYPE
+                )));
+            }
+        }
+        let duplicates_count = config_helper::resolve_value_or_default(
+            &config,
+            DUPLICATES_COUNT,
+            false,
+            Self::PROC_TYPE,
+        )?;
+
+        let result = DedupProcedureConfig {
+            keep,
+            duplicates_count,
+        };
+        Ok(result)
+    }
+
+    pub fn keep(&self) -> &String {
+        &self.keep
+    }
+
+    pub fn duplicates_count(&self) -> bool {
+        self.duplicates_count
+    }
+}
+
+impl<G: UndirectedGraph + 'static> ProcedureBuilder<G> for DedupProcedureBuilder {
+    fn build_from_map(&self, config: ConfigMap) -> Result<Box<dyn Procedure<G>>> {
+        let proc_config = DedupProcedureConfig::from_proc_config(&config)?;
+        Ok(Box::new(DedupProcedure::new(proc_config)))
+    }
+}
+
+impl DedupProcedureBuilder {
+    pub fn build<G: UndirectedGraph + 'static>(
+        config: DedupProcedureConfig,
+    ) -> Box<dyn Procedure<G>> {
+        Box::new(DedupProcedure::new(config))
+    }
+}
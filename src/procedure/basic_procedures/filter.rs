@@ -1,4 +1,5 @@
 use crate::graph::undirected::UndirectedGraph;
+use crate::procedure::basic_procedures::filter_expression::FilterExpression;
 use crate::procedure::error::Error;
 use crate::procedure::helpers::config_helper;
 use crate::procedure::procedure::{GraphProperties, Procedure, Result};
@@ -7,6 +8,10 @@ use serde_json::Value;
 use std::collections::HashMap;
 use std::{marker, result};
 
+// config keys
+const FILTER_BY: &str = "filter-by";
+const FILTER: &str = "filter";
+
 // keys
 const COMPARATOR: &str = "comparator";
 const VALUE: &str = "value";
@@ -26,6 +31,7 @@ struct FilterProcedure<G> {
 
 pub struct FilterProcedureConfig {
     filter_by: GraphProperties,
+    filter: Option<FilterExpression>,
 }
 
 pub struct FilterProcedureBuilder {}
@@ -41,12 +47,22 @@ impl<G: UndirectedGraph> Procedure<G> for FilterProcedure<G> {
         println!("running filter procedure");
         self.filter(graphs)
     }
+
+    fn run_chunk(&mut self, graphs: &mut Vec<(G, GraphProperties)>) -> Result<()> {
+        self.filter(graphs)
+    }
 }
 
 impl<G: UndirectedGraph> FilterProcedure<G> {
     pub fn filter(&self, graphs: &mut Vec<(G, GraphProperties)>) -> Result<()> {
         let filter_properties = self.config.filter_by();
+        let filter = self.config.filter();
         graphs.retain(|graph| {
+            if let Some(filter) = filter {
+                if !filter.evaluate(&graph.1) {
+                    return false;
+                }
+            }
             let mut retain = true;
             for filter_property in filter_properties {
                 let mut has_property = false;
@@ -163,19 +179,55 @@ impl FilterProcedureConfig {
     pub const PROC_TYPE: &'static str = "filter";
 
     pub fn new(filter_by: GraphProperties) -> Self {
-        FilterProcedureConfig { filter_by }
+        FilterProcedureConfig {
+            filter_by,
+            filter: None,
+        }
     }
 
-    pub fn from_proc_config(config: &HashMap<String, serde_json::Value>) -> Result<Self> {
-        let filter_by = config_helper::resolve_value(&config, "filter-by", Self::PROC_TYPE)?;
+    ///
+    /// expression - e.g. `colourable == false && (oddness >= 4 || resistance > 2)`
+    ///
+    pub fn from_expression(expression: &str) -> Result<Self> {
+        Ok(FilterProcedureConfig {
+            filter_by: GraphProperties::new(),
+            filter: Some(FilterExpression::parse(expression)?),
+        })
+    }
 
-        let result = FilterProcedureConfig { filter_by };
+    pub fn from_proc_config(config: &HashMap<String, serde_json::Value>) -> Result<Self> {
+        if !config.contains_key(FILTER_BY) && !config.contains_key(FILTER) {
+            return Err(Error::ConfigError(format!(
+                "field '{}' or '{}' for procedure '{}' is missing",
+                FILTER_BY,
+                FILTER,
+                Self::PROC_TYPE
+            )));
+        }
+        let filter_by = config_helper::resolve_value_or_default(
+            &config,
+            FILTER_BY,
+            GraphProperties::new(),
+            Self::PROC_TYPE,
+        )?;
+        let expression: Option<String> =
+            config_helper::resolve_value_or_default(&config, FILTER, None, Self::PROC_TYPE)?;
+        let filter = match expression {
+            Some(expression) => Some(FilterExpression::parse(&expression)?),
+            None => None,
+        };
+
+        let result = FilterProcedureConfig { filter_by, filter };
         Ok(result)
     }
 
     pub fn filter_by(&self) -> &GraphProperties {
         &self.filter_by
     }
+
+    pub fn filter(&self) -> Option<&FilterExpression> {
+        self.filter.as_ref()
+    }
 }
 
 impl<G: UndirectedGraph + 'static> ProcedureBuilder<G> for FilterProcedureBuilder {
@@ -0,0 +1,509 @@
+use crate::procedure::error::Error;
+use crate::procedure::procedure::{GraphProperties, Result};
+use serde_json::Value;
+use std::cmp::Ordering;
+use std::iter::Peekable;
+use std::str::Chars;
+
+const EXISTS: &str = "exists";
+const LENGTH: &str = "len";
+
+///
+/// Boolean expression over graph properties, e.g.
+/// `colourable == false && (oddness >= 4 || resistance > 2)`.
+///
+/// Supports `&&`, `||`, `!`, parentheses, comparisons `==` (or `=`), `!=`, `>`, `<`, `>=`, `<=`
+/// of property with number, boolean or string literal, `len(property)` - length of array (string,
+/// object) property, `exists(property)` and bare property name - true if property is `true`.
+/// Property names may start with digit (e.g. `5-cdc`) unless they form number.
+/// Comparison of missing property or of values of different types is false (except of `!=`).
+///
+#[derive(Debug, Clone, PartialEq)]
+pub enum FilterExpression {
+    And(Box<FilterExpression>, Box<FilterExpression>),
+    Or(Box<FilterExpression>, Box<FilterExpression>),
+    Not(Box<FilterExpression>),
+    Exists(String),
+    IsTrue(String),
+    Compare(Operand, Operator, Value),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Operand {
+    Property(String),
+    Length(String),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Operator {
+    Equal,
+    NotEqual,
+    Greater,
+    Less,
+    GreaterOrEqual,
+    LessOrEqual,
+}
+
+impl FilterExpression {
+    pub fn parse(expression: &str) -> Result<FilterExpression> {
+        let tokens = tokenize(expression)?;
+        let mut parser = Parser {
+            tokens,
+            position: 0,
+            expression,
+        };
+        let result = parser.parse_or()?;
+        if parser.position < parser.tokens.len() {
+            return Err(parser.error(&format!("unexpected '{}'", parser.tokens[parser.position])));
+        }
+        Ok(result)
+    }
+
+    pub fn evaluate(&self, properties: &GraphProperties) -> bool {
+        match self {
+            FilterExpression::And(first, second) => {
+                first.evaluate(properties) && second.evaluate(properties)
+            }
+            FilterExpression::Or(first, second) => {
+                first.evaluate(properties) || second.evaluate(properties)
+            }
+            FilterExpression::Not(inner) => !inner.evaluate(properties),
+            FilterExpression::Exists(property) => properties.contains_key(property),
+            FilterExpression::IsTrue(property) => {
+                properties.get(property) == Some(&Value::Bool(true))
+            }
+            FilterExpression::Compare(operand, operator, value) => {
+                let ordering = match operand.value(properties) {
+                    Some(property_value) => compare(&property_value, value),
+                    None => None,
+                };
+                operator.holds(ordering)
+            }
+        }
+    }
+}
+
+impl Operand {
+    fn value(&self, properties: &GraphProperties) -> Option<Value> {
+        match self {
+            Operand::Property(property) => properties.get(property).cloned(),
+            Operand::Length(property) => {
+                let length = match properties.get(property)? {
+                    Value::Array(array) => array.len(),
+                    Value::String(string) => string.chars().count(),
+                    Value::Object(object) => object.len(),
+                    _ => return None,
+                };
+                Some(Value::from(length))
+            }
+        }
+    }
+}
+
+impl Operator {
+    fn symbol(&self) -> &'static str {
+        match self {
+            Operator::Equal => "==",
+            Operator::NotEqual => "!=",
+            Operator::Greater => ">",
+            Operator::Less => "<",
+            Operator::GreaterOrEqual => ">=",
+            Operator::LessOrEqual => "<=",
+        }
+    }
+
+    ///
+    /// ordering - None if values are not comparable
+    ///
+    fn holds(&self, ordering: Option<Ordering>) -> bool {
+        match (self, ordering) {
+            (Operator::NotEqual, None) => true,
+            (_, None) => false,
+            (Operator::Equal, Some(ordering)) => ordering == Ordering::Equal,
+            (Operator::NotEqual, Some(ordering)) => ordering != Ordering::Equal,
+            (Operator::Greater, Some(ordering)) => ordering == Ordering::Greater,
+            (Operator::Less, Some(ordering)) => ordering == Ordering::Less,
+            (Operator::GreaterOrEqual, Some(ordering)) => ordering != Ordering::Less,
+            (Operator::LessOrEqual, Some(ordering)) => ordering != Ordering::Greater,
+        }
+    }
+}
+
+///
+/// numbers are compared as floats, booleans only for (in)equality
+///
+fn compare(property_value: &Value, value: &Value) -> Option<Ordering> {
+    match (property_value, value) {
+        (Value::Number(first), Value::Number(second)) => {
+            first.as_f64()?.partial_cmp(&second.as_f64()?)
+        }
+        (Value::String(first), Value::String(second)) => Some(first.cmp(second)),
+        (Value::Bool(first), Value::Bool(second)) => {
+            if first == second {
+                Some(Ordering::Equal)
+            } else {
+                None
+            }
+        }
+        _ => None,
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    LeftParenthesis,
+    RightParenthesis,
+    And,
+    Or,
+    Not,
+    Operator(Operator),
+    Identifier(String),
+    Literal(Value),
+}
+
+impl std::fmt::Display for Token {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Token::LeftParenthesis => write!(f, "("),
+            Token::RightParenthesis => write!(f, ")"),
+            Token::And => write!(f, "&&"),
+            Token::Or => write!(f, "||"),
+            Token::Not => write!(f, "!"),
+            Token::Operator(operator) => write!(f, "{}", operator.symbol()),
+            Token::Identifier(identifier) => write!(f, "{}", identifier),
+            Token::Literal(value) => write!(f, "{}", value),
+        }
+    }
+}
+
+fn tokenize(expression: &str) -> Result<Vec<Token>> {
+    let mut tokens = vec![];
+    let mut chars = expression.chars().peekable();
+    while let Some(next) = chars.peek().cloned() {
+        if next.is_whitespace() {
+            chars.next();
+            continue;
+        }
+        let token = match next {
+            '(' => single(&mut chars, Token::LeftParenthesis),
+            ')' => single(&mut chars, Token::RightParenthesis),
+            '&' | '|' => {
+                chars.next();
+                if chars.next() != Some(next) {
+                    return Err(syntax_error(
+                        expression,
+                        &format!("expected '{}{}'", next, next),
+                    ));
+                }
+                if next == '&' {
+                    Token::And
+                } else {
+                    Token::Or
+                }
+            }
+            '!' | '=' | '<' | '>' => {
+                chars.next();
+                let with_equals = chars.peek() == Some(&'=');
+                if with_equals {
+                    chars.next();
+                }
+                match (next, with_equals) {
+                    ('!', false) => Token::Not,
+                    ('!', true) => Token::Operator(Operator::NotEqual),
+                    ('=', _) => Token::Operator(Operator::Equal),
+                    ('<', false) => Token::Operator(Operator::Less),
+                    ('<', true) => Token::Operator(Operator::LessOrEqual),
+                    ('>', false) => Token::Operator(Operator::Greater),
+                    _ => Token::Operator(Operator::GreaterOrEqual),
+                }
+            }
+            '"' | '\'' => {
+                chars.next();
+                let mut string = String::new();
+                loop {
+                    match chars.next() {
+                        Some(character) if character == next => break,
+                        Some(character) => string.push(character),
+                        None => return Err(syntax_error(expression, "unterminated string")),
+                    }
+                }
+                Token::Literal(Value::String(string))
+            }
+            _ if next.is_ascii_digit() || next == '-' || next == '.' => {
+                let word = take_while(&mut chars, |character| {
+                    character.is_alphanumeric()
+                        || character == '.'
+                        || character == '-'
+                        || character == '_'
+                });
+                match parse_number(expression, &word) {
+                    Ok(number) => Token::Literal(number),
+                    // property name starting with digit, e.g. 5-cdc
+                    Err(_) if next.is_ascii_digit() && !word.contains('.') => {
+                        Token::Identifier(word)
+                    }
+                    Err(error) => return Err(error),
+                }
+            }
+            _ if next.is_alphabetic() || next == '_' => {
+                let identifier = take_while(&mut chars, |character| {
+                    character.is_alphanumeric() || character == '-' || character == '_'
+                });
+                match identifier.as_str() {
+                    "true" => Token::Literal(Value::Bool(true)),
+                    "false" => Token::Literal(Value::Bool(false)),
+                    _ => Token::Identifier(identifier),
+                }
+            }
+            _ => {
+                return Err(syntax_error(
+                    expression,
+                    &format!("unexpected character '{}'", next),
+                ))
+            }
+        };
+        tokens.push(token);
+    }
+    Ok(tokens)
+}
+
+fn single(chars: &mut Peekable<Chars>, token: Token) -> Token {
+    chars.next();
+    token
+}
+
+fn take_while(chars: &mut Peekable<Chars>, predicate: impl Fn(char) -> bool) -> String {
+    let mut result = String::new();
+    while let Some(character) = chars.peek() {
+        if !predicate(*character) {
+            break;
+        }
+        result.push(*character);
+        chars.next();
+    }
+    result
+}
+
+fn parse_number(expression: &str, number: &str) -> Result<Value> {
+    if let Ok(integer) = number.parse::<i64>() {
+        return Ok(Value::from(integer));
+    }
+    match number.parse::<f64>() {
+        Ok(float) => Ok(Value::from(float)),
+        Err(_) => Err(syntax_error(
+            expression,
+            &format!("malformed number '{}'", number),
+        )),
+    }
+}
+
+fn syntax_error(expression: &str, message: &str) -> Error {
+    Error::ConfigError(format!(
+        "malformed filter expression '{}': {}",
+        expression, message
+    ))
+}
+
+///
+/// recursive descent parser - precedence from lowest: ||, &&, !
+///
+struct Parser<'a> {
+    tokens: Vec<Token>,
+    position: usize,
+    expression: &'a str,
+}
+
+impl<'a> Parser<'a> {
+    fn parse_or(&mut self) -> Result<FilterExpression> {
+        let mut result = self.parse_and()?;
+        while self.accept(&Token::Or) {
+            let second = self.parse_and()?;
+            result = FilterExpression::Or(Box::new(result), Box::new(second));
+        }
+        Ok(result)
+    }
+
+    fn parse_and(&mut self) -> Result<FilterExpression> {
+        let mut result = self.parse_not()?;
+        while self.accept(&Token::And) {
+            let second = self.parse_not()?;
+            result = FilterExpression::And(Box::new(result), Box::new(second));
+        }
+        Ok(result)
+    }
+
+    fn parse_not(&mut self) -> Result<FilterExpression> {
+        if self.accept(&Token::Not) {
+            let inner = self.parse_not()?;
+            return Ok(FilterExpression::Not(Box::new(inner)));
+        }
+        self.parse_primary()
+    }
+
+    fn parse_primary(&mut self) -> Result<FilterExpression> {
+        if self.accept(&Token::LeftParenthesis) {
+            let inner = self.parse_or()?;
+            self.expect(&Token::RightParenthesis)?;
+            return Ok(inner);
+        }
+        let identifier = self.identifier()?;
+        let operand = if self.accept(&Token::LeftParenthesis) {
+            let property = self.identifier()?;
+            self.expect(&Token::RightParenthesis)?;
+            match identifier.as_str() {
+                EXISTS => return Ok(FilterExpression::Exists(property)),
+                LENGTH => Operand::Length(property),
+                _ => return Err(self.error(&format!("unknown function '{}'", identifier))),
+            }
+        } else {
+            Operand::Property(identifier)
+        };
+
+        let operator = match self.tokens.get(self.position) {
+            Some(Token::Operator(operator)) => *operator,
+            _ => {
+                return match operand {
+                    Operand::Property(property) => Ok(FilterExpression::IsTrue(property)),
+                    Operand::Length(_) => Err(self.error("expected comparison after len")),
+                }
+            }
+        };
+        self.position += 1;
+        let value = match self.tokens.get(self.position) {
+            Some(Token::Literal(value)) => value.clone(),
+            _ => return Err(self.error("expected value after comparison operator")),
+        };
+        self.position += 1;
+        Ok(FilterExpression::Compare(operand, operator, value))
+    }
+
+    fn identifier(&mut self) -> Result<String> {
+        match self.tokens.get(self.position) {
+            Some(Token::Identifier(identifier)) => {
+                self.position += 1;
+                Ok(identifier.clone())
+            }
+            Some(token) => Err(self.error(&format!("unexpected '{}'", token))),
+            None => Err(self.error("unexpected end of expression")),
+        }
+    }
+
+    fn accept(&mut self, token: &Token) -> bool {
+        if self.tokens.get(self.position) == Some(token) {
+            self.position += 1;
+            return true;
+        }
+        false
+    }
+
+    fn expect(&mut self, token: &Token) -> Result<()> {
+        if self.accept(token) {
+            return Ok(());
+        }
+        Err(self.error(&format!("expected '{}'", token)))
+    }
+
+    fn error(&self, message: &str) -> Error {
+        syntax_error(self.expression, message)
+    }
+}
+
+///
+/// TESTS
+///
+#[cfg(test)]
+mod tests {
+    use crate::procedure::basic_procedures::filter_expression::FilterExpression;
+    use crate::procedure::procedure::GraphProperties;
+    use serde_json::json;
+
+    fn properties() -> GraphProperties {
+        let mut properties = GraphProperties::new();
+        properties.insert("colourable".to_string(), json!(false));
+        properties.insert("oddness".to_string(), json!(2));
+        properties.insert("resistance".to_string(), json!(3));
+        properties.insert("girth".to_string(), json!(5.0));
+        properties.insert("name".to_string(), json!("petersen"));
+        properties.insert("resistance-witness".to_string(), json!([[0, 4], [1, 5]]));
+        properties.insert("5-cdc".to_string(), json!(true));
+        properties
+    }
+
+    fn evaluate(expression: &str) -> bool {
+        FilterExpression::parse(expression)
+            .unwrap()
+            .evaluate(&properties())
+    }
+
+    #[test]
+    fn should_evaluate_logical_operators() {
+        assert_eq!(
+            evaluate("colourable == false && (oddness >= 4 || resistance > 2)"),
+            true
+        );
+        assert_eq!(
+            evaluate("colourable == false && (oddness >= 4 || resistance > 3)"),
+            false
+        );
+        assert_eq!(evaluate("!colourable && oddness=2"), true);
+        assert_eq!(evaluate("colourable || oddness != 2"), false);
+        // && binds tighter than ||
+        assert_eq!(
+            evaluate("oddness < 2 && colourable || resistance <= 3"),
+            true
+        );
+        assert_eq!(evaluate("!(oddness > 1)"), false);
+    }
+
+    #[test]
+    fn should_compare_values_of_different_types() {
+        assert_eq!(evaluate("girth == 5"), true);
+        assert_eq!(evaluate("girth > 4.5"), true);
+        assert_eq!(evaluate("oddness > -1"), true);
+        assert_eq!(evaluate("name == 'petersen'"), true);
+        assert_eq!(evaluate("name < \"q\""), true);
+        assert_eq!(evaluate("len(resistance-witness) == 2"), true);
+        assert_eq!(evaluate("len(name) > 10"), false);
+        // values of different types are never equal
+        assert_eq!(evaluate("colourable == 0"), false);
+        assert_eq!(evaluate("colourable != 0"), true);
+        assert_eq!(evaluate("colourable > false"), false);
+    }
+
+    #[test]
+    fn should_check_existence_of_property() {
+        assert_eq!(evaluate("exists(oddness)"), true);
+        assert_eq!(evaluate("exists(cdc)"), false);
+        assert_eq!(evaluate("!exists(cdc)"), true);
+        assert_eq!(evaluate("cdc == true"), false);
+        assert_eq!(evaluate("cdc != true"), true);
+    }
+
+    #[test]
+    fn should_filter_by_property_starting_with_digit() {
+        assert_eq!(evaluate("5-cdc"), true);
+        assert_eq!(evaluate("5-cdc == true && oddness == 2"), true);
+        assert_eq!(evaluate("!5-cdc"), false);
+        assert_eq!(evaluate("exists(5-cdc)"), true);
+        assert_eq!(evaluate("exists(3-cdc)"), false);
+    }
+
+    #[test]
+    fn should_reject_malformed_expression() {
+        for expression in vec![
+            "",
+            "oddness >",
+            "oddness > 2 &&",
+            "(oddness > 2",
+            "oddness > 2)",
+            "oddness & 2",
+            "oddness >= 1.2.3",
+            "name == 'petersen",
+            "size(name) > 2",
+            "len(name)",
+            "oddness # 2",
+        ] {
+            assert_eq!(FilterExpression::parse(expression).is_err(), true);
+        }
+    }
+}
@@ -0,0 +1,236 @@
+use crate::graph::edge::Edge;
+use crate::graph::graph::{Graph, GraphConstructor};
+use crate::graph::undirected::UndirectedGraph;
+use crate::procedure::basic_procedures::chrom_props::config::GRAPH_INDEX;
+use crate::procedure::error::Error;
+use crate::procedure::helpers::config_helper;
+use crate::procedure::procedure::{ChunkHandler, GraphProperties, Procedure, Result};
+use crate::procedure::procedure_builder::{ConfigMap, ProcedureBuilder};
+use crate::service::constructions::cubic_graphs::{CubicGraphs, CubicGraphsConfig};
+use crate::service::constructions::families::GraphFamily;
+use std::collections::HashMap;
+use std::marker;
+use std::str::FromStr;
+
+// config params
+const FAMILY: &str = "family";
+const N: &str = "n";
+const K: &str = "k";
+const GIRTH: &str = "girth";
+const CYCLIC_CONNECTIVITY: &str = "cyclic-connectivity";
+const COLOURABLE: &str = "colourable";
+const SHARD: &str = "shard";
+
+// family of all cubic graphs of order n
+const CUBIC: &str = "cubic";
+
+// properties
+const SIZE: &str = "size";
+
+///
+/// Generates members of graph family (e.g. flower snarks) or all cubic graphs of given order
+/// instead of reading them from file
+///
+struct GenerateProcedure<G: Graph> {
+    config: GenerateProcedureConfig,
+    _ph: marker::PhantomData<G>,
+}
+
+impl<G: UndirectedGraph + GraphConstructor + Clone> Procedure<G> for GenerateProcedure<G> {
+    fn run(&self, graphs: &mut Vec<(G, GraphProperties)>) -> Result<()> {
+        println!("running generate procedure");
+        self.generate_with(|graph| {
+            graphs.push(graph);
+            Ok(())
+        })
+    }
+
+    fn stream(&mut self, chunk_size: usize, handler: &mut ChunkHandler<'_, G>) -> Result<()> {
+        println!(
+            "running generate procedure (chunks of {} graphs)",
+            chunk_size
+        );
+        let mut chunk = Vec::with_capacity(chunk_size);
+        self.generate_with(|graph| {
+            chunk.push(graph);
+            if chunk.len() >= chunk_size {
+                handler(&mut chunk)?;
+                chunk.clear();
+            }
+            Ok(())
+        })?;
+        if !chunk.is_empty() {
+            handler(&mut chunk)?;
+        }
+        Ok(())
+    }
+}
+
+impl<G: UndirectedGraph + GraphConstructor + Clone> GenerateProcedure<G> {
+    ///
+    /// passes generated graphs one by one to consumer, each with its family, parameters and
+    /// index among generated graphs as graph-index
+    ///
+    fn generate_with<F>(&self, mut consumer: F) -> Result<()>
+    where
+        F: FnMut((G, GraphProperties)) -> Result<()>,
+    {
+        let mut index = 0;
+        for n in self.config.n().iter() {
+            match self.config.generator() {
+                Generator::Family(family) => {
+                    let graph: G = family.generate(*n, self.config.k())?;
+                    let mut properties = Self::properties(&family.to_string(), *n, &graph, index);
+                    if let (GraphFamily::GeneralizedPetersen, Some(k)) = (family, self.config.k()) {
+                        properties.insert(K.to_string(), serde_json::json!(k));
+                    }
+                    consumer((graph, properties))?;
+                    index += 1;
+                }
+                Generator::Cubic(cubic_config) => {
+                    let cubic_graphs = CubicGraphs::new(*n, cubic_config.clone())?;
+                    cubic_graphs.generate(|simple_graph| {
+                        let mut graph = G::with_vertices_capacity(simple_graph.size());
+                        for edge in simple_graph.edges() {
+                            graph.add_edge(edge.from(), edge.to());
+                        }
+                        let properties = Self::properties(CUBIC, *n, &graph, index);
+                        index += 1;
+                        consumer((graph, properties))
+                    })?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn properties(family: &str, n: usize, graph: &G, index: usize) -> GraphProperties {
+        let mut properties = GraphProperties::new();
+        properties.insert(FAMILY.to_string(), serde_json::json!(family));
+        properties.insert(N.to_string(), serde_json::json!(n));
+        properties.insert(SIZE.to_string(), serde_json::json!(graph.size()));
+        properties.insert(GRAPH_INDEX.to_string(), serde_json::json!(index));
+        properties
+    }
+}
+
+///
+/// Graphs to generate - members of parametric family or all cubic graphs of order n
+///
+#[derive(Debug, Clone, PartialEq)]
+pub enum Generator {
+    Family(GraphFamily),
+    Cubic(CubicGraphsConfig),
+}
+
+pub struct GenerateProcedureConfig {
+    generator: Generator,
+    n: Vec<usize>,
+    k: Option<usize>,
+}
+
+impl GenerateProcedureConfig {
+    pub const PROC_TYPE: &'static str = "generate";
+
+    pub fn new(family: GraphFamily, n: Vec<usize>, k: Option<usize>) -> Self {
+        GenerateProcedureConfig {
+            generator: Generator::Family(family),
+            n,
+            k,
+        }
+    }
+
+    pub fn new_cubic(n: Vec<usize>, cubic_config: CubicGraphsConfig) -> Self {
+        GenerateProcedureConfig {
+            generator: Generator::Cubic(cubic_config),
+            n,
+            k: None,
+        }
+    }
+
+    pub fn from_proc_config(config: &HashMap<String, serde_json::Value>) -> Result<Self> {
+        let family: String = config_helper::resolve_value(config, FAMILY, Self::PROC_TYPE)?;
+        // n is single number or list of numbers
+        let n: serde_json::Value = config_helper::resolve_value(config, N, Self::PROC_TYPE)?;
+        let n = if n.is_array() {
+            config_helper::resolve_value(config, N, Self::PROC_TYPE)?
+        } else {
+            vec![config_helper::resolve_value(config, N, Self::PROC_TYPE)?]
+        };
+        if family == CUBIC {
+            return Ok(Self::new_cubic(n, Self::resolve_cubic_config(config)?));
+        }
+        let family = GraphFamily::from_str(&family)?;
+        let k = config_helper::resolve_value_or_default(config, K, None, Self::PROC_TYPE)?;
+        if family == GraphFamily::GeneralizedPetersen && k.is_none() {
+            return Err(Error::ConfigError(format!(
+                "field '{}' for procedure '{}' is required for family '{}'",
+                K,
+                Self::PROC_TYPE,
+                family
+            )));
+        }
+        Ok(Self::new(family, n, k))
+    }
+
+    fn resolve_cubic_config(
+        config: &HashMap<String, serde_json::Value>,
+    ) -> Result<CubicGraphsConfig> {
+        let mut cubic_config = CubicGraphsConfig::all();
+        cubic_config.girth = config_helper::resolve_value_or_default(
+            config,
+            GIRTH,
+            cubic_config.girth,
+            Self::PROC_TYPE,
+        )?;
+        cubic_config.cyclic_connectivity = config_helper::resolve_value_or_default(
+            config,
+            CYCLIC_CONNECTIVITY,
+            None,
+            Self::PROC_TYPE,
+        )?;
+        cubic_config.colourable =
+            config_helper::resolve_value_or_default(config, COLOURABLE, None, Self::PROC_TYPE)?;
+        let shard: Option<String> =
+            config_helper::resolve_value_or_default(config, SHARD, None, Self::PROC_TYPE)?;
+        cubic_config.shard = match shard {
+            Some(shard) => Some(config_helper::parse_shard(&shard, Self::PROC_TYPE)?),
+            None => None,
+        };
+        Ok(cubic_config)
+    }
+
+    pub fn generator(&self) -> &Generator {
+        &self.generator
+    }
+
+    pub fn n(&self) -> &Vec<usize> {
+        &self.n
+    }
+
+    pub fn k(&self) -> Option<usize> {
+        self.k
+    }
+}
+
+pub struct GenerateProcedureBuilder {}
+
+impl<G: UndirectedGraph + GraphConstructor + Clone + 'static> ProcedureBuilder<G>
+    for GenerateProcedureBuilder
+{
+    fn build_from_map(&self, config: ConfigMap) -> Result<Box<dyn Procedure<G>>> {
+        let proc_config = GenerateProcedureConfig::from_proc_config(&config)?;
+        Ok(Self::build(proc_config))
+    }
+}
+
+impl GenerateProcedureBuilder {
+    pub fn build<G: UndirectedGraph + GraphConstructor + Clone + 'static>(
+        config: GenerateProcedureConfig,
+    ) -> Box<dyn Procedure<G>> {
+        Box::new(GenerateProcedure {
+            config,
+            _ph: marker::PhantomData,
+        })
+    }
+}
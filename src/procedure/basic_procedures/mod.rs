@@ -1,7 +1,11 @@
+pub mod branch;
 pub mod chrom_props;
 pub mod colour;
 pub mod constructions;
+pub mod dedup;
 pub mod filter;
+pub mod filter_expression;
+pub mod generate;
 pub mod read;
 pub mod write;
 
@@ -1,8 +1,9 @@
 use crate::graph::graph::{Graph, GraphConstructor};
 use crate::graph::undirected::UndirectedGraph;
+use crate::procedure::basic_procedures::chrom_props::config::GRAPH_INDEX;
 use crate::procedure::error::Error;
 use crate::procedure::helpers::config_helper;
-use crate::procedure::procedure::{GraphProperties, Procedure, Result};
+use crate::procedure::procedure::{ChunkHandler, GraphProperties, Procedure, Result};
 use crate::procedure::procedure_builder::{ConfigMap, ProcedureBuilder};
 use crate::service::io::error::ReadError;
 use crate::service::io::reader::GraphFileReader;
@@ -17,6 +18,11 @@ use std::{fs, marker, path};
 const FILE_NAME: &str = "file";
 const GRAPH_FORMAT: &str = "graph-format";
 const NUMBER_OF_GRAPHS: &str = "number-of-graphs";
+const SKIP: &str = "skip";
+const OFFSET: &str = "offset";
+const RANGES: &str = "ranges";
+const SHARD: &str = "shard";
+const WITH_GRAPH_INDEX: &str = "with-graph-index";
 
 // config param properties
 pub const G6_FORMAT: &str = "g6";
@@ -36,30 +42,67 @@ impl<G: UndirectedGraph + GraphConstructor> Procedure<G> for ReadProcedure<G> {
         println!("running read procedure");
         self.read_graphs(graphs)
     }
+
+    fn stream(&mut self, chunk_size: usize, handler: &mut ChunkHandler<'_, G>) -> Result<()> {
+        println!("running read procedure (chunks of {} graphs)", chunk_size);
+        let mut chunk = Vec::with_capacity(chunk_size);
+        self.read_graphs_with(|graph| {
+            chunk.push(graph);
+            if chunk.len() >= chunk_size {
+                handler(&mut chunk)?;
+                chunk.clear();
+            }
+            Ok(())
+        })?;
+        if !chunk.is_empty() {
+            handler(&mut chunk)?;
+        }
+        Ok(())
+    }
 }
 
 impl<G: UndirectedGraph + GraphConstructor> ReadProcedure<G> {
     pub fn read_graphs(&self, graphs: &mut Vec<(G, GraphProperties)>) -> Result<()> {
+        self.read_graphs_with(|graph| {
+            graphs.push(graph);
+            Ok(())
+        })
+    }
+
+    ///
+    /// passes read graphs one by one to consumer
+    ///
+    fn read_graphs_with<F>(&self, consumer: F) -> Result<()>
+    where
+        F: FnMut((G, GraphProperties)) -> Result<()>,
+    {
         let file_path = self.config.file_path();
-        let graphs_count = self.config.number_of_graphs();
         let file = Self::open_file_to_read(file_path)?;
         let graph_format = self.config.graph_format();
 
         match graph_format.as_str() {
             G6_FORMAT => {
                 let reader = G6Reader::new(&file);
-                Self::read_by_format(reader, graphs, graphs_count)?;
+                self.read_by_format(reader, consumer)?;
             }
             BA_FORMAT => {
                 let reader = BaReader::<G>::new(&file);
-                Self::read_by_format(reader, graphs, graphs_count)?;
+                self.read_by_format(reader, consumer)?;
             }
             S6_FORMAT => {
                 let reader = S6Reader::<G>::new(&file);
-                Self::read_by_format(reader, graphs, graphs_count)?;
+                self.read_by_format(reader, consumer)?;
             }
             JSON_FORMAT => {
-                Self::read_json_format(graphs, graphs_count, &file)?;
+                let mut reader = JsonReader::<G>::new(&file);
+                self.select_graphs(
+                    || {
+                        reader
+                            .next_with_properties()
+                            .map(|graph| graph.map_err(Error::from))
+                    },
+                    consumer,
+                )?;
             }
             _ => {
                 return Err(Error::ConfigError(String::from(
@@ -70,65 +113,62 @@ impl<G: UndirectedGraph + GraphConstructor> ReadProcedure<G> {
         Ok(())
     }
 
-    fn read_by_format<'a, R>(
-        mut reader: R,
-        graphs: &mut Vec<(G, GraphProperties)>,
-        graphs_count: Option<usize>,
-    ) -> Result<()>
+    fn read_by_format<'a, R, F>(&self, mut reader: R, consumer: F) -> Result<()>
     where
         R: GraphFileReader<'a, G>,
+        F: FnMut((G, GraphProperties)) -> Result<()>,
     {
-        let mut counter = 1;
-        let mut graph_opt = reader.next();
-        while graph_opt.is_some() {
-            let graph = graph_opt.unwrap()?;
-            let mut properties = GraphProperties::new();
-            properties.insert("size".to_string(), serde_json::to_value(graph.size())?);
-            graphs.push((graph, properties));
-            counter += 1;
-
-            if graphs_count.is_some() && graphs_count.unwrap() < counter {
-                break;
-            }
-            graph_opt = reader.next();
-        }
-        if graphs_count.is_some() && graphs_count.unwrap() > counter {
-            println!(
-                "You asked for: {} graphs but given file contains only {}",
-                graphs_count.unwrap(),
-                counter
-            );
-        }
-        Ok(())
+        self.select_graphs(
+            || {
+                let graph = match reader.next()? {
+                    Ok(graph) => graph,
+                    Err(error) => return Some(Err(error.into())),
+                };
+                let mut properties = GraphProperties::new();
+                properties.insert("size".to_string(), serde_json::Value::from(graph.size()));
+                Some(Ok((graph, properties)))
+            },
+            consumer,
+        )
     }
 
-    fn read_json_format(
-        graphs: &mut Vec<(G, GraphProperties)>,
-        graphs_count: Option<usize>,
-        file: &fs::File,
-    ) -> Result<()> {
-        let mut counter = 1;
-
-        let mut reader = JsonReader::<G>::new(file);
-
-        let mut graph_opt = reader.next_with_properties();
-        while graph_opt.is_some() {
-            let graph = graph_opt.unwrap()?;
-            graphs.push(graph);
-            counter += 1;
-
-            if graphs_count.is_some() && graphs_count.unwrap() < counter {
-                break;
+    ///
+    /// passes graphs selected by config to consumer, each with its index in file as graph-index
+    /// unless with-graph-index is turned off (graph-index already present in json input is kept)
+    ///
+    fn select_graphs<N, F>(&self, mut next: N, mut consumer: F) -> Result<()>
+    where
+        N: FnMut() -> Option<Result<(G, GraphProperties)>>,
+        F: FnMut((G, GraphProperties)) -> Result<()>,
+    {
+        let selection = self.config.selection();
+        let graphs_count = self.config.number_of_graphs();
+        let mut selected = 0;
+        let mut index = 0;
+        while graphs_count.map_or(true, |count| selected < count) && !selection.is_past(index) {
+            let graph = match next() {
+                Some(graph) => graph?,
+                None => break,
+            };
+            if selection.selects(index) {
+                let (graph, mut properties) = graph;
+                if self.config.with_graph_index() {
+                    properties
+                        .entry(GRAPH_INDEX.to_string())
+                        .or_insert_with(|| serde_json::Value::from(index));
+                }
+                consumer((graph, properties))?;
+                selected += 1;
             }
-
-            graph_opt = reader.next_with_properties();
+            index += 1;
         }
-        if graphs_count.is_some() && graphs_count.unwrap() > counter {
-            println!(
-                "You asked for: {} graphs but given file contains only {}",
-                graphs_count.unwrap(),
-                counter
-            );
+        if let Some(count) = graphs_count {
+            if count > selected {
+                println!(
+                    "You asked for: {} graphs but given file contains only {}",
+                    count, selected
+                );
+            }
         }
         Ok(())
     }
@@ -148,6 +188,141 @@ pub struct ReadProcedureConfig {
     file_path: String,
     graph_format: String,
     number_of_graphs: Option<usize>,
+    selection: GraphSelection,
+    with_graph_index: bool,
+}
+
+///
+/// Selection of graphs by their index in file (counted from 0) - first skip graphs are skipped,
+/// if ranges are given, index has to be in one of them (inclusive) and if shard k/n is given,
+/// index modulo n has to be k. Number of graphs limits number of selected graphs.
+///
+#[derive(Debug, Clone, PartialEq)]
+pub struct GraphSelection {
+    pub skip: usize,
+    pub ranges: Vec<(usize, Option<usize>)>,
+    pub shard: Option<(usize, usize)>,
+}
+
+impl GraphSelection {
+    pub fn all() -> Self {
+        GraphSelection {
+            skip: 0,
+            ranges: vec![],
+            shard: None,
+        }
+    }
+
+    pub fn selects(&self, index: usize) -> bool {
+        if index < self.skip {
+            return false;
+        }
+        if !self.ranges.is_empty()
+            && !self
+                .ranges
+                .iter()
+                .any(|(from, to)| index >= *from && to.map_or(true, |to| index <= to))
+        {
+            return false;
+        }
+        match self.shard {
+            Some((shard, shards)) => index % shards == shard,
+            None => true,
+        }
+    }
+
+    ///
+    /// no graph with given or greater index is selected
+    ///
+    pub fn is_past(&self, index: usize) -> bool {
+        if self.ranges.is_empty() {
+            return false;
+        }
+        self.ranges
+            .iter()
+            .all(|(_, to)| to.map_or(false, |to| index > to))
+    }
+
+    ///
+    /// range - index (e.g. 7), inclusive range of indices (e.g. "100-199") or open range (e.g.
+    /// "100-")
+    ///
+    fn parse_range(range: &serde_json::Value) -> Result<(usize, Option<usize>)> {
+        if let Some(index) = range.as_u64() {
+            return Ok((index as usize, Some(index as usize)));
+        }
+        let error = || {
+            Error::ConfigError(format!(
+                "malformed range {} for procedure '{}', expected e.g. 7, \"100-199\" or \"100-\"",
+                range,
+                ReadProcedureConfig::PROC_TYPE
+            ))
+        };
+        let range = range.as_str().ok_or_else(error)?;
+        let mut bounds = range.splitn(2, '-');
+        let from = bounds.next().unwrap().trim();
+        let from: usize = from.parse().map_err(|_| error())?;
+        let to = match bounds.next().map(|to| to.trim()) {
+            None => Some(from),
+            Some("") => None,
+            Some(to) => Some(to.parse().map_err(|_| error())?),
+        };
+        if to.map_or(false, |to| to < from) {
+            return Err(error());
+        }
+        Ok((from, to))
+    }
+
+    fn from_proc_config(config: &HashMap<String, serde_json::Value>) -> Result<Self> {
+        let skip = config_helper::resolve_value_or_default(
+            &config,
+            SKIP,
+            None,
+            ReadProcedureConfig::PROC_TYPE,
+        )?;
+        let offset = config_helper::resolve_value_or_default(
+            &config,
+            OFFSET,
+            None,
+            ReadProcedureConfig::PROC_TYPE,
+        )?;
+        if skip.is_some() && offset.is_some() {
+            return Err(Error::ConfigError(format!(
+                "fields '{}' and '{}' for procedure '{}' are aliases, use only one of them",
+                SKIP,
+                OFFSET,
+                ReadProcedureConfig::PROC_TYPE
+            )));
+        }
+        let ranges: Vec<serde_json::Value> = config_helper::resolve_value_or_default(
+            &config,
+            RANGES,
+            vec![],
+            ReadProcedureConfig::PROC_TYPE,
+        )?;
+        let ranges = ranges
+            .iter()
+            .map(Self::parse_range)
+            .collect::<Result<Vec<_>>>()?;
+        let shard: Option<String> = config_helper::resolve_value_or_default(
+            &config,
+            SHARD,
+            None,
+            ReadProcedureConfig::PROC_TYPE,
+        )?;
+        let shard = match shard {
+            Some(shard) => Some(config_helper::parse_shard(
+                &shard,
+                ReadProcedureConfig::PROC_TYPE,
+            )?),
+            None => None,
+        };
+        Ok(GraphSelection {
+            skip: skip.or(offset).unwrap_or(0),
+            ranges,
+            shard,
+        })
+    }
 }
 
 impl ReadProcedureConfig {
@@ -158,6 +333,8 @@ impl ReadProcedureConfig {
             file_path,
             graph_format,
             number_of_graphs,
+            selection: GraphSelection::all(),
+            with_graph_index: true,
         }
     }
 
@@ -166,6 +343,8 @@ impl ReadProcedureConfig {
             file_path: DEFAULT_FILE_NAME.to_string(),
             graph_format: GRAPH_FORMAT.to_string(),
             number_of_graphs: None,
+            selection: GraphSelection::all(),
+            with_graph_index: true,
         }
     }
 
@@ -188,10 +367,19 @@ impl ReadProcedureConfig {
             None,
             Self::PROC_TYPE,
         )?;
+        let selection = GraphSelection::from_proc_config(&config)?;
+        let with_graph_index = config_helper::resolve_value_or_default(
+            &config,
+            WITH_GRAPH_INDEX,
+            true,
+            Self::PROC_TYPE,
+        )?;
         let result = ReadProcedureConfig {
             file_path,
             graph_format,
             number_of_graphs,
+            selection,
+            with_graph_index,
         };
         Ok(result)
     }
@@ -207,6 +395,14 @@ impl ReadProcedureConfig {
     pub fn number_of_graphs(&self) -> Option<usize> {
         self.number_of_graphs
     }
+
+    pub fn selection(&self) -> &GraphSelection {
+        &self.selection
+    }
+
+    pub fn with_graph_index(&self) -> bool {
+        self.with_graph_index
+    }
 }
 
 pub struct ReadProcedureBuilder {}
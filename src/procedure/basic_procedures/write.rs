@@ -1,4 +1,4 @@
-use crate::graph::graph::GraphConstructor;
+use crate::graph::graph::{Graph, GraphConstructor};
 use crate::graph::undirected::UndirectedGraph;
 use crate::procedure::basic_procedures::read;
 use crate::procedure::error::Error;
@@ -9,6 +9,7 @@ use crate::service::io::error::{ReadError, WriteError};
 use crate::service::io::writer_ba::BaWriter;
 use crate::service::io::writer_g6::G6Writer;
 use crate::service::io::writer_s6::S6Writer;
+use crate::service::symmetry::canonical;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::fs::OpenOptions;
@@ -19,11 +20,15 @@ use std::{fs, marker, path};
 const FILE_NAME: &str = "file";
 const GRAPH_FORMAT: &str = "graph-format";
 const WITH_PROPERTIES: &str = "with-properties";
+const CANONICAL: &str = "canonical";
 
 const DEFAULT_FILE_NAME: &str = "write-procedure-output-file";
 
 struct WriteProcedure<G: UndirectedGraph> {
     config: WriteProcedureConfig,
+    // file of json output in streaming mode and number of graphs written to it
+    stream_file: Option<fs::File>,
+    written: usize,
     _ph: marker::PhantomData<G>,
 }
 
@@ -33,6 +38,7 @@ pub struct WriteProcedureConfig {
     file_path: String,
     graph_format: String,
     with_properties: bool,
+    canonical: bool,
 }
 
 impl<G: UndirectedGraph> Procedure<G> for WriteProcedure<G> {
@@ -40,9 +46,44 @@ impl<G: UndirectedGraph> Procedure<G> for WriteProcedure<G> {
         println!("running write procedure");
         self.write_graphs(graphs)
     }
+
+    fn run_chunk(&mut self, graphs: &mut Vec<(G, GraphProperties)>) -> Result<()> {
+        if self.config.with_properties() {
+            return self.write_chunk_with_properties(graphs);
+        }
+        // graphs without properties are appended to file
+        self.write_without_properties(graphs, self.config.graph_format(), self.config.file_path())
+    }
+
+    fn finish(&mut self) -> Result<()> {
+        println!("running write procedure");
+        if !self.config.with_properties() {
+            return Ok(());
+        }
+        let file_path = self.config.file_path().clone();
+        let result = match self.stream_file.take() {
+            Some(mut file) => writeln!(file, "\n]"),
+            None => fs::File::create(&file_path).and_then(|mut file| writeln!(file, "[]")),
+        };
+        if let Err(err) = result {
+            return Err(Error::WriteError(WriteError {
+                message: format!("error while writing to file: {}, error: {}", file_path, err),
+            }));
+        }
+        Ok(())
+    }
 }
 
 impl<G: UndirectedGraph> WriteProcedure<G> {
+    fn new(config: WriteProcedureConfig) -> Self {
+        WriteProcedure {
+            config,
+            stream_file: None,
+            written: 0,
+            _ph: marker::PhantomData,
+        }
+    }
+
     pub fn write_graphs(&self, graphs: &mut Vec<(G, GraphProperties)>) -> Result<()>
     where
         G: UndirectedGraph,
@@ -62,10 +103,25 @@ impl<G: UndirectedGraph> WriteProcedure<G> {
         graphs: &mut Vec<(G, GraphProperties)>,
         graph_format: &String,
         file_path: &String,
+    ) -> Result<()> {
+        if self.config.canonical() {
+            let canonical_graphs: Vec<_> = graphs
+                .iter()
+                .map(|graph| (canonical::canonical_form(&graph.0), ()))
+                .collect();
+            return Self::write_graphs_in_format(&canonical_graphs, graph_format, file_path);
+        }
+        Self::write_graphs_in_format(graphs, graph_format, file_path)
+    }
+
+    fn write_graphs_in_format<H: Graph, P>(
+        graphs: &Vec<(H, P)>,
+        graph_format: &String,
+        file_path: &String,
     ) -> Result<()> {
         match graph_format.as_str() {
             read::G6_FORMAT => {
-                G6Writer::write_graphs_to_file(&graphs, file_path)?;
+                G6Writer::write_graphs_to_file(graphs, file_path)?;
             }
             read::BA_FORMAT => {
                 BaWriter::write_graphs_to_file(graphs, file_path)?;
@@ -91,28 +147,11 @@ impl<G: UndirectedGraph> WriteProcedure<G> {
         let mut file = Self::open_file_to_write(file_path)?;
         let mut vec = vec![];
         for graph in graphs {
-            let graph_string;
-            match graph_format.as_str() {
-                read::G6_FORMAT => {
-                    graph_string = G6Writer::graph_to_g6_string(&graph.0);
-                }
-                read::S6_FORMAT => {
-                    graph_string = S6Writer::graph_to_s6_string(&graph.0);
-                }
-                _ => {
-                    return Err(Error::ConfigError(format!(
-                        "unknown graph format: '{}' for procedure: {}",
-                        graph_format,
-                        WriteProcedureConfig::PROC_TYPE
-                    )));
-                }
-            }
-            let graph_with_properties = GraphWithProperties {
-                graph: graph_string,
-                properties: graph.1.clone(),
-                graph_format: graph_format.clone(),
-            };
-            vec.push(graph_with_properties);
+            vec.push(Self::graph_with_properties(
+                graph,
+                graph_format,
+                self.config.canonical(),
+            )?);
         }
         let serialized = serde_json::to_string_pretty(&vec).unwrap();
         let result = writeln!(file, "{}", serialized);
@@ -124,6 +163,70 @@ impl<G: UndirectedGraph> WriteProcedure<G> {
         Ok(())
     }
 
+    ///
+    /// streaming mode - chunks are written as items of one json array, which is closed in finish
+    ///
+    fn write_chunk_with_properties(&mut self, graphs: &[(G, GraphProperties)]) -> Result<()> {
+        let file_path = self.config.file_path();
+        if self.stream_file.is_none() {
+            let mut file = fs::File::create(file_path).map_err(WriteError::from)?;
+            write!(file, "[").map_err(WriteError::from)?;
+            self.stream_file = Some(file);
+        }
+        let file = self.stream_file.as_mut().unwrap();
+        for graph in graphs {
+            let graph_with_properties = Self::graph_with_properties(
+                graph,
+                self.config.graph_format(),
+                self.config.canonical(),
+            )?;
+            let serialized = serde_json::to_string_pretty(&graph_with_properties)?;
+            let separator = if self.written > 0 { "," } else { "" };
+            let result = write!(file, "{}\n{}", separator, serialized);
+            if let Err(err) = result {
+                return Err(Error::WriteError(WriteError {
+                    message: format!("error while writing to file: {}, error: {}", file_path, err),
+                }));
+            }
+            self.written += 1;
+        }
+        Ok(())
+    }
+
+    fn graph_with_properties(
+        graph: &(G, GraphProperties),
+        graph_format: &String,
+        canonical: bool,
+    ) -> Result<GraphWithProperties> {
+        let graph_string;
+        match graph_format.as_str() {
+            read::G6_FORMAT if canonical => {
+                graph_string = canonical::canonical_g6_string(&graph.0);
+            }
+            read::S6_FORMAT if canonical => {
+                graph_string = canonical::canonical_s6_string(&graph.0);
+            }
+            read::G6_FORMAT => {
+                graph_string = G6Writer::graph_to_g6_string(&graph.0);
+            }
+            read::S6_FORMAT => {
+                graph_string = S6Writer::graph_to_s6_string(&graph.0);
+            }
+            _ => {
+                return Err(Error::ConfigError(format!(
+                    "unknown graph format: '{}' for procedure: {}",
+                    graph_format,
+                    WriteProcedureConfig::PROC_TYPE
+                )));
+            }
+        }
+        Ok(GraphWithProperties {
+            graph: graph_string,
+            properties: graph.1.clone(),
+            graph_format: graph_format.clone(),
+        })
+    }
+
     fn open_file_to_write<P: AsRef<path::Path>>(path: P) -> Result<fs::File> {
         let file_result = OpenOptions::new().write(true).create(true).open(&path);
         if file_result.is_err() {
@@ -145,11 +248,17 @@ pub struct GraphWithProperties {
 impl WriteProcedureConfig {
     pub const PROC_TYPE: &'static str = "write";
 
-    pub fn new(file_path: String, graph_format: String, with_properties: bool) -> Self {
+    pub fn new(
+        file_path: String,
+        graph_format: String,
+        with_properties: bool,
+        canonical: bool,
+    ) -> Self {
         WriteProcedureConfig {
             file_path,
             graph_format,
             with_properties,
+            canonical,
         }
     }
 
@@ -158,6 +267,7 @@ impl WriteProcedureConfig {
             file_path: DEFAULT_FILE_NAME.to_string(),
             graph_format: read::G6_FORMAT.to_string(),
             with_properties: false,
+            canonical: false,
         }
     }
 
@@ -176,11 +286,14 @@ impl WriteProcedureConfig {
         )?;
         let with_properties =
             config_helper::resolve_value(&config, WITH_PROPERTIES, Self::PROC_TYPE)?;
+        let canonical =
+            config_helper::resolve_value_or_default(&config, CANONICAL, false, Self::PROC_TYPE)?;
 
         let result = WriteProcedureConfig {
             file_path,
             graph_format,
             with_properties,
+            canonical,
         };
         Ok(result)
     }
@@ -195,6 +308,10 @@ impl WriteProcedureConfig {
     pub fn with_properties(&self) -> bool {
         self.with_properties
     }
+
+    pub fn canonical(&self) -> bool {
+        self.canonical
+    }
 }
 
 impl<G: UndirectedGraph + GraphConstructor + 'static> ProcedureBuilder<G>
@@ -202,10 +319,7 @@ impl<G: UndirectedGraph + GraphConstructor + 'static> ProcedureBuilder<G>
 {
     fn build_from_map(&self, config: ConfigMap) -> Result<Box<dyn Procedure<G>>> {
         let proc_config = WriteProcedureConfig::from_proc_config(&config)?;
-        Ok(Box::new(WriteProcedure {
-            config: proc_config,
-            _ph: marker::PhantomData,
-        }))
+        Ok(Box::new(WriteProcedure::new(proc_config)))
     }
 }
 
@@ -213,9 +327,6 @@ impl WriteProcedureBuilder {
     pub fn build<G: UndirectedGraph + 'static>(
         config: WriteProcedureConfig,
     ) -> Box<dyn Procedure<G>> {
-        Box::new(WriteProcedure {
-            config,
-            _ph: marker::PhantomData,
-        })
+        Box::new(WriteProcedure::new(config))
     }
 }
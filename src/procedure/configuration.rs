@@ -1,6 +1,24 @@
+use crate::procedure::basic_procedures::branch::{BranchProcedureConfig, BRANCHES};
+use crate::procedure::basic_procedures::colour::ColourProcedureConfig;
+use crate::procedure::basic_procedures::counter::CounterProcedureConfig;
+use crate::procedure::basic_procedures::dedup::DedupProcedureConfig;
+use crate::procedure::basic_procedures::filter::FilterProcedureConfig;
+use crate::procedure::basic_procedures::generate::GenerateProcedureConfig;
+use crate::procedure::basic_procedures::read::ReadProcedureConfig;
+use crate::procedure::basic_procedures::write::WriteProcedureConfig;
+use crate::procedure::error::Error;
+use crate::procedure::procedure;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
+pub const SIMPLE_GRAPH: &str = "simple";
+pub const MULTI_GRAPH: &str = "multi";
+pub const SIMPLE_EDGE_GRAPH: &str = "simple-edge";
+
+const RESUME: &str = "resume";
+const CHECKPOINT_FILE: &str = "checkpoint-file";
+const PROCEDURES: &str = "procedures";
+
 #[derive(Debug, PartialEq, Serialize, Deserialize)]
 pub struct Configuration {
     pub version: f64,
@@ -8,6 +26,19 @@ pub struct Configuration {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub options: Option<Vec<String>>,
 
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub streaming: Option<StreamingConfig>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub report: Option<ReportConfig>,
+
+    ///
+    /// representation of graphs passed through procedures - simple (default), multi (can hold
+    /// parallel edges) or simple-edge
+    ///
+    #[serde(alias = "graph-type", skip_serializing_if = "Option::is_none")]
+    pub graph_type: Option<String>,
+
     pub procedures: Vec<ProcedureConfig>,
 }
 
@@ -19,8 +50,128 @@ pub struct ProcedureConfig {
     pub config: Option<HashMap<String, serde_json::Value>>,
 }
 
+///
+/// If present, graphs are passed through procedures in chunks of chunk_size graphs instead of
+/// reading all of them into memory at once
+///
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct StreamingConfig {
+    #[serde(alias = "chunk-size", default = "StreamingConfig::default_chunk_size")]
+    pub chunk_size: usize,
+}
+
+impl StreamingConfig {
+    pub const DEFAULT_CHUNK_SIZE: usize = 1000;
+
+    fn default_chunk_size() -> usize {
+        Self::DEFAULT_CHUNK_SIZE
+    }
+}
+
+///
+/// If present, JSON report with number of graphs, timings of procedures and errors is written to
+/// file (or printed if file is not set) at the end of run
+///
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct ReportConfig {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub file: Option<String>,
+}
+
 impl Configuration {
     pub fn from_yaml_string(yaml_string: &str) -> Result<Configuration, serde_yaml::Error> {
         serde_yaml::from_str(&yaml_string)
     }
+
+    ///
+    /// sets resume option of procedures with checkpoint file (e.g. chromatic-properties), also
+    /// in branches - they skip graphs finished in previous run
+    ///
+    pub fn resume(&mut self) {
+        resume_procedures(&mut self.procedures);
+    }
+
+    pub fn graph_type(&self) -> &str {
+        match &self.graph_type {
+            Some(graph_type) => graph_type,
+            None => SIMPLE_GRAPH,
+        }
+    }
+
+    ///
+    /// multigraphs can be passed only through procedures which handle parallel edges (also in
+    /// branches) - e.g. chromatic-properties works on simple graphs and colour procedure handles
+    /// parallel edges only with sat colourisers
+    ///
+    pub fn check_graph_type(&self) -> procedure::Result<()> {
+        if self.graph_type() != MULTI_GRAPH {
+            return Ok(());
+        }
+        check_parallel_edges_support(&self.procedures)
+    }
+}
+
+fn resume_procedures(procedures: &mut [ProcedureConfig]) {
+    for procedure in procedures.iter_mut() {
+        let config = match procedure.config.as_mut() {
+            Some(config) => config,
+            None => continue,
+        };
+        if config.contains_key(CHECKPOINT_FILE) {
+            config.insert(RESUME.to_string(), serde_json::Value::Bool(true));
+        }
+        if procedure.proc_type != BranchProcedureConfig::PROC_TYPE {
+            continue;
+        }
+        if let Some(serde_json::Value::Array(branches)) = config.get_mut(BRANCHES) {
+            for branch in branches.iter_mut() {
+                let branch_procedures = match branch.get_mut(PROCEDURES) {
+                    Some(branch_procedures) => branch_procedures,
+                    None => continue,
+                };
+                // malformed branches are left as they are and reported when chain is built
+                let mut sub_chain: Vec<ProcedureConfig> =
+                    match serde_json::from_value(branch_procedures.clone()) {
+                        Ok(sub_chain) => sub_chain,
+                        Err(_) => continue,
+                    };
+                resume_procedures(&mut sub_chain);
+                if let Ok(value) = serde_json::to_value(sub_chain) {
+                    *branch_procedures = value;
+                }
+            }
+        }
+    }
+}
+
+fn check_parallel_edges_support(procedures: &[ProcedureConfig]) -> procedure::Result<()> {
+    for procedure in procedures.iter() {
+        let empty = HashMap::new();
+        let config = procedure.config.as_ref().unwrap_or(&empty);
+        let supported = match procedure.proc_type.as_str() {
+            ReadProcedureConfig::PROC_TYPE
+            | WriteProcedureConfig::PROC_TYPE
+            | CounterProcedureConfig::PROC_TYPE
+            | FilterProcedureConfig::PROC_TYPE
+            | DedupProcedureConfig::PROC_TYPE
+            | GenerateProcedureConfig::PROC_TYPE => true,
+            ColourProcedureConfig::PROC_TYPE => {
+                ColourProcedureConfig::from_proc_config(config)?.handles_parallel_edges()
+            }
+            BranchProcedureConfig::PROC_TYPE => {
+                for branch in BranchProcedureConfig::from_proc_config(config)?.branches() {
+                    check_parallel_edges_support(&branch.procedures)?;
+                }
+                true
+            }
+            _ => false,
+        };
+        if !supported {
+            return Err(Error::ConfigError(format!(
+                "procedure '{}' does not support graph-type {} (parallel edges)",
+                procedure.proc_type, MULTI_GRAPH
+            )));
+        }
+    }
+    Ok(())
 }
@@ -14,3 +14,10 @@ impl From<Error> for ConstructionError {
         ConstructionError::new(message)
     }
 }
+
+impl From<serde_json::error::Error> for ConstructionError {
+    fn from(error: serde_json::error::Error) -> Self {
+        let message = format!("serde json error: {}", error);
+        ConstructionError { message }
+    }
+}
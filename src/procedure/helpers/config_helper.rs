@@ -59,3 +59,27 @@ pub fn resolve_value<V: serde::de::DeserializeOwned, T: AsRef<str>>(
     }
     Ok(field_value)
 }
+
+///
+/// shard - "k/n" where 0 <= k < n
+///
+pub fn parse_shard(shard: &str, procedure: &str) -> Result<(usize, usize)> {
+    let error = || {
+        Error::ConfigError(format!(
+            "malformed shard '{}' for procedure '{}', expected k/n where 0 <= k < n",
+            shard, procedure
+        ))
+    };
+    let mut parts = shard.splitn(2, '/');
+    let shard_index: usize = parts.next().unwrap().trim().parse().map_err(|_| error())?;
+    let shards: usize = parts
+        .next()
+        .ok_or_else(error)?
+        .trim()
+        .parse()
+        .map_err(|_| error())?;
+    if shard_index >= shards {
+        return Err(error());
+    }
+    Ok((shard_index, shards))
+}
@@ -44,6 +44,7 @@ pub mod procedure;
 pub mod procedure_builder;
 pub mod procedure_chain;
 pub mod procedure_registry;
+pub mod run_report;
 
 #[cfg(test)]
 mod tests;
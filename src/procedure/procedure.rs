@@ -6,6 +6,36 @@ use std::result;
 pub type Result<T> = result::Result<T, Error>;
 pub type GraphProperties = HashMap<String, serde_json::Value>;
 
+///
+/// Receives chunks of graphs produced by source procedure in streaming mode
+///
+pub type ChunkHandler<'a, G> = dyn FnMut(&mut Vec<(G, GraphProperties)>) -> Result<()> + 'a;
+
 pub trait Procedure<G: UndirectedGraph> {
     fn run(&self, graphs: &mut Vec<(G, GraphProperties)>) -> Result<()>;
+
+    ///
+    /// Streaming mode - procedure gets graphs in chunks, one chunk at a time. Procedures which
+    /// aggregate results over all graphs (e.g. count) keep their state between chunks.
+    ///
+    fn run_chunk(&mut self, graphs: &mut Vec<(G, GraphProperties)>) -> Result<()> {
+        self.run(graphs)
+    }
+
+    ///
+    /// Streaming mode - called once after last chunk was processed
+    ///
+    fn finish(&mut self) -> Result<()> {
+        Ok(())
+    }
+
+    ///
+    /// Streaming mode - procedure which produces graphs (e.g. read) passes them to handler in
+    /// chunks of at most chunk_size graphs
+    ///
+    fn stream(&mut self, _chunk_size: usize, _handler: &mut ChunkHandler<'_, G>) -> Result<()> {
+        Err(Error::ConfigError(String::from(
+            "first procedure of chain run in streaming mode has to produce graphs (e.g. read)",
+        )))
+    }
 }
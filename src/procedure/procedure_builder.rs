@@ -1,10 +1,27 @@
 use std::collections::HashMap;
 
 use crate::graph::graph::Graph;
+use crate::graph::undirected::UndirectedGraph;
 use crate::procedure::procedure::{Procedure, Result};
+use crate::procedure::procedure_registry::ProcedureRegistry;
 
 pub type ConfigMap = HashMap<String, serde_json::Value>;
 
 pub trait ProcedureBuilder<G: Graph> {
     fn build_from_map(&self, config: ConfigMap) -> Result<Box<dyn Procedure<G>>>;
+
+    ///
+    /// builds procedure by registry which is building it - procedures composed of other
+    /// procedures create them by this registry
+    ///
+    fn build_from_map_with_registry(
+        &self,
+        config: ConfigMap,
+        _registry: &ProcedureRegistry<G>,
+    ) -> Result<Box<dyn Procedure<G>>>
+    where
+        G: UndirectedGraph,
+    {
+        self.build_from_map(config)
+    }
 }
@@ -1,12 +1,19 @@
 use crate::graph::graph::GraphConstructor;
 use crate::graph::undirected::UndirectedGraph;
 use crate::procedure::configuration::ProcedureConfig;
+use crate::procedure::error::Error;
 use crate::procedure::procedure::{GraphProperties, Procedure, Result};
 use crate::procedure::procedure_registry::ProcedureRegistry;
+use crate::procedure::run_report::ProcedureReport;
+use std::cell::RefCell;
+use std::time::Instant;
 
 pub struct ProcedureChain<G: UndirectedGraph> {
     _proc_registry: ProcedureRegistry<G>,
     procedures: Vec<Box<dyn Procedure<G>>>,
+    // proc types of procedures, used in reports
+    names: Vec<String>,
+    reports: RefCell<Vec<ProcedureReport>>,
 }
 
 impl<G: UndirectedGraph + GraphConstructor + Clone + 'static> ProcedureChain<G> {
@@ -15,7 +22,9 @@ impl<G: UndirectedGraph + GraphConstructor + Clone + 'static> ProcedureChain<G>
         configurations: Vec<ProcedureConfig>,
     ) -> Result<Self> {
         let mut procedures = vec![];
+        let mut names = vec![];
         for configuration in configurations {
+            names.push(configuration.proc_type.clone());
             let proc = registry.create_procedure(configuration)?;
             procedures.push(proc);
         }
@@ -23,6 +32,8 @@ impl<G: UndirectedGraph + GraphConstructor + Clone + 'static> ProcedureChain<G>
         Ok(ProcedureChain {
             _proc_registry: registry,
             procedures,
+            names,
+            reports: RefCell::new(vec![]),
         })
     }
 
@@ -30,9 +41,15 @@ impl<G: UndirectedGraph + GraphConstructor + Clone + 'static> ProcedureChain<G>
         procedures: Vec<Box<dyn Procedure<G>>>,
         registry: ProcedureRegistry<G>,
     ) -> Result<Self> {
+        // procedures created directly have no proc type
+        let names = (0..procedures.len())
+            .map(|index| format!("procedure-{}", index))
+            .collect();
         Ok(Self {
             _proc_registry: registry,
             procedures,
+            names,
+            reports: RefCell::new(vec![]),
         })
     }
 
@@ -42,8 +59,82 @@ impl<G: UndirectedGraph + GraphConstructor + Clone + 'static> ProcedureChain<G>
     }
 
     pub fn run(&self, graphs: &mut Vec<(G, GraphProperties)>) -> Result<()> {
-        for procedure in self.procedures.iter() {
-            procedure.run(graphs)?;
+        self.reset_reports();
+        for (index, procedure) in self.procedures.iter().enumerate() {
+            let graphs_in = graphs.len();
+            let begin = Instant::now();
+            let result = procedure.run(graphs);
+            self.reports.borrow_mut()[index].add_run(begin.elapsed(), graphs_in, graphs.len());
+            result?;
+        }
+        Ok(())
+    }
+
+    ///
+    /// timings of procedures of last run, procedures which were not run have zero duration
+    ///
+    pub fn reports(&self) -> Vec<ProcedureReport> {
+        self.reports.borrow().clone()
+    }
+
+    fn reset_reports(&self) {
+        let reports = self
+            .names
+            .iter()
+            .map(|name| ProcedureReport::new(name.clone()))
+            .collect();
+        self.reports.replace(reports);
+    }
+
+    ///
+    /// Runs procedures over graphs in chunks of at most chunk_size graphs - first procedure has to
+    /// produce graphs (e.g. read), so only one chunk is held in memory at a time
+    ///
+    pub fn run_streaming(&mut self, chunk_size: usize) -> Result<()> {
+        if chunk_size == 0 {
+            return Err(Error::ConfigError(String::from(
+                "chunk size of streaming mode has to be greater than 0",
+            )));
+        }
+        self.reset_reports();
+        let (source, procedures) = match self.procedures.split_first_mut() {
+            Some(procedures) => procedures,
+            None => return Ok(()),
+        };
+        let reports = &self.reports;
+        let begin = Instant::now();
+        let result = source.stream(chunk_size, &mut |chunk| {
+            let mut reports = reports.borrow_mut();
+            reports[0].add_run(Default::default(), 0, chunk.len());
+            for (index, procedure) in procedures.iter_mut().enumerate() {
+                let graphs_in = chunk.len();
+                let begin = Instant::now();
+                let result = procedure.run_chunk(chunk);
+                reports[index + 1].add_run(begin.elapsed(), graphs_in, chunk.len());
+                result?;
+                if chunk.is_empty() {
+                    break;
+                }
+            }
+            Ok(())
+        });
+        // time of source procedure without time spent in following procedures
+        let mut reports = self.reports.borrow_mut();
+        let downstream: u64 = reports
+            .iter()
+            .skip(1)
+            .map(|report| report.duration_us)
+            .sum();
+        let total = begin.elapsed().as_micros() as u64;
+        reports[0].duration_us = total.saturating_sub(downstream);
+        drop(reports);
+        result?;
+
+        for (index, procedure) in self.procedures.iter_mut().enumerate() {
+            let begin = Instant::now();
+            let result = procedure.finish();
+            self.reports.borrow_mut()[index].add_run(begin.elapsed(), 0, 0);
+            result?;
         }
         Ok(())
     }
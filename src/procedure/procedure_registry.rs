@@ -2,6 +2,7 @@ use std::collections::HashMap;
 
 use crate::graph::graph::GraphConstructor;
 use crate::graph::undirected::UndirectedGraph;
+use crate::procedure::basic_procedures::branch::{BranchProcedureBuilder, BranchProcedureConfig};
 use crate::procedure::basic_procedures::chrom_props::chromatic_properties::ChromaticPropsProcedureBuilder;
 use crate::procedure::basic_procedures::chrom_props::config::ChromaticPropsProcedureConfig;
 use crate::procedure::basic_procedures::colour::{ColourProcedureBuilder, ColourProcedureConfig};
@@ -11,7 +12,11 @@ use crate::procedure::basic_procedures::constructions::{
 use crate::procedure::basic_procedures::counter::{
     CounterProcedureBuilder, CounterProcedureConfig,
 };
+use crate::procedure::basic_procedures::dedup::{DedupProcedureBuilder, DedupProcedureConfig};
 use crate::procedure::basic_procedures::filter::{FilterProcedureBuilder, FilterProcedureConfig};
+use crate::procedure::basic_procedures::generate::{
+    GenerateProcedureBuilder, GenerateProcedureConfig,
+};
 use crate::procedure::basic_procedures::read::{ReadProcedureBuilder, ReadProcedureConfig};
 use crate::procedure::basic_procedures::unknown_procedure::UnknownProcedure;
 use crate::procedure::basic_procedures::write::{WriteProcedureBuilder, WriteProcedureConfig};
@@ -60,6 +65,18 @@ impl<G: UndirectedGraph + GraphConstructor + Clone + 'static> ProcedureRegistry<
             CounterProcedureConfig::PROC_TYPE.to_string(),
             CounterProcedureBuilder {},
         );
+        reg.insert(
+            DedupProcedureConfig::PROC_TYPE.to_string(),
+            DedupProcedureBuilder {},
+        );
+        reg.insert(
+            BranchProcedureConfig::PROC_TYPE.to_string(),
+            BranchProcedureBuilder {},
+        );
+        reg.insert(
+            GenerateProcedureConfig::PROC_TYPE.to_string(),
+            GenerateProcedureBuilder {},
+        );
         reg
     }
 
@@ -79,7 +96,7 @@ impl<G: UndirectedGraph + GraphConstructor + Clone + 'static> ProcedureRegistry<
 
         let proc_builder = self.registry.get(&config.proc_type);
         if let Some(builder) = proc_builder {
-            let proc = builder.build_from_map(conf_map);
+            let proc = builder.build_from_map_with_registry(conf_map, self);
             return proc;
         }
 
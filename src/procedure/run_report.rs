@@ -0,0 +1,78 @@
+use crate::procedure::procedure::Result;
+use crate::service::io::error::WriteError;
+use serde::Serialize;
+use std::fs;
+use std::time::Duration;
+
+///
+/// Time spent in procedure and number of graphs it received and passed on. In streaming mode
+/// values are summed over all chunks.
+///
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct ProcedureReport {
+    pub proc_type: String,
+    pub duration_us: u64,
+    pub graphs_in: usize,
+    pub graphs_out: usize,
+}
+
+impl ProcedureReport {
+    pub fn new(proc_type: String) -> Self {
+        ProcedureReport {
+            proc_type,
+            duration_us: 0,
+            graphs_in: 0,
+            graphs_out: 0,
+        }
+    }
+
+    pub fn add_run(&mut self, duration: Duration, graphs_in: usize, graphs_out: usize) {
+        self.duration_us += duration.as_micros() as u64;
+        self.graphs_in += graphs_in;
+        self.graphs_out += graphs_out;
+    }
+
+    pub fn duration_ms(&self) -> u64 {
+        self.duration_us / 1000
+    }
+}
+
+///
+/// Summary of whole run of procedure chain
+///
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct RunReport {
+    pub graphs: usize,
+    pub elapsed_ms: u64,
+    pub procedures: Vec<ProcedureReport>,
+    pub errors: Vec<String>,
+}
+
+impl RunReport {
+    ///
+    /// graphs - number of graphs passed on by last procedure
+    ///
+    pub fn new(procedures: Vec<ProcedureReport>, elapsed: Duration, errors: Vec<String>) -> Self {
+        let graphs = match procedures.last() {
+            Some(procedure) => procedure.graphs_out,
+            None => 0,
+        };
+        RunReport {
+            graphs,
+            elapsed_ms: elapsed.as_millis() as u64,
+            procedures,
+            errors,
+        }
+    }
+
+    pub fn to_json_string(&self) -> Result<String> {
+        Ok(serde_json::to_string_pretty(self)?)
+    }
+
+    pub fn write_to_file(&self, file_path: &str) -> Result<()> {
+        fs::write(file_path, self.to_json_string()?).map_err(WriteError::from)?;
+        Ok(())
+    }
+}
@@ -1,7 +1,10 @@
+use crate::graph::graph::{Graph, GraphConstructor};
+use crate::graph::undirected::multi_graph::graph::MultiGraph;
 use crate::graph::undirected::simple_graph::graph::SimpleGraph;
 use crate::procedure::basic_procedures::chrom_props::chromatic_properties::ChromaticPropsProcedureBuilder;
 use crate::procedure::basic_procedures::chrom_props::config::{
-    ChromaticPropertiesToCompute, ChromaticPropsProcedureConfig, ParallelizationType,
+    CheckpointConfig, ChromaticPropertiesToCompute, ChromaticPropsProcedureConfig,
+    ParallelizationType,
 };
 use crate::procedure::basic_procedures::colour::{
     ColourProcedureBuilder, ColourProcedureConfig, ColouriserType,
@@ -12,12 +15,27 @@ use crate::procedure::basic_procedures::constructions::{
 use crate::procedure::basic_procedures::counter::{
     CounterProcedureBuilder, CounterProcedureConfig,
 };
+use crate::procedure::basic_procedures::dedup::{
+    DedupProcedureBuilder, DedupProcedureConfig, KEEP_FIRST, KEEP_MERGED,
+};
 use crate::procedure::basic_procedures::filter::{FilterProcedureBuilder, FilterProcedureConfig};
+use crate::procedure::basic_procedures::generate::{
+    GenerateProcedureBuilder, GenerateProcedureConfig,
+};
 use crate::procedure::basic_procedures::read;
 use crate::procedure::basic_procedures::read::{ReadProcedureBuilder, ReadProcedureConfig};
 use crate::procedure::basic_procedures::write::{WriteProcedureBuilder, WriteProcedureConfig};
+use crate::procedure::configuration;
+use crate::procedure::configuration::Configuration;
+use crate::procedure::error::Error;
+use crate::procedure::procedure;
 use crate::procedure::procedure::{GraphProperties, Procedure};
+use crate::procedure::procedure_builder::ProcedureBuilder;
 use crate::procedure::procedure_chain::ProcedureChain;
+use crate::procedure::procedure_registry::ProcedureRegistry;
+use crate::procedure::run_report::RunReport;
+use crate::service::io::reader_g6::G6Reader;
+use crate::service::symmetry::canonical;
 use crate::tests::test_data::test_data;
 use std::collections::HashMap;
 
@@ -81,3 +99,1580 @@ fn should_create_procedures() {
     procedures.push(write);
     procedures.push(chrom_props);
 }
+
+#[test]
+fn should_add_colouring_to_properties() {
+    let colour_config = ColourProcedureConfig::new(ColouriserType::Sat, true, None, false);
+    let colour = ColourProcedureBuilder::build(colour_config);
+
+    let graph = G6Reader::<SimpleGraph>::read_graph(test_data::NO_SNARK_IN_G6_18).unwrap();
+    let mut graphs = vec![(graph, GraphProperties::new())];
+    graphs.push((test_data::get_petersen_graph(), GraphProperties::new()));
+    colour.run(&mut graphs).unwrap();
+
+    assert_eq!(graphs[0].1["colourable"], serde_json::Value::Bool(true));
+    let colouring = graphs[0].1["colouring"].as_array().unwrap();
+    assert_eq!(colouring.len(), 27);
+    assert_eq!(graphs[1].1["colourable"], serde_json::Value::Bool(false));
+    assert_eq!(graphs[1].1.get("colouring"), None);
+}
+
+#[test]
+fn should_write_unsat_proofs_to_proof_dir() {
+    let proof_dir =
+        std::env::temp_dir().join(format!("snark-tool-colour-proofs-{}", std::process::id()));
+    let colour_config = ColourProcedureConfig::new(
+        ColouriserType::Sat,
+        false,
+        Some(proof_dir.to_string_lossy().to_string()),
+        false,
+    );
+    let colour = ColourProcedureBuilder::build(colour_config);
+
+    let graph = G6Reader::<SimpleGraph>::read_graph(test_data::NO_SNARK_IN_G6_18).unwrap();
+    let mut graphs = vec![(graph, GraphProperties::new())];
+    graphs.push((test_data::get_petersen_graph(), GraphProperties::new()));
+    colour.run(&mut graphs).unwrap();
+
+    assert_eq!(graphs[0].1.get("unsat-proof"), None);
+    assert_eq!(graphs[1].1["colourable"], serde_json::Value::Bool(false));
+    assert_eq!(proof_dir.join("graph-0.cnf").exists(), false);
+    assert_eq!(proof_dir.join("graph-1.cnf").exists(), true);
+    assert_eq!(proof_dir.join("graph-1.drat").exists(), true);
+    std::fs::remove_dir_all(proof_dir).unwrap();
+}
+
+#[test]
+fn should_write_unsat_proofs_to_proof_dir_for_sat_cadical() {
+    let proof_dir = std::env::temp_dir().join(format!(
+        "snark-tool-colour-cadical-proofs-{}",
+        std::process::id()
+    ));
+    let mut config = HashMap::new();
+    config.insert(
+        "colouriser-type".to_string(),
+        serde_json::json!("sat-cadical"),
+    );
+    config.insert(
+        "proof-dir".to_string(),
+        serde_json::json!(proof_dir.to_string_lossy()),
+    );
+    config.insert("with-colouring".to_string(), serde_json::json!(true));
+    let colour_config = ColourProcedureConfig::from_proc_config(&config).unwrap();
+    let colour = ColourProcedureBuilder::build(colour_config);
+
+    let graph = G6Reader::<SimpleGraph>::read_graph(test_data::NO_SNARK_IN_G6_18).unwrap();
+    let mut graphs = vec![(graph, GraphProperties::new())];
+    graphs.push((test_data::get_petersen_graph(), GraphProperties::new()));
+    colour.run(&mut graphs).unwrap();
+
+    // colouring of colourable graph is taken from the same solver run
+    assert_eq!(graphs[0].1["colourable"], serde_json::Value::Bool(true));
+    assert_eq!(graphs[0].1.get("colouring").is_some(), true);
+    assert_eq!(graphs[1].1["colourable"], serde_json::Value::Bool(false));
+    assert_eq!(graphs[1].1["unsat-proof"].get("drat").is_some(), true);
+    assert_eq!(proof_dir.join("graph-0.cnf").exists(), false);
+    assert_eq!(proof_dir.join("graph-0.drat").exists(), false);
+    assert_eq!(proof_dir.join("graph-1.cnf").exists(), true);
+    assert_eq!(proof_dir.join("graph-1.drat").exists(), true);
+    std::fs::remove_dir_all(proof_dir).unwrap();
+}
+
+#[test]
+fn should_reject_proof_dir_for_non_sat_colouriser() {
+    let mut config = HashMap::new();
+    config.insert(
+        "colouriser-type".to_string(),
+        serde_json::Value::String("dfs".to_string()),
+    );
+    config.insert(
+        "proof-dir".to_string(),
+        serde_json::Value::String("proofs".to_string()),
+    );
+    let result = ColourProcedureConfig::from_proc_config(&config);
+    assert_eq!(result.is_err(), true);
+}
+
+#[test]
+fn should_add_colourings_count_to_properties() {
+    let mut to_compute = ChromaticPropertiesToCompute::new();
+    to_compute.colourings_count = true;
+    let chrom_props_config = ChromaticPropsProcedureConfig::new(
+        ColouriserType::Dfs,
+        ParallelizationType::None,
+        to_compute,
+        1,
+    );
+    let chrom_props = ChromaticPropsProcedureBuilder::build(chrom_props_config);
+
+    let graph = G6Reader::<SimpleGraph>::read_graph(test_data::NO_SNARK_IN_G6_18).unwrap();
+    let mut graphs = vec![(graph, GraphProperties::new())];
+    graphs.push((test_data::get_petersen_graph(), GraphProperties::new()));
+    chrom_props.run(&mut graphs).unwrap();
+
+    assert_eq!(graphs[0].1["colourings-count"].as_u64().unwrap() > 0, true);
+    assert_eq!(graphs[1].1["colourings-count"], serde_json::json!(0));
+}
+
+#[test]
+fn should_add_automorphism_properties() {
+    let mut to_compute = ChromaticPropertiesToCompute::new();
+    to_compute.automorphism_group_order = true;
+    to_compute.vertex_orbits = true;
+    to_compute.edge_orbits = true;
+    let chrom_props_config = ChromaticPropsProcedureConfig::new(
+        ColouriserType::Dfs,
+        ParallelizationType::None,
+        to_compute,
+        1,
+    );
+    let chrom_props = ChromaticPropsProcedureBuilder::build(chrom_props_config);
+
+    let mut graphs = vec![(test_data::get_petersen_graph(), GraphProperties::new())];
+    chrom_props.run(&mut graphs).unwrap();
+
+    assert_eq!(
+        graphs[0].1["automorphism-group-order"],
+        serde_json::json!(120)
+    );
+    assert_eq!(
+        graphs[0].1["vertex-orbits"],
+        serde_json::json!([[0, 1, 2, 3, 4, 5, 6, 7, 8, 9]])
+    );
+    assert_eq!(graphs[0].1["edge-orbits"][0].as_array().unwrap().len(), 15);
+}
+
+#[test]
+fn should_run_procedure_chain_in_chunks() {
+    let output =
+        std::env::temp_dir().join(format!("snark-tool-streaming-{}.json", std::process::id()));
+    let read_config = ReadProcedureConfig::new(
+        test_data::GG_30_G05_CYC4_G6_100_FILE_PATH.to_string(),
+        read::G6_FORMAT.to_string(),
+        None,
+    );
+    let mut to_compute = ChromaticPropertiesToCompute::new();
+    to_compute.girth = true;
+    let chrom_props_config = ChromaticPropsProcedureConfig::new(
+        ColouriserType::Dfs,
+        ParallelizationType::None,
+        to_compute,
+        1,
+    );
+    let write_config = WriteProcedureConfig::new(
+        output.to_string_lossy().to_string(),
+        read::G6_FORMAT.to_string(),
+        true,
+        false,
+    );
+    let procedures: Vec<Box<dyn Procedure<SimpleGraph>>> = vec![
+        ReadProcedureBuilder::build(read_config),
+        ColourProcedureBuilder::build(ColourProcedureConfig::default()),
+        ChromaticPropsProcedureBuilder::build(chrom_props_config),
+        CounterProcedureBuilder::build(CounterProcedureConfig::new(false)),
+        WriteProcedureBuilder::build(write_config),
+    ];
+    let mut chain = ProcedureChain::from_procedures(procedures).unwrap();
+    chain.run_streaming(7).unwrap();
+
+    let written = std::fs::read_to_string(&output).unwrap();
+    std::fs::remove_file(&output).unwrap();
+    let written: Vec<serde_json::Value> = serde_json::from_str(&written).unwrap();
+    assert_eq!(written.len(), 100);
+    for (index, graph) in written.iter().enumerate() {
+        assert_eq!(graph["properties"]["graph-index"], serde_json::json!(index));
+        assert_eq!(graph["properties"]["colourable"].is_boolean(), true);
+    }
+}
+
+#[test]
+fn should_require_source_of_graphs_in_streaming_mode() {
+    let colour = ColourProcedureBuilder::build(ColourProcedureConfig::default());
+    let mut chain: ProcedureChain<SimpleGraph> =
+        ProcedureChain::from_procedures(vec![colour]).unwrap();
+    assert_eq!(chain.run_streaming(10).is_err(), true);
+}
+
+#[test]
+fn should_parse_streaming_config() {
+    let config = Configuration::from_yaml_string(
+        "version: 0.1\nstreaming:\n  chunk-size: 50\nprocedures:\n  - proc-type: count\n",
+    )
+    .unwrap();
+    assert_eq!(config.streaming.unwrap().chunk_size, 50);
+
+    let config =
+        Configuration::from_yaml_string("version: 0.1\nprocedures:\n  - proc-type: count\n")
+            .unwrap();
+    assert_eq!(config.streaming.is_none(), true);
+}
+
+fn graphs_with_isomorphic_copies() -> Vec<(SimpleGraph, GraphProperties)> {
+    let petersen = test_data::get_petersen_graph();
+    let graph = G6Reader::<SimpleGraph>::read_graph(test_data::NO_SNARK_IN_G6_18).unwrap();
+    let copies = vec![
+        petersen.clone(),
+        graph.clone(),
+        canonical::canonical_form(&petersen),
+        canonical::canonical_form(&graph),
+        canonical::canonical_form(&petersen),
+    ];
+    copies
+        .into_iter()
+        .enumerate()
+        .map(|(index, graph)| {
+            let mut properties = GraphProperties::new();
+            properties.insert(format!("copy-{}", index), serde_json::json!(index));
+            (graph, properties)
+        })
+        .collect()
+}
+
+#[test]
+fn should_remove_isomorphic_copies() {
+    let dedup =
+        DedupProcedureBuilder::build(DedupProcedureConfig::new(KEEP_FIRST.to_string(), true));
+    let mut graphs = graphs_with_isomorphic_copies();
+    dedup.run(&mut graphs).unwrap();
+
+    assert_eq!(graphs.len(), 2);
+    assert_eq!(graphs[0].1["copy-0"], serde_json::json!(0));
+    assert_eq!(graphs[0].1["duplicates-count"], serde_json::json!(2));
+    assert_eq!(graphs[1].1["copy-1"], serde_json::json!(1));
+    assert_eq!(graphs[1].1["duplicates-count"], serde_json::json!(1));
+}
+
+#[test]
+fn should_merge_properties_of_isomorphic_copies() {
+    let dedup =
+        DedupProcedureBuilder::build(DedupProcedureConfig::new(KEEP_MERGED.to_string(), false));
+    let mut graphs = graphs_with_isomorphic_copies();
+    dedup.run(&mut graphs).unwrap();
+
+    assert_eq!(graphs.len(), 2);
+    assert_eq!(graphs[0].1.len(), 3);
+    assert_eq!(graphs[0].1["copy-4"], serde_json::json!(4));
+    assert_eq!(graphs[1].1.len(), 2);
+    assert_eq!(graphs[1].1.get("duplicates-count"), None);
+}
+
+#[test]
+fn should_not_remove_multigraphs_with_same_underlying_graph() {
+    // path 0-1-2-3 with doubled end edge, doubled middle edge and other doubled end edge
+    let path_with_double_edge = |from: usize, to: usize| {
+        let mut graph = MultiGraph::with_vertices_capacity(4);
+        for vertex in 0..3 {
+            graph.add_edge(vertex, vertex + 1);
+        }
+        graph.add_edge(from, to);
+        (graph, GraphProperties::new())
+    };
+    let mut graphs = vec![
+        path_with_double_edge(0, 1),
+        path_with_double_edge(1, 2),
+        path_with_double_edge(2, 3),
+    ];
+    let dedup =
+        DedupProcedureBuilder::build(DedupProcedureConfig::new(KEEP_FIRST.to_string(), true));
+    dedup.run(&mut graphs).unwrap();
+
+    assert_eq!(graphs.len(), 2);
+    assert_eq!(graphs[0].0.has_edge(0, 1), true);
+    assert_eq!(graphs[0].1["duplicates-count"], serde_json::json!(1));
+    assert_eq!(graphs[1].1["duplicates-count"], serde_json::json!(0));
+}
+
+#[test]
+fn should_reject_unknown_dedup_keep_option() {
+    let mut config = HashMap::new();
+    config.insert("keep".to_string(), serde_json::json!("random"));
+    let result = DedupProcedureConfig::from_proc_config(&config);
+    assert_eq!(result.is_err(), true);
+}
+
+#[test]
+fn should_add_flow_number_properties() {
+    let mut to_compute = ChromaticPropertiesToCompute::new();
+    to_compute.flow_number = true;
+    let chrom_props_config = ChromaticPropsProcedureConfig::new(
+        ColouriserType::Dfs,
+        ParallelizationType::None,
+        to_compute,
+        1,
+    );
+    let chrom_props = ChromaticPropsProcedureBuilder::build(chrom_props_config);
+
+    let graph = G6Reader::<SimpleGraph>::read_graph(test_data::NO_SNARK_IN_G6_18).unwrap();
+    let mut graphs = vec![(test_data::get_petersen_graph(), GraphProperties::new())];
+    graphs.push((graph, GraphProperties::new()));
+    chrom_props.run(&mut graphs).unwrap();
+
+    assert_eq!(graphs[0].1["flow-number"], serde_json::json!(5));
+    assert_eq!(
+        graphs[0].1["circular-flow-number"]["upper-bound"],
+        serde_json::json!("5/1")
+    );
+    assert_eq!(graphs[1].1["flow-number"].as_u64().unwrap() <= 4, true);
+}
+
+#[test]
+fn should_add_perfect_matching_cover_properties() {
+    let mut to_compute = ChromaticPropertiesToCompute::new();
+    to_compute.perfect_matching_index = true;
+    to_compute.fulkerson_cover = true;
+    let chrom_props_config = ChromaticPropsProcedureConfig::new(
+        ColouriserType::Dfs,
+        ParallelizationType::None,
+        to_compute,
+        1,
+    );
+    let chrom_props = ChromaticPropsProcedureBuilder::build(chrom_props_config);
+
+    let graph = G6Reader::<SimpleGraph>::read_graph(test_data::NO_SNARK_IN_G6_18).unwrap();
+    let mut graphs = vec![(test_data::get_petersen_graph(), GraphProperties::new())];
+    graphs.push((graph, GraphProperties::new()));
+    chrom_props.run(&mut graphs).unwrap();
+
+    assert_eq!(graphs[0].1["perfect-matching-index"], serde_json::json!(5));
+    assert_eq!(graphs[0].1["fulkerson-cover"], serde_json::json!(true));
+    assert_eq!(graphs[1].1["perfect-matching-index"], serde_json::json!(3));
+    assert_eq!(graphs[1].1["fulkerson-cover"], serde_json::json!(true));
+}
+
+#[test]
+fn should_add_cycle_double_cover_properties() {
+    let mut to_compute = ChromaticPropertiesToCompute::new();
+    to_compute.cdc = true;
+    to_compute.five_cdc = true;
+    let chrom_props_config = ChromaticPropsProcedureConfig::new(
+        ColouriserType::Dfs,
+        ParallelizationType::None,
+        to_compute,
+        1,
+    );
+    let chrom_props = ChromaticPropsProcedureBuilder::build(chrom_props_config);
+
+    let graph = G6Reader::<SimpleGraph>::read_graph(test_data::SNARK_IN_G6_20).unwrap();
+    let mut graphs = vec![(test_data::get_petersen_graph(), GraphProperties::new())];
+    graphs.push((graph, GraphProperties::new()));
+    chrom_props.run(&mut graphs).unwrap();
+
+    for graph in graphs.iter() {
+        assert_eq!(graph.1["cdc"], serde_json::json!(true));
+        assert_eq!(graph.1["5-cdc"], serde_json::json!(true));
+    }
+
+    // two triangles sharing vertex 0 of degree 4 - not searched
+    let mut graph = SimpleGraph::with_capacity(5, 6);
+    graph.add_edge(0, 1);
+    graph.add_edge(1, 2);
+    graph.add_edge(2, 0);
+    graph.add_edge(0, 3);
+    graph.add_edge(3, 4);
+    graph.add_edge(4, 0);
+    let mut graphs = vec![(graph, GraphProperties::new())];
+    chrom_props.run(&mut graphs).unwrap();
+    assert_eq!(graphs[0].1["cdc"], serde_json::Value::Null);
+    assert_eq!(graphs[0].1["5-cdc"], serde_json::Value::Null);
+}
+
+#[test]
+fn should_add_oddness_witness_and_weak_oddness_properties() {
+    let mut to_compute = ChromaticPropertiesToCompute::new();
+    to_compute.oddness_witness = true;
+    to_compute.weak_oddness = true;
+    to_compute.weak_oddness_witness = true;
+    let chrom_props_config = ChromaticPropsProcedureConfig::new(
+        ColouriserType::Dfs,
+        ParallelizationType::None,
+        to_compute,
+        1,
+    );
+    let chrom_props = ChromaticPropsProcedureBuilder::build(chrom_props_config);
+
+    let mut graphs = vec![(test_data::get_petersen_graph(), GraphProperties::new())];
+    chrom_props.run(&mut graphs).unwrap();
+
+    let properties = &graphs[0].1;
+    assert_eq!(properties.contains_key("oddness"), false);
+    let witness = &properties["oddness-witness"];
+    assert_eq!(witness["perfect-matching"].as_array().unwrap().len(), 5);
+    assert_eq!(witness["odd-cycles"].as_array().unwrap().len(), 2);
+    assert_eq!(properties["weak-oddness"], serde_json::json!(2));
+    assert_eq!(
+        properties["weak-oddness-witness"]["odd-components"]
+            .as_array()
+            .unwrap()
+            .len(),
+        2
+    );
+}
+
+#[test]
+fn should_add_null_oddness_of_graph_without_perfect_matching() {
+    let mut to_compute = ChromaticPropertiesToCompute::new();
+    to_compute.oddness = true;
+    to_compute.oddness_witness = true;
+    let chrom_props_config = ChromaticPropsProcedureConfig::new(
+        ColouriserType::Dfs,
+        ParallelizationType::None,
+        to_compute,
+        1,
+    );
+    let chrom_props = ChromaticPropsProcedureBuilder::build(chrom_props_config);
+
+    // star with three leaves
+    let mut graph = SimpleGraph::with_capacity(4, 3);
+    graph.add_edge(0, 1);
+    graph.add_edge(0, 2);
+    graph.add_edge(0, 3);
+    let mut graphs = vec![(graph, GraphProperties::new())];
+    chrom_props.run(&mut graphs).unwrap();
+
+    assert_eq!(graphs[0].1["oddness"], serde_json::Value::Null);
+    assert_eq!(graphs[0].1["oddness-witness"], serde_json::Value::Null);
+}
+
+#[test]
+fn should_add_resistance_witness() {
+    let mut to_compute = ChromaticPropertiesToCompute::new();
+    to_compute.resistance = true;
+    to_compute.resistance_witness = true;
+    let chrom_props_config = ChromaticPropsProcedureConfig::new(
+        ColouriserType::Dfs,
+        ParallelizationType::None,
+        to_compute,
+        1,
+    );
+    let chrom_props = ChromaticPropsProcedureBuilder::build(chrom_props_config);
+
+    let graph =
+        G6Reader::<SimpleGraph>::read_graph(test_data::SNARK_IN_G6_36_STABLE_RES_3).unwrap();
+    let mut graphs = vec![(graph, GraphProperties::new())];
+    chrom_props.run(&mut graphs).unwrap();
+
+    assert_eq!(graphs[0].1["resistance"], serde_json::json!(3));
+    let witness = graphs[0].1["resistance-witness"].as_array().unwrap();
+    assert_eq!(witness.len(), 3);
+}
+
+#[test]
+fn should_report_procedure_timings() {
+    let config = Configuration::from_yaml_string(&format!(
+        "version: 0.1\nreport:\n  file: report.json\nprocedures:\n  - proc-type: read\n    config:\n      file: {}\n  - proc-type: colour\n    config:\n      with-time: true\n  - proc-type: filter\n    config:\n      filter-by:\n        colourable: false\n",
+        test_data::GG_30_G05_CYC4_G6_100_FILE_PATH
+    ))
+    .unwrap();
+    assert_eq!(config.report.unwrap().file, Some("report.json".to_string()));
+    let chain =
+        ProcedureChain::from_procedures_config(ProcedureRegistry::new_basic(), config.procedures)
+            .unwrap();
+    let mut graphs: Vec<(SimpleGraph, GraphProperties)> = vec![];
+    chain.run(&mut graphs).unwrap();
+
+    for graph in graphs.iter() {
+        assert_eq!(graph.1["colour-time-us"].is_u64(), true);
+    }
+    let reports = chain.reports();
+    let proc_types: Vec<&str> = reports
+        .iter()
+        .map(|report| report.proc_type.as_str())
+        .collect();
+    assert_eq!(proc_types, vec!["read", "colour", "filter"]);
+    assert_eq!(reports[0].graphs_out, 100);
+    assert_eq!(reports[1].graphs_in, 100);
+    assert_eq!(reports[2].graphs_out, graphs.len());
+
+    let report = RunReport::new(
+        reports,
+        std::time::Duration::from_millis(5),
+        vec!["error".to_string()],
+    );
+    let report: serde_json::Value =
+        serde_json::from_str(&report.to_json_string().unwrap()).unwrap();
+    assert_eq!(report["graphs"], serde_json::json!(graphs.len()));
+    assert_eq!(report["elapsed-ms"], serde_json::json!(5));
+    assert_eq!(
+        report["procedures"][1]["proc-type"],
+        serde_json::json!("colour")
+    );
+    assert_eq!(report["procedures"][1]["duration-us"].is_u64(), true);
+    assert_eq!(report["errors"], serde_json::json!(["error"]));
+}
+
+#[test]
+fn should_report_procedure_timings_in_streaming_mode() {
+    let read_config = ReadProcedureConfig::new(
+        test_data::GG_30_G05_CYC4_G6_100_FILE_PATH.to_string(),
+        read::G6_FORMAT.to_string(),
+        None,
+    );
+    let procedures: Vec<Box<dyn Procedure<SimpleGraph>>> = vec![
+        ReadProcedureBuilder::build(read_config),
+        ColourProcedureBuilder::build(ColourProcedureConfig::default()),
+        CounterProcedureBuilder::build(CounterProcedureConfig::new(false)),
+    ];
+    let mut chain = ProcedureChain::from_procedures(procedures).unwrap();
+    chain.run_streaming(30).unwrap();
+
+    let reports = chain.reports();
+    assert_eq!(reports.len(), 3);
+    assert_eq!(reports[0].proc_type, "procedure-0");
+    assert_eq!(reports[0].graphs_out, 100);
+    assert_eq!(reports[1].graphs_in, 100);
+    assert_eq!(reports[2].graphs_out, 100);
+}
+
+#[test]
+fn should_parse_graph_type_config() {
+    let config = Configuration::from_yaml_string(
+        "version: 0.1\ngraph-type: multi\nprocedures:\n  - proc-type: count\n",
+    )
+    .unwrap();
+    assert_eq!(config.graph_type(), configuration::MULTI_GRAPH);
+
+    let config =
+        Configuration::from_yaml_string("version: 0.1\nprocedures:\n  - proc-type: count\n")
+            .unwrap();
+    assert_eq!(config.graph_type(), configuration::SIMPLE_GRAPH);
+}
+
+#[test]
+fn should_keep_parallel_edges_of_multigraph() {
+    // cubic multigraph with digons 0-1 and 2-3
+    let file_path =
+        std::env::temp_dir().join(format!("snark-tool-multigraph-{}.ba", std::process::id()));
+    std::fs::write(&file_path, "1\n1\n4\n1 1 2 \n0 0 3 \n0 3 3 \n1 2 2 \n").unwrap();
+    let config = Configuration::from_yaml_string(&format!(
+        "version: 0.1\ngraph-type: multi\nprocedures:\n  - proc-type: read\n    config:\n      file: {}\n      graph-format: ba\n",
+        file_path.to_string_lossy()
+    ))
+    .unwrap();
+
+    let chain: ProcedureChain<MultiGraph> =
+        ProcedureChain::from_procedures_config(ProcedureRegistry::new_basic(), config.procedures)
+            .unwrap();
+    let mut multigraphs = vec![];
+    chain.run(&mut multigraphs).unwrap();
+    assert_eq!(multigraphs.len(), 1);
+    assert_eq!(multigraphs[0].0.edges().count(), 6);
+
+    let read_config = ReadProcedureConfig::new(
+        file_path.to_string_lossy().to_string(),
+        read::BA_FORMAT.to_string(),
+        None,
+    );
+    let chain: ProcedureChain<SimpleGraph> =
+        ProcedureChain::from_procedures(vec![ReadProcedureBuilder::build(read_config)]).unwrap();
+    let mut graphs = vec![];
+    chain.run(&mut graphs).unwrap();
+    assert_eq!(graphs[0].0.edges().count(), 4);
+    std::fs::remove_file(file_path).unwrap();
+}
+
+#[test]
+fn should_colour_multigraph_with_digons() {
+    // cubic multigraph with digons 0-1 and 2-3
+    let file_path = std::env::temp_dir().join(format!(
+        "snark-tool-multigraph-colour-{}.ba",
+        std::process::id()
+    ));
+    std::fs::write(&file_path, "1\n1\n4\n1 1 2 \n0 0 3 \n0 3 3 \n1 2 2 \n").unwrap();
+    let config = Configuration::from_yaml_string(&format!(
+        "version: 0.1\ngraph-type: multi\nprocedures:\n  - proc-type: read\n    config:\n      file: {}\n      graph-format: ba\n  - proc-type: colour\n    config:\n      colouriser-type: sat\n      with-colouring: true\n",
+        file_path.to_string_lossy()
+    ))
+    .unwrap();
+    assert_eq!(config.check_graph_type().is_ok(), true);
+
+    let chain: ProcedureChain<MultiGraph> =
+        ProcedureChain::from_procedures_config(ProcedureRegistry::new_basic(), config.procedures)
+            .unwrap();
+    let mut multigraphs = vec![];
+    chain.run(&mut multigraphs).unwrap();
+    std::fs::remove_file(file_path).unwrap();
+    assert_eq!(
+        multigraphs[0].1["colourable"],
+        serde_json::Value::Bool(true)
+    );
+    let colouring = multigraphs[0].1["colouring"].as_array().unwrap();
+    assert_eq!(colouring.len(), 6);
+    // parallel edges 0-1 go first and have different colours
+    assert_eq!(colouring[0][1], serde_json::json!(1));
+    assert_eq!(colouring[1][1], serde_json::json!(1));
+    assert_ne!(colouring[0][2], colouring[1][2]);
+}
+
+#[test]
+fn should_reject_multigraphs_in_procedures_without_parallel_edges() {
+    let procedures = vec![
+        "  - proc-type: colour\n",
+        "  - proc-type: chromatic-properties\n    config:\n      properties: [critical]\n",
+        "  - proc-type: branch\n    config:\n      branches:\n        - name: all\n          procedures:\n            - proc-type: colour\n              config:\n                colouriser-type: dfs\n",
+    ];
+    for procedure in procedures {
+        let config = Configuration::from_yaml_string(&format!(
+            "version: 0.1\ngraph-type: multi\nprocedures:\n{}",
+            procedure
+        ))
+        .unwrap();
+        assert_eq!(config.check_graph_type().is_err(), true);
+
+        // simple graphs are not restricted
+        let config =
+            Configuration::from_yaml_string(&format!("version: 0.1\nprocedures:\n{}", procedure))
+                .unwrap();
+        assert_eq!(config.check_graph_type().is_ok(), true);
+    }
+}
+
+#[test]
+fn should_filter_graphs_by_expression() {
+    let mut graphs = vec![];
+    for (colourable, oddness, resistance) in vec![(false, 2, 3), (false, 4, 2), (false, 2, 2)] {
+        let mut properties = GraphProperties::new();
+        properties.insert("colourable".to_string(), serde_json::json!(colourable));
+        properties.insert("oddness".to_string(), serde_json::json!(oddness));
+        properties.insert("resistance".to_string(), serde_json::json!(resistance));
+        graphs.push((test_data::get_petersen_graph(), properties));
+    }
+    graphs.push((test_data::get_petersen_graph(), GraphProperties::new()));
+
+    let mut config = HashMap::new();
+    config.insert(
+        "filter".to_string(),
+        serde_json::json!("colourable == false && (oddness >= 4 || resistance > 2)"),
+    );
+    let filter: Box<dyn Procedure<SimpleGraph>> =
+        FilterProcedureBuilder {}.build_from_map(config).unwrap();
+    filter.run(&mut graphs).unwrap();
+    assert_eq!(graphs.len(), 2);
+    assert_eq!(graphs[0].1["resistance"], serde_json::json!(3));
+    assert_eq!(graphs[1].1["oddness"], serde_json::json!(4));
+
+    let mut config = HashMap::new();
+    config.insert("filter".to_string(), serde_json::json!("oddness >= && 4"));
+    let filter: Result<Box<dyn Procedure<SimpleGraph>>, _> =
+        FilterProcedureBuilder {}.build_from_map(config);
+    assert_eq!(filter.is_err(), true);
+    let filter: Result<Box<dyn Procedure<SimpleGraph>>, _> =
+        FilterProcedureBuilder {}.build_from_map(HashMap::new());
+    assert_eq!(filter.is_err(), true);
+}
+
+#[test]
+fn should_filter_graphs_by_five_cdc() {
+    let mut graphs = vec![];
+    for five_cdc in vec![true, false] {
+        let mut properties = GraphProperties::new();
+        properties.insert("5-cdc".to_string(), serde_json::json!(five_cdc));
+        graphs.push((test_data::get_petersen_graph(), properties));
+    }
+
+    let mut config = HashMap::new();
+    config.insert("filter".to_string(), serde_json::json!("5-cdc == false"));
+    let filter: Box<dyn Procedure<SimpleGraph>> =
+        FilterProcedureBuilder {}.build_from_map(config).unwrap();
+    filter.run(&mut graphs).unwrap();
+    assert_eq!(graphs.len(), 1);
+    assert_eq!(graphs[0].1["5-cdc"], serde_json::json!(false));
+}
+
+#[test]
+fn should_route_graphs_to_branches() {
+    let dir = std::env::temp_dir().join(format!("snark-tool-branches-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let colourable_path = dir.join("colourable.g6");
+    let snarks_path = dir.join("snarks.json");
+    let config = Configuration::from_yaml_string(&format!(
+        "version: 0.1
+procedures:
+  - proc-type: colour
+  - proc-type: branch
+    config:
+      merge: true
+      branches:
+        - name: colourable
+          filter: colourable
+          procedures:
+            - proc-type: write
+              config:
+                file: {}
+                with-properties: false
+        - name: snarks
+          procedures:
+            - proc-type: filter
+              config:
+                filter: exists(colourable)
+            - proc-type: write
+              config:
+                file: {}
+                with-properties: true
+",
+        colourable_path.to_string_lossy(),
+        snarks_path.to_string_lossy()
+    ))
+    .unwrap();
+    let chain: ProcedureChain<SimpleGraph> =
+        ProcedureChain::from_procedures_config(ProcedureRegistry::new_basic(), config.procedures)
+            .unwrap();
+
+    let mut graphs = vec![];
+    for graph in vec![
+        test_data::NO_SNARK_IN_G6_18,
+        test_data::SNARK_IN_G6_10_PETERSEN,
+        test_data::SNARK_IN_G6_20,
+    ] {
+        graphs.push((
+            G6Reader::<SimpleGraph>::read_graph(graph).unwrap(),
+            GraphProperties::new(),
+        ));
+    }
+    chain.run(&mut graphs).unwrap();
+
+    // graphs of both branches are merged back in order of branches
+    assert_eq!(graphs.len(), 3);
+    assert_eq!(graphs[0].1["colourable"], serde_json::json!(true));
+    assert_eq!(graphs[1].1["colourable"], serde_json::json!(false));
+    assert_eq!(graphs[2].1["colourable"], serde_json::json!(false));
+    let colourable = std::fs::read_to_string(&colourable_path).unwrap();
+    assert_eq!(colourable.lines().count(), 1);
+    let snarks = std::fs::read_to_string(&snarks_path).unwrap();
+    let snarks: serde_json::Value = serde_json::from_str(&snarks).unwrap();
+    assert_eq!(snarks.as_array().unwrap().len(), 2);
+    assert_eq!(
+        snarks[0]["properties"]["colourable"],
+        serde_json::json!(false)
+    );
+    std::fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn should_keep_unmatched_graphs_without_merge() {
+    let config = Configuration::from_yaml_string(
+        "version: 0.1
+procedures:
+  - proc-type: branch
+    config:
+      branches:
+        - name: large
+          filter: order > 10
+",
+    )
+    .unwrap();
+    let chain: ProcedureChain<SimpleGraph> =
+        ProcedureChain::from_procedures_config(ProcedureRegistry::new_basic(), config.procedures)
+            .unwrap();
+    let mut graphs = vec![];
+    for order in vec![10, 20, 8] {
+        let mut properties = GraphProperties::new();
+        properties.insert("order".to_string(), serde_json::json!(order));
+        graphs.push((test_data::get_petersen_graph(), properties));
+    }
+    chain.run(&mut graphs).unwrap();
+    assert_eq!(graphs.len(), 2);
+    assert_eq!(graphs[0].1["order"], serde_json::json!(10));
+    assert_eq!(graphs[1].1["order"], serde_json::json!(8));
+}
+
+#[test]
+fn should_merge_branches_after_unmatched_graphs() {
+    let config = Configuration::from_yaml_string(
+        "version: 0.1
+procedures:
+  - proc-type: branch
+    config:
+      merge: true
+      branches:
+        - name: large
+          filter: order > 10
+        - name: medium
+          filter: order > 8
+",
+    )
+    .unwrap();
+    let chain: ProcedureChain<SimpleGraph> =
+        ProcedureChain::from_procedures_config(ProcedureRegistry::new_basic(), config.procedures)
+            .unwrap();
+    let mut graphs = vec![];
+    for order in vec![20, 10, 8, 30] {
+        let mut properties = GraphProperties::new();
+        properties.insert("order".to_string(), serde_json::json!(order));
+        graphs.push((test_data::get_petersen_graph(), properties));
+    }
+    chain.run(&mut graphs).unwrap();
+
+    // unmatched graphs first, then graphs of branches in order of branches
+    let orders: Vec<serde_json::Value> = graphs
+        .iter()
+        .map(|graph| graph.1["order"].clone())
+        .collect();
+    assert_eq!(
+        orders,
+        vec![
+            serde_json::json!(8),
+            serde_json::json!(20),
+            serde_json::json!(30),
+            serde_json::json!(10)
+        ]
+    );
+}
+
+struct MarkProcedure {}
+
+impl Procedure<SimpleGraph> for MarkProcedure {
+    fn run(&self, graphs: &mut Vec<(SimpleGraph, GraphProperties)>) -> procedure::Result<()> {
+        for graph in graphs.iter_mut() {
+            graph
+                .1
+                .insert("marked".to_string(), serde_json::json!(true));
+        }
+        Ok(())
+    }
+}
+
+struct MarkProcedureBuilder {}
+
+impl ProcedureBuilder<SimpleGraph> for MarkProcedureBuilder {
+    fn build_from_map(
+        &self,
+        _config: HashMap<String, serde_json::Value>,
+    ) -> procedure::Result<Box<dyn Procedure<SimpleGraph>>> {
+        Ok(Box::new(MarkProcedure {}))
+    }
+}
+
+#[test]
+fn should_create_branch_procedures_by_registry_of_chain() {
+    let config = Configuration::from_yaml_string(
+        "version: 0.1
+procedures:
+  - proc-type: branch
+    config:
+      merge: true
+      branches:
+        - name: large
+          filter: order > 10
+          procedures:
+            - proc-type: mark
+",
+    )
+    .unwrap();
+    let mut registry = ProcedureRegistry::new_basic();
+    registry.insert("mark".to_string(), MarkProcedureBuilder {});
+    let chain: ProcedureChain<SimpleGraph> =
+        ProcedureChain::from_procedures_config(registry, config.procedures).unwrap();
+    let mut graphs = vec![];
+    for order in vec![10, 20] {
+        let mut properties = GraphProperties::new();
+        properties.insert("order".to_string(), serde_json::json!(order));
+        graphs.push((test_data::get_petersen_graph(), properties));
+    }
+    chain.run(&mut graphs).unwrap();
+
+    assert_eq!(graphs[0].1.contains_key("marked"), false);
+    assert_eq!(graphs[1].1["marked"], serde_json::json!(true));
+}
+
+fn girth_config_with_checkpoint(
+    parallelization: ParallelizationType,
+    file: &std::path::Path,
+    resume: bool,
+) -> ChromaticPropsProcedureConfig {
+    let mut to_compute = ChromaticPropertiesToCompute::new();
+    to_compute.girth = true;
+    let mut config =
+        ChromaticPropsProcedureConfig::new(ColouriserType::Dfs, parallelization, to_compute, 2);
+    config.checkpoint = Some(CheckpointConfig {
+        file: file.to_string_lossy().to_string(),
+        interval: 2,
+        resume,
+    });
+    config
+}
+
+fn graphs_for_checkpoint() -> Vec<(SimpleGraph, GraphProperties)> {
+    vec![
+        (test_data::get_petersen_graph(), GraphProperties::new()),
+        (
+            G6Reader::read_graph(test_data::NO_SNARK_IN_G6_18).unwrap(),
+            GraphProperties::new(),
+        ),
+        (
+            G6Reader::read_graph(test_data::SNARK_IN_G6_20).unwrap(),
+            GraphProperties::new(),
+        ),
+    ]
+}
+
+#[test]
+fn should_checkpoint_and_resume_chromatic_properties() {
+    for (position, parallelization) in vec![
+        ParallelizationType::None,
+        ParallelizationType::BatchBased,
+        ParallelizationType::GraphBased,
+    ]
+    .into_iter()
+    .enumerate()
+    {
+        let file = std::env::temp_dir().join(format!(
+            "snark-tool-checkpoint-{}-{}.jsonl",
+            std::process::id(),
+            position
+        ));
+        let chrom_props = ChromaticPropsProcedureBuilder::build(girth_config_with_checkpoint(
+            parallelization.clone(),
+            &file,
+            false,
+        ));
+        let mut graphs = graphs_for_checkpoint();
+        chrom_props.run(&mut graphs).unwrap();
+        let checkpoint = std::fs::read_to_string(&file).unwrap();
+        assert_eq!(checkpoint.lines().count(), 3);
+
+        // graph 1 is finished with different girth, graph 2 is not finished
+        let lines: Vec<&str> = checkpoint.lines().collect();
+        let mut entries: Vec<serde_json::Value> = lines
+            .iter()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+        entries.retain(|entry| entry["graph-index"] != serde_json::json!(2));
+        for entry in entries.iter_mut() {
+            if entry["graph-index"] == serde_json::json!(1) {
+                entry["properties"]["girth"] = serde_json::json!(42);
+            }
+        }
+        let mut content: String = entries.iter().map(|entry| format!("{}\n", entry)).collect();
+        // incomplete line written by crashed run
+        content.push_str("{\"graph-index\": 2, \"prop");
+        std::fs::write(&file, content).unwrap();
+
+        let chrom_props = ChromaticPropsProcedureBuilder::build(girth_config_with_checkpoint(
+            parallelization,
+            &file,
+            true,
+        ));
+        let mut graphs = graphs_for_checkpoint();
+        chrom_props.run(&mut graphs).unwrap();
+        assert_eq!(graphs[0].1["girth"], serde_json::json!(5));
+        assert_eq!(graphs[1].1["girth"], serde_json::json!(42));
+        assert_eq!(graphs[1].1["graph-index"], serde_json::json!(1));
+        assert_eq!(graphs[2].1.contains_key("girth"), true);
+        assert_ne!(graphs[2].1["girth"], serde_json::json!(42));
+        std::fs::remove_file(file).unwrap();
+    }
+}
+
+#[test]
+fn should_resume_chromatic_properties_in_streaming_mode() {
+    let file = std::env::temp_dir().join(format!(
+        "snark-tool-checkpoint-streaming-{}.jsonl",
+        std::process::id()
+    ));
+    let chain = |resume: bool| {
+        let read_config = ReadProcedureConfig::new(
+            test_data::GG_30_G05_CYC4_G6_100_FILE_PATH.to_string(),
+            read::G6_FORMAT.to_string(),
+            Some(40),
+        );
+        let procedures: Vec<Box<dyn Procedure<SimpleGraph>>> = vec![
+            ReadProcedureBuilder::build(read_config),
+            ChromaticPropsProcedureBuilder::build(girth_config_with_checkpoint(
+                ParallelizationType::None,
+                &file,
+                resume,
+            )),
+        ];
+        ProcedureChain::from_procedures(procedures).unwrap()
+    };
+    chain(false).run_streaming(15).unwrap();
+    let checkpoint = std::fs::read_to_string(&file).unwrap();
+    assert_eq!(checkpoint.lines().count(), 40);
+    let entry: serde_json::Value =
+        serde_json::from_str(checkpoint.lines().last().unwrap()).unwrap();
+    assert_eq!(entry["graph-index"], serde_json::json!(39));
+
+    // resumed run only computes graphs missing in checkpoint
+    let content: String = checkpoint
+        .lines()
+        .filter(|line| !line.starts_with("{\"graph-index\":20,"))
+        .map(|line| format!("{}\n", line))
+        .collect();
+    std::fs::write(&file, content).unwrap();
+    chain(true).run_streaming(15).unwrap();
+    let checkpoint = std::fs::read_to_string(&file).unwrap();
+    assert_eq!(checkpoint.lines().count(), 40);
+    let entry: serde_json::Value =
+        serde_json::from_str(checkpoint.lines().last().unwrap()).unwrap();
+    assert_eq!(entry["graph-index"], serde_json::json!(20));
+    std::fs::remove_file(file).unwrap();
+}
+
+#[test]
+fn should_set_resume_option_of_procedures() {
+    let mut config = Configuration::from_yaml_string(
+        "version: 0.1\nprocedures:\n  - proc-type: count\n  - proc-type: chromatic-properties\n    config:\n      properties: [girth]\n      checkpoint-file: checkpoint.jsonl\n",
+    )
+    .unwrap();
+    config.resume();
+    // only procedures with checkpoint file are resumed
+    assert_eq!(config.procedures[0].config, None);
+    assert_eq!(
+        config.procedures[1].config.as_ref().unwrap()["resume"],
+        serde_json::json!(true)
+    );
+    let chrom_props_config = ChromaticPropsProcedureConfig::from_proc_config(
+        config.procedures[1].config.as_ref().unwrap(),
+    )
+    .unwrap();
+    let checkpoint = chrom_props_config.checkpoint.unwrap();
+    assert_eq!(checkpoint.file, "checkpoint.jsonl");
+    assert_eq!(checkpoint.interval, 10);
+    assert_eq!(checkpoint.resume, true);
+}
+
+#[test]
+fn should_set_resume_option_of_procedures_in_branches() {
+    let mut config = Configuration::from_yaml_string(
+        "
+version: 0.1
+procedures:
+  - proc-type: branch
+    config:
+      branches:
+        - name: outer
+          procedures:
+            - proc-type: count
+            - proc-type: branch
+              config:
+                branches:
+                  - name: inner
+                    procedures:
+                      - proc-type: chromatic-properties
+                        config:
+                          properties: [girth]
+                          checkpoint-file: checkpoint.jsonl
+",
+    )
+    .unwrap();
+    config.resume();
+    let outer = &config.procedures[0].config.as_ref().unwrap()["branches"][0]["procedures"];
+    assert_eq!(outer[0].get("config"), None);
+    let inner = &outer[1]["config"]["branches"][0]["procedures"][0];
+    assert_eq!(inner["config"]["resume"], serde_json::json!(true));
+    assert_eq!(
+        inner["config"]["checkpoint-file"],
+        serde_json::json!("checkpoint.jsonl")
+    );
+
+    // resumed sub-chains are still valid
+    let chain: Result<ProcedureChain<SimpleGraph>, _> =
+        ProcedureChain::from_procedures_config(ProcedureRegistry::new_basic(), config.procedures);
+    assert_eq!(chain.is_ok(), true);
+}
+
+#[test]
+fn should_key_checkpoint_by_graph_index() {
+    let file = std::env::temp_dir().join(format!(
+        "snark-tool-checkpoint-graph-index-{}.jsonl",
+        std::process::id()
+    ));
+    // graphs with original indices set by previous procedure (e.g. read with skip)
+    let graphs_with_index = |indices: Vec<usize>| {
+        let mut graphs = graphs_for_checkpoint();
+        for (graph, graph_index) in graphs.iter_mut().zip(indices) {
+            graph
+                .1
+                .insert("graph-index".to_string(), serde_json::json!(graph_index));
+        }
+        graphs
+    };
+    let chrom_props = ChromaticPropsProcedureBuilder::build(girth_config_with_checkpoint(
+        ParallelizationType::None,
+        &file,
+        false,
+    ));
+    let mut graphs = graphs_with_index(vec![10, 11, 12]);
+    chrom_props.run(&mut graphs).unwrap();
+    let checkpoint = std::fs::read_to_string(&file).unwrap();
+    let mut entries: Vec<serde_json::Value> = checkpoint
+        .lines()
+        .map(|line| serde_json::from_str(line).unwrap())
+        .collect();
+    let indices: Vec<serde_json::Value> = entries
+        .iter()
+        .map(|entry| entry["graph-index"].clone())
+        .collect();
+    assert_eq!(indices, vec![10, 11, 12]);
+
+    // graph 11 is restored by its graph index although it is at different position
+    for entry in entries.iter_mut() {
+        entry["properties"]["girth"] =
+            serde_json::json!(42 + entry["graph-index"].as_u64().unwrap());
+    }
+    entries.remove(2);
+    let content: String = entries.iter().map(|entry| format!("{}\n", entry)).collect();
+    std::fs::write(&file, content).unwrap();
+    let chrom_props = ChromaticPropsProcedureBuilder::build(girth_config_with_checkpoint(
+        ParallelizationType::None,
+        &file,
+        true,
+    ));
+    let mut graphs = graphs_with_index(vec![11, 12, 10]);
+    chrom_props.run(&mut graphs).unwrap();
+    assert_eq!(graphs[0].1["girth"], serde_json::json!(53));
+    // graph 12 is not in checkpoint, its girth is computed again
+    assert_eq!(graphs[1].1["girth"], serde_json::json!(3));
+    assert_eq!(graphs[2].1["girth"], serde_json::json!(52));
+    assert_eq!(graphs[1].1["graph-index"], serde_json::json!(12));
+    std::fs::remove_file(file).unwrap();
+}
+
+#[test]
+fn should_truncate_incomplete_checkpoint_line_on_resume() {
+    let file = std::env::temp_dir().join(format!(
+        "snark-tool-checkpoint-incomplete-{}.jsonl",
+        std::process::id()
+    ));
+    let chrom_props = ChromaticPropsProcedureBuilder::build(girth_config_with_checkpoint(
+        ParallelizationType::None,
+        &file,
+        false,
+    ));
+    let mut graphs = graphs_for_checkpoint();
+    chrom_props.run(&mut graphs).unwrap();
+    let checkpoint = std::fs::read_to_string(&file).unwrap();
+
+    // run crashed while writing last entry
+    let lines: Vec<&str> = checkpoint.lines().collect();
+    let content = format!("{}\n{}\n{}", lines[0], lines[1], &lines[2][..10]);
+    std::fs::write(&file, content).unwrap();
+    let chrom_props = ChromaticPropsProcedureBuilder::build(girth_config_with_checkpoint(
+        ParallelizationType::None,
+        &file,
+        true,
+    ));
+    let mut graphs = graphs_for_checkpoint();
+    chrom_props.run(&mut graphs).unwrap();
+
+    // appended entry starts on new line and incomplete entry is gone
+    let checkpoint = std::fs::read_to_string(&file).unwrap();
+    assert_eq!(checkpoint.ends_with('\n'), true);
+    let indices: Vec<serde_json::Value> = checkpoint
+        .lines()
+        .map(|line| serde_json::from_str::<serde_json::Value>(line).unwrap()["graph-index"].clone())
+        .collect();
+    assert_eq!(indices, vec![0, 1, 2]);
+    std::fs::remove_file(file).unwrap();
+}
+
+fn read_with_config(
+    config: serde_json::Value,
+) -> Result<Vec<(SimpleGraph, GraphProperties)>, Error> {
+    let mut config: HashMap<String, serde_json::Value> = serde_json::from_value(config).unwrap();
+    config.insert(
+        "file".to_string(),
+        serde_json::json!(test_data::GG_30_G05_CYC4_G6_100_FILE_PATH),
+    );
+    let read: Box<dyn Procedure<SimpleGraph>> = ReadProcedureBuilder {}.build_from_map(config)?;
+    let mut graphs = vec![];
+    read.run(&mut graphs)?;
+    Ok(graphs)
+}
+
+fn graph_indices(graphs: &[(SimpleGraph, GraphProperties)]) -> Vec<u64> {
+    graphs
+        .iter()
+        .map(|graph| graph.1["graph-index"].as_u64().unwrap())
+        .collect()
+}
+
+#[test]
+fn should_add_graph_index_unless_turned_off() {
+    let graphs = read_with_config(serde_json::json!({"number-of-graphs": 3})).unwrap();
+    assert_eq!(graph_indices(&graphs), vec![0, 1, 2]);
+
+    let graphs = read_with_config(serde_json::json!({
+        "number-of-graphs": 3,
+        "skip": 5,
+        "with-graph-index": false
+    }))
+    .unwrap();
+    assert_eq!(graphs.len(), 3);
+    for graph in graphs.iter() {
+        assert_eq!(graph.1.contains_key("graph-index"), false);
+    }
+}
+
+#[test]
+fn should_read_selected_graphs_with_original_index() {
+    let graphs = read_with_config(serde_json::json!({})).unwrap();
+    assert_eq!(graph_indices(&graphs), (0..100).collect::<Vec<u64>>());
+
+    let graphs = read_with_config(serde_json::json!({"skip": 95})).unwrap();
+    assert_eq!(graph_indices(&graphs), vec![95, 96, 97, 98, 99]);
+    let graphs =
+        read_with_config(serde_json::json!({"offset": 10, "number-of-graphs": 3})).unwrap();
+    assert_eq!(graph_indices(&graphs), vec![10, 11, 12]);
+
+    let graphs = read_with_config(serde_json::json!({"ranges": [7, "20-22", "98-"]})).unwrap();
+    assert_eq!(graph_indices(&graphs), vec![7, 20, 21, 22, 98, 99]);
+    let graphs =
+        read_with_config(serde_json::json!({"ranges": ["0-30"], "shard": "1/10"})).unwrap();
+    assert_eq!(graph_indices(&graphs), vec![1, 11, 21]);
+}
+
+#[test]
+fn should_split_input_into_disjoint_shards() {
+    let all = read_with_config(serde_json::json!({})).unwrap();
+    let mut indices = vec![];
+    for shard in 0..3 {
+        let graphs =
+            read_with_config(serde_json::json!({ "shard": format!("{}/3", shard) })).unwrap();
+        for graph in graphs.iter() {
+            let index = graph.1["graph-index"].as_u64().unwrap() as usize;
+            assert_eq!(index % 3, shard);
+            assert_eq!(graph.0, all[index].0);
+            indices.push(index);
+        }
+    }
+    indices.sort();
+    assert_eq!(indices, (0..100).collect::<Vec<usize>>());
+}
+
+#[test]
+fn should_reject_malformed_graph_selection() {
+    for config in vec![
+        serde_json::json!({"shard": "3/3"}),
+        serde_json::json!({"shard": "1"}),
+        serde_json::json!({"shard": "a/3"}),
+        serde_json::json!({"ranges": ["5-2"]}),
+        serde_json::json!({"ranges": ["x-2"]}),
+        serde_json::json!({"ranges": [true]}),
+        serde_json::json!({"skip": 1, "offset": 2}),
+    ] {
+        assert_eq!(read_with_config(config).is_err(), true);
+    }
+}
+
+#[test]
+fn should_keep_original_graph_index_in_chromatic_properties() {
+    let mut config = HashMap::new();
+    config.insert(
+        "file".to_string(),
+        serde_json::json!(test_data::GG_30_G05_CYC4_G6_100_FILE_PATH),
+    );
+    config.insert("shard".to_string(), serde_json::json!("1/4"));
+    let mut to_compute = ChromaticPropertiesToCompute::new();
+    to_compute.girth = true;
+    let chrom_props_config = ChromaticPropsProcedureConfig::new(
+        ColouriserType::Dfs,
+        ParallelizationType::BatchBased,
+        to_compute,
+        2,
+    );
+    let procedures: Vec<Box<dyn Procedure<SimpleGraph>>> = vec![
+        ReadProcedureBuilder {}.build_from_map(config).unwrap(),
+        ChromaticPropsProcedureBuilder::build(chrom_props_config),
+    ];
+    let chain = ProcedureChain::from_procedures(procedures).unwrap();
+    let mut graphs = vec![];
+    chain.run(&mut graphs).unwrap();
+    assert_eq!(graphs.len(), 25);
+    for (position, graph) in graphs.iter().enumerate() {
+        assert_eq!(graph.1["graph-index"], serde_json::json!(position * 4 + 1));
+        assert_eq!(graph.1.contains_key("girth"), true);
+    }
+}
+
+fn construction_config(
+    construction_type: &str,
+    max_extensions: Option<usize>,
+    reject_isomorphic: bool,
+) -> ConstructionProcedureConfig {
+    let mut config = HashMap::new();
+    config.insert(
+        "construction-type".to_string(),
+        serde_json::json!(construction_type),
+    );
+    if let Some(max_extensions) = max_extensions {
+        config.insert(
+            "max-extensions".to_string(),
+            serde_json::json!(max_extensions),
+        );
+    }
+    config.insert(
+        "reject-isomorphic".to_string(),
+        serde_json::json!(reject_isomorphic),
+    );
+    ConstructionProcedureConfig::from_proc_config(&config).unwrap()
+}
+
+#[test]
+fn should_enumerate_non_isomorphic_constructions_with_provenance() {
+    let construction =
+        ConstructionProcedureBuilder::build(construction_config("dot-product", None, true));
+    let mut graphs = vec![(test_data::get_petersen_graph(), GraphProperties::new())];
+    construction.run(&mut graphs).unwrap();
+
+    // dot products of Petersen graph with itself are two Blanusa snarks
+    assert_eq!(graphs.len(), 3);
+    assert_eq!(graphs[0].1["extensions-count"], serde_json::json!(2));
+    let first = canonical::canonical_g6_string(&graphs[1].0);
+    let second = canonical::canonical_g6_string(&graphs[2].0);
+    assert_ne!(first, second);
+    for (graph, properties) in graphs.iter().skip(1) {
+        assert_eq!(graph.size(), 18);
+        assert_eq!(properties["construction"], serde_json::json!("dot-product"));
+        assert_eq!(properties["parent-graph-index"], serde_json::json!(0));
+        assert_eq!(
+            properties["construction-edges"].as_array().unwrap().len(),
+            2
+        );
+        assert_eq!(
+            properties["construction-vertices"]
+                .as_array()
+                .unwrap()
+                .len(),
+            2
+        );
+    }
+}
+
+#[test]
+fn should_limit_number_of_constructions() {
+    let mut graphs = vec![(test_data::get_petersen_graph(), GraphProperties::new())];
+    let construction =
+        ConstructionProcedureBuilder::build(construction_config("dot-product", None, false));
+    construction.run(&mut graphs).unwrap();
+    let all_extensions = graphs.len() - 1;
+    assert!(all_extensions > 2);
+
+    let mut graphs = vec![(test_data::get_petersen_graph(), GraphProperties::new())];
+    let construction =
+        ConstructionProcedureBuilder::build(construction_config("dot-product", Some(1), false));
+    construction.run(&mut graphs).unwrap();
+    assert_eq!(graphs.len(), 2);
+}
+
+#[test]
+fn should_reject_isomorphic_constructions_unless_turned_off() {
+    let mut config = HashMap::new();
+    config.insert(
+        "construction-type".to_string(),
+        serde_json::json!("dot-product"),
+    );
+    let construction_config = ConstructionProcedureConfig::from_proc_config(&config).unwrap();
+    assert_eq!(construction_config.reject_isomorphic(), true);
+    let construction = ConstructionProcedureBuilder::build(construction_config);
+    let mut graphs = vec![(test_data::get_petersen_graph(), GraphProperties::new())];
+    construction.run(&mut graphs).unwrap();
+    // input graph and two Blanusa snarks
+    assert_eq!(graphs.len(), 3);
+
+    config.insert("reject-isomorphic".to_string(), serde_json::json!(false));
+    let construction_config = ConstructionProcedureConfig::from_proc_config(&config).unwrap();
+    assert_eq!(construction_config.reject_isomorphic(), false);
+    let construction = ConstructionProcedureBuilder::build(construction_config);
+    let mut graphs = vec![(test_data::get_petersen_graph(), GraphProperties::new())];
+    construction.run(&mut graphs).unwrap();
+    assert!(graphs.len() > 3);
+}
+
+#[test]
+fn should_skip_graphs_without_construction() {
+    let construction =
+        ConstructionProcedureBuilder::build(construction_config("i-extension", None, true));
+    let graph = G6Reader::<SimpleGraph>::read_graph(test_data::NO_SNARK_IN_G6_18).unwrap();
+    let mut graphs = vec![(graph, GraphProperties::new())];
+    construction.run(&mut graphs).unwrap();
+
+    assert_eq!(graphs.len(), 1);
+    assert_eq!(graphs[0].1["extensions-count"], serde_json::json!(0));
+}
+
+#[test]
+fn should_construct_superposition_of_graphs() {
+    let config = Configuration::from_yaml_string(&format!(
+        "version: 0.1
+procedures:
+  - proc-type: construction
+    config:
+      construction-type: superposition
+      supervertices:
+        - vertex: 0
+          graph: \"{0}\"
+          removed-vertex: 0
+      superedges:
+        - edge: [0, 4]
+          graph: \"{0}\"
+          removed-edges: [[0, 4]]
+          connectors: [[0], [1]]
+  - proc-type: colour
+",
+        test_data::SNARK_IN_G6_10_PETERSEN
+    ))
+    .unwrap();
+    let chain =
+        ProcedureChain::from_procedures_config(ProcedureRegistry::new_basic(), config.procedures)
+            .unwrap();
+    let mut graphs = vec![(test_data::get_petersen_graph(), GraphProperties::new())];
+    chain.run(&mut graphs).unwrap();
+
+    assert_eq!(graphs.len(), 2);
+    assert_eq!(graphs[0].1["extensions-count"], serde_json::json!(1));
+    let (graph, properties) = &graphs[1];
+    assert_eq!(graph.size(), 10 - 1 + 9 + 10);
+    assert_eq!(
+        properties["construction"],
+        serde_json::json!("superposition")
+    );
+    assert_eq!(properties["construction-vertices"], serde_json::json!([0]));
+    assert_eq!(
+        properties["construction-edges"],
+        serde_json::json!([[0, 4]])
+    );
+    assert_eq!(properties["colourable"], serde_json::json!(false));
+}
+
+#[test]
+fn should_reject_malformed_superposition_config() {
+    let petersen = serde_json::json!(test_data::SNARK_IN_G6_10_PETERSEN);
+    for config in vec![
+        serde_json::json!({"construction-type": "superposition"}),
+        serde_json::json!({
+            "construction-type": "superposition",
+            "supervertices": [{"vertex": 0, "graph": petersen}]
+        }),
+        serde_json::json!({
+            "construction-type": "superposition",
+            "superedges": [{"edge": [0, 4], "graph": petersen, "removed-edges": [[0, 4], [3, 5]]}]
+        }),
+    ] {
+        let config: HashMap<String, serde_json::Value> = serde_json::from_value(config).unwrap();
+        assert_eq!(
+            ConstructionProcedureConfig::from_proc_config(&config).is_err(),
+            true
+        );
+    }
+}
+
+#[test]
+fn should_generate_graphs_of_family() {
+    let config = Configuration::from_yaml_string(
+        "version: 0.1\nprocedures:\n  - proc-type: generate\n    config:\n      family: flower\n      n: [5, 7]\n  - proc-type: colour\n",
+    )
+    .unwrap();
+    let chain =
+        ProcedureChain::from_procedures_config(ProcedureRegistry::new_basic(), config.procedures)
+            .unwrap();
+    let mut graphs: Vec<(SimpleGraph, GraphProperties)> = vec![];
+    chain.run(&mut graphs).unwrap();
+
+    assert_eq!(graphs.len(), 2);
+    for (index, (graph, properties)) in graphs.iter().enumerate() {
+        assert_eq!(graph.size(), 20 + 8 * index);
+        assert_eq!(properties["family"], serde_json::json!("flower"));
+        assert_eq!(properties["n"], serde_json::json!(5 + 2 * index));
+        assert_eq!(properties["graph-index"], serde_json::json!(index));
+        assert_eq!(properties["colourable"], serde_json::json!(false));
+    }
+}
+
+#[test]
+fn should_generate_graphs_in_streaming_mode() {
+    let mut config = HashMap::new();
+    config.insert(
+        "family".to_string(),
+        serde_json::json!("generalized-petersen"),
+    );
+    config.insert("n".to_string(), serde_json::json!(8));
+    config.insert("k".to_string(), serde_json::json!(3));
+    let generate_config = GenerateProcedureConfig::from_proc_config(&config).unwrap();
+    assert_eq!(generate_config.n(), &vec![8]);
+
+    let procedures: Vec<Box<dyn Procedure<SimpleGraph>>> = vec![
+        GenerateProcedureBuilder::build(generate_config),
+        ColourProcedureBuilder::build(ColourProcedureConfig::default()),
+    ];
+    let mut chain = ProcedureChain::from_procedures(procedures).unwrap();
+    chain.run_streaming(1).unwrap();
+
+    config.remove("k");
+    assert_eq!(
+        GenerateProcedureConfig::from_proc_config(&config).is_err(),
+        true
+    );
+    config.insert("family".to_string(), serde_json::json!("unknown"));
+    assert_eq!(
+        GenerateProcedureConfig::from_proc_config(&config).is_err(),
+        true
+    );
+}
+
+#[test]
+fn should_generate_cubic_graphs() {
+    let config = Configuration::from_yaml_string(
+        "version: 0.1\nprocedures:\n  - proc-type: generate\n    config:\n      family: cubic\n      n: [8, 10]\n      girth: 4\n  - proc-type: colour\n",
+    )
+    .unwrap();
+    let chain =
+        ProcedureChain::from_procedures_config(ProcedureRegistry::new_basic(), config.procedures)
+            .unwrap();
+    let mut graphs: Vec<(SimpleGraph, GraphProperties)> = vec![];
+    chain.run(&mut graphs).unwrap();
+
+    // 2 cubic graphs of order 8 and 6 of order 10 have girth at least 4
+    assert_eq!(graphs.len(), 8);
+    for (index, (graph, properties)) in graphs.iter().enumerate() {
+        let n = if index < 2 { 8 } else { 10 };
+        assert_eq!(graph.size(), n);
+        assert_eq!(properties["family"], serde_json::json!("cubic"));
+        assert_eq!(properties["n"], serde_json::json!(n));
+        assert_eq!(properties["graph-index"], serde_json::json!(index));
+    }
+    // Petersen graph is the only one which is not colourable
+    let uncolourable = graphs
+        .iter()
+        .filter(|(_, properties)| properties["colourable"] == serde_json::json!(false))
+        .count();
+    assert_eq!(uncolourable, 1);
+}
+
+#[test]
+fn should_generate_cubic_graphs_in_shards() {
+    let mut config = HashMap::new();
+    config.insert("family".to_string(), serde_json::json!("cubic"));
+    config.insert("n".to_string(), serde_json::json!(10));
+    config.insert("girth".to_string(), serde_json::json!(5));
+    config.insert("cyclic-connectivity".to_string(), serde_json::json!(4));
+    config.insert("colourable".to_string(), serde_json::json!(false));
+
+    let mut snarks = 0;
+    for shard in 0..2 {
+        config.insert(
+            "shard".to_string(),
+            serde_json::json!(format!("{}/2", shard)),
+        );
+        let generate_config = GenerateProcedureConfig::from_proc_config(&config).unwrap();
+        let procedure: Box<dyn Procedure<SimpleGraph>> =
+            GenerateProcedureBuilder::build(generate_config);
+        let mut graphs = vec![];
+        procedure.run(&mut graphs).unwrap();
+        snarks += graphs.len();
+    }
+    // Petersen graph is the only snark of order 10
+    assert_eq!(snarks, 1);
+
+    config.insert("shard".to_string(), serde_json::json!("2/2"));
+    assert_eq!(
+        GenerateProcedureConfig::from_proc_config(&config).is_err(),
+        true
+    );
+    config.insert("shard".to_string(), serde_json::json!("0/2"));
+    config.insert("n".to_string(), serde_json::json!(9));
+    let generate_config = GenerateProcedureConfig::from_proc_config(&config).unwrap();
+    let procedure: Box<dyn Procedure<SimpleGraph>> =
+        GenerateProcedureBuilder::build(generate_config);
+    assert_eq!(procedure.run(&mut vec![]).is_err(), true);
+}
@@ -1,11 +1,11 @@
-use crate::graph::edge::{Edge, EdgeConstructor};
 use crate::graph::graph::Graph;
-use crate::graph::undirected::edge::UndirectedEdge;
 use crate::graph::undirected::simple_graph::graph::SimpleGraph;
 use crate::service::chromatic_properties::edge_subcriticality_solver::EdgeSubcriticalitySolver;
 use crate::service::chromatic_properties::CriticalProperties;
 use crate::service::colour::colouriser::Colouriser;
+use crate::service::colour::incremental_colouriser::IncrementalColouriser;
 use crate::service::colour::recursive::dfs_improved::DFSColourizer;
+use crate::service::symmetry::automorphisms::AutomorphismGroup;
 
 #[derive(Debug, Clone)]
 pub struct CriticalPropertiesStruct {
@@ -37,6 +37,14 @@ impl CriticalPropertiesStruct {
             edge_property_computed: false,
         }
     }
+
+    ///
+    /// one vertex of each vertex orbit - properties are invariant under automorphisms, so it is
+    /// enough to remove these as first vertex (orbits are computed only when vertex properties are)
+    ///
+    pub fn first_vertices(&self) -> Vec<usize> {
+        AutomorphismGroup::of_graph(&self.untouched_graph).vertex_orbit_representatives()
+    }
 }
 
 pub struct CriticalPropertiesSolver<C>
@@ -119,14 +127,15 @@ where
         self.properties.is_critical = true;
         self.properties.is_cocritical = true;
 
-        let graph = &mut self.properties.graph;
+        let graph_size = self.properties.untouched_graph.size();
+        let mut colouriser = C::Incremental::of_graph(&self.properties.untouched_graph);
 
-        for first_vertex in 0..graph.size() {
+        for first_vertex in self.properties.first_vertices() {
             self.properties.is_vertex_subcritical = false;
 
-            graph.remove_edges_of_vertex(first_vertex);
+            colouriser.remove_edges_of_vertex(first_vertex);
 
-            for second_vertex in 0..graph.size() {
+            for second_vertex in 0..graph_size {
                 if first_vertex == second_vertex {
                     continue;
                 }
@@ -152,26 +161,21 @@ where
                 }
 
                 let colourable_opt =
-                    self.properties.colourings[first_vertex * graph.size() + second_vertex];
+                    self.properties.colourings[first_vertex * graph_size + second_vertex];
                 let colourable;
                 if colourable_opt.is_some() {
                     colourable = colourable_opt.unwrap();
                 } else {
-                    graph.remove_edges_of_vertex(second_vertex);
+                    colouriser.remove_edges_of_vertex(second_vertex);
 
-                    colourable = C::is_colorable(graph);
+                    colourable = colouriser.is_colorable();
 
-                    self.properties.colourings[first_vertex * graph.size() + second_vertex] =
+                    self.properties.colourings[first_vertex * graph_size + second_vertex] =
                         Some(colourable);
-                    self.properties.colourings[second_vertex * graph.size() + first_vertex] =
+                    self.properties.colourings[second_vertex * graph_size + first_vertex] =
                         Some(colourable);
 
-                    restore_edges_of_vertex_except_for(
-                        &self.properties.untouched_graph,
-                        graph,
-                        second_vertex,
-                        first_vertex,
-                    );
+                    colouriser.restore_edges_of_vertex(second_vertex);
                 }
 
                 // check properties
@@ -190,7 +194,7 @@ where
                 }
             }
 
-            restore_edges_of_vertex(&self.properties.untouched_graph, graph, first_vertex);
+            colouriser.restore_edges_of_vertex(first_vertex);
 
             if !self.properties.is_vertex_subcritical {
                 return;
@@ -208,30 +212,3 @@ impl CriticalPropertiesSolver<DFSColourizer> {
         )
     }
 }
-
-pub fn restore_edges_of_vertex(
-    original_graph: &SimpleGraph,
-    changed_graph: &mut SimpleGraph,
-    vertex: usize,
-) {
-    for neighboring_edge in original_graph.vertices[vertex].edges.iter() {
-        changed_graph.add_edge(neighboring_edge.from(), neighboring_edge.to());
-    }
-}
-
-pub fn restore_edges_of_vertex_except_for(
-    original_graph: &SimpleGraph,
-    changed_graph: &mut SimpleGraph,
-    vertex: usize,
-    except_for: usize,
-) {
-    let except_for_edge = UndirectedEdge::new(vertex, except_for);
-    for neighboring_edge in original_graph.vertices[vertex].edges.iter() {
-        if neighboring_edge.from() == except_for_edge.from()
-            && neighboring_edge.to() == except_for_edge.to()
-        {
-            continue;
-        }
-        changed_graph.add_edge(neighboring_edge.from(), neighboring_edge.to());
-    }
-}
@@ -2,8 +2,9 @@ use crate::graph::graph::Graph;
 use crate::service::chromatic_properties::critical_prop::CriticalPropertiesStruct;
 use crate::service::chromatic_properties::edge_subcriticality_solver::EdgeSubcriticalityParallelSolver;
 use crate::service::chromatic_properties::error::ChromaticPropertiesError;
-use crate::service::chromatic_properties::{critical_prop, CriticalProperties};
+use crate::service::chromatic_properties::CriticalProperties;
 use crate::service::colour::colouriser::Colouriser;
+use crate::service::colour::incremental_colouriser::IncrementalColouriser;
 use crate::service::colour::recursive::dfs_improved::DFSColourizer;
 use std::borrow::Borrow;
 use std::collections::HashMap;
@@ -114,14 +115,12 @@ where
         let graph_size = self.properties.graph.size();
         let mut results_gained = false;
 
-        for first_vertex in 0..graph_size {
-            let mut self_copy = self.clone();
-            let graph = &mut self_copy.properties.graph;
-            graph.remove_edges_of_vertex(first_vertex);
-
+        let first_vertices = self.properties.first_vertices();
+        for first_vertex in first_vertices.iter() {
+            let self_copy = self.clone();
             let tx_cloned = mpsc::Sender::clone(&tx);
-            let handle = Self::spawn_thread_for_subgraph(self_copy, first_vertex, tx_cloned);
-            threads.insert(index, handle);
+            let handle = Self::spawn_thread_for_subgraph(self_copy, *first_vertex, tx_cloned);
+            threads.insert(*first_vertex, handle);
             index += 1;
 
             if index >= self.threads_count {
@@ -144,14 +143,12 @@ where
             if results_gained {
                 break;
             }
-            if index < graph_size {
-                let mut self_copy = self.clone();
-                let graph_copy = &mut self_copy.properties.graph;
-                graph_copy.remove_edges_of_vertex(index);
-
+            if index < first_vertices.len() {
+                let first_vertex = first_vertices[index];
+                let self_copy = self.clone();
                 let tx_cloned = mpsc::Sender::clone(&tx);
-                let handle = Self::spawn_thread_for_subgraph(self_copy, index, tx_cloned);
-                threads.insert(index, handle);
+                let handle = Self::spawn_thread_for_subgraph(self_copy, first_vertex, tx_cloned);
+                threads.insert(first_vertex, handle);
                 index += 1;
             } else {
                 break;
@@ -232,10 +229,12 @@ where
         mut props: CriticalPropertiesParallelSolver<C>,
         first_vertex: usize,
     ) -> Result<ThreadResult<C>> {
-        let graph = &mut props.properties.graph;
+        let graph_size = props.properties.untouched_graph.size();
+        let mut colouriser = C::Incremental::of_graph(&props.properties.untouched_graph);
+        colouriser.remove_edges_of_vertex(first_vertex);
 
         // do in thread
-        for second_vertex in 0..graph.size() {
+        for second_vertex in 0..graph_size {
             if first_vertex == second_vertex {
                 continue;
             }
@@ -261,26 +260,21 @@ where
             }
 
             let colourable_opt =
-                props.properties.colourings[first_vertex * graph.size() + second_vertex];
+                props.properties.colourings[first_vertex * graph_size + second_vertex];
             let colourable;
             if colourable_opt.is_some() {
                 colourable = colourable_opt.unwrap();
             } else {
-                graph.remove_edges_of_vertex(second_vertex);
+                colouriser.remove_edges_of_vertex(second_vertex);
 
-                colourable = C::is_colorable(graph);
+                colourable = colouriser.is_colorable();
 
-                props.properties.colourings[first_vertex * graph.size() + second_vertex] =
+                props.properties.colourings[first_vertex * graph_size + second_vertex] =
                     Some(colourable);
-                props.properties.colourings[second_vertex * graph.size() + first_vertex] =
+                props.properties.colourings[second_vertex * graph_size + first_vertex] =
                     Some(colourable);
 
-                critical_prop::restore_edges_of_vertex_except_for(
-                    &props.properties.untouched_graph,
-                    graph,
-                    second_vertex,
-                    first_vertex,
-                );
+                colouriser.restore_edges_of_vertex(second_vertex);
             }
 
             // check properties
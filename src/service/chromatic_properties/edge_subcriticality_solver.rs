@@ -4,6 +4,7 @@ use crate::graph::undirected::edge::UndirectedEdge;
 use crate::graph::undirected::simple_graph::graph::SimpleGraph;
 use crate::service::chromatic_properties::error::ChromaticPropertiesError;
 use crate::service::colour::colouriser::Colouriser;
+use crate::service::colour::incremental_colouriser::IncrementalColouriser;
 use std::borrow::Borrow;
 use std::collections::HashMap;
 use std::option::Option::Some;
@@ -16,12 +17,12 @@ pub struct EdgeSubcriticalitySolver {}
 
 impl EdgeSubcriticalitySolver {
     pub fn compute_edge_subcriticality<C: Colouriser>(graph: &SimpleGraph) -> bool {
-        let mut graph_copy = SimpleGraph::from_graph(graph);
+        let mut colouriser = C::Incremental::of_graph(graph);
         let mut edge_subcritical = true;
 
         let mut computed_pairs = HashMap::new();
         for first_edge in graph.edges() {
-            graph_copy.remove_edge(first_edge.from(), first_edge.to());
+            colouriser.remove_edge(first_edge.from(), first_edge.to());
 
             for second_edge in graph.edges() {
                 if first_edge.eq(second_edge) {
@@ -31,9 +32,9 @@ impl EdgeSubcriticalitySolver {
                 if computed_pairs.contains_key(&(first_edge, second_edge)) {
                     colourable = *computed_pairs.get(&(first_edge, second_edge)).unwrap();
                 } else {
-                    graph_copy.remove_edge(second_edge.from(), second_edge.to());
-                    colourable = C::is_colorable(&graph_copy);
-                    graph_copy.add_edge(second_edge.from(), second_edge.to());
+                    colouriser.remove_edge(second_edge.from(), second_edge.to());
+                    colourable = colouriser.is_colorable();
+                    colouriser.restore_edge(second_edge.from(), second_edge.to());
 
                     computed_pairs.insert((first_edge, second_edge), colourable);
                     computed_pairs.insert((second_edge, first_edge), colourable);
@@ -44,7 +45,7 @@ impl EdgeSubcriticalitySolver {
                 }
                 edge_subcritical = false;
             }
-            graph_copy.add_edge(first_edge.from(), first_edge.to());
+            colouriser.restore_edge(first_edge.from(), first_edge.to());
             if !edge_subcritical {
                 return false;
             }
@@ -93,10 +94,7 @@ where
 
         let mut edge_iter = graph.edges();
         while let Some(first_edge) = edge_iter.next() {
-            let mut solver_copy = solver.clone();
-            let graph_ref = &mut solver_copy.graph;
-            graph_ref.remove_edge(first_edge.from(), first_edge.to());
-
+            let solver_copy = solver.clone();
             let tx_cloned = mpsc::Sender::clone(&tx);
             let handle =
                 Self::spawn_thread_for_subgraph(first_edge.clone(), tx_cloned, solver_copy, index);
@@ -126,10 +124,7 @@ where
 
             let next_edge = edge_iter.next();
             if let Some(first_edge) = next_edge {
-                let mut solver_copy = solver.clone();
-                let graph_ref = &mut solver_copy.graph;
-                graph_ref.remove_edge(first_edge.from(), first_edge.to());
-
+                let solver_copy = solver.clone();
                 let tx_cloned = mpsc::Sender::clone(&tx);
                 let handle = Self::spawn_thread_for_subgraph(
                     first_edge.clone(),
@@ -191,7 +186,8 @@ where
         index: usize,
     ) -> Result<ThreadResult<C>> {
         let mut edge_subcritical = false;
-        let graph = &mut solver.graph;
+        let mut colouriser = C::Incremental::of_graph(&solver.untouched_graph);
+        colouriser.remove_edge(first_edge.from(), first_edge.to());
         let computed_pairs = &mut solver.computed_pairs;
 
         for second_edge in solver.untouched_graph.edges() {
@@ -204,9 +200,9 @@ where
                     .get(&(first_edge.clone(), second_edge.clone()))
                     .unwrap();
             } else {
-                graph.remove_edge(second_edge.from(), second_edge.to());
-                colourable = C::is_colorable(graph);
-                graph.add_edge(second_edge.from(), second_edge.to());
+                colouriser.remove_edge(second_edge.from(), second_edge.to());
+                colourable = colouriser.is_colorable();
+                colouriser.restore_edge(second_edge.from(), second_edge.to());
 
                 computed_pairs.insert((first_edge.clone(), second_edge.clone()), colourable);
                 computed_pairs.insert((second_edge.clone(), first_edge.clone()), colourable);
@@ -3,6 +3,7 @@ pub mod critical_prop_parallel;
 pub mod edge_subcriticality_solver;
 pub mod error;
 pub mod resistance;
+pub mod resistance_sat;
 pub mod resistibility;
 pub mod stable_and_critical_prop;
 pub mod stable_and_critical_prop_parallel;
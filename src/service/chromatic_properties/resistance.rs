@@ -2,6 +2,7 @@ use crate::graph::edge::Edge;
 use crate::graph::graph::Graph;
 use crate::graph::vertex::Vertex;
 use crate::service::colour::colouriser::Colouriser;
+use crate::service::colour::incremental_colouriser::IncrementalColouriser;
 use std::marker;
 
 pub struct Resistance<G, C>
@@ -26,67 +27,74 @@ where
     }
 
     pub fn edge_resistance(&self, graph: &G) -> Option<usize> {
-        for i in 0..graph.size() {
-            let e_res = self.edge_resistance_recursive(graph, i);
-            if e_res.is_some() {
-                return e_res;
-            }
-        }
-        None
+        let mut colouriser = C::Incremental::of_graph(graph);
+        let edges: Vec<(usize, usize)> =
+            graph.edges().map(|edge| (edge.from(), edge.to())).collect();
+        (0..graph.size()).find(|i| Self::edge_resistance_recursive(&mut colouriser, &edges, 0, *i))
     }
 
-    // optimization opportunity - do not copy graph - use mutable pointer
-    fn edge_resistance_recursive(&self, graph: &G, max_nesting: usize) -> Option<usize> {
+    ///
+    /// returns true if removal of some max_nesting edges from edges[start..] makes graph colourable
+    ///
+    fn edge_resistance_recursive(
+        colouriser: &mut C::Incremental,
+        edges: &[(usize, usize)],
+        start: usize,
+        max_nesting: usize,
+    ) -> bool {
         if max_nesting == 0 {
-            let colourable = C::is_colorable(graph);
-            if colourable {
-                return Some(0);
-            }
-            return None;
+            return colouriser.is_colorable();
         }
 
-        let mut local_graph = graph.clone();
-        for edge in graph.edges() {
-            local_graph.remove_edge(edge.from(), edge.to());
-            let e_res = self.edge_resistance_recursive(&local_graph, max_nesting - 1);
-
-            if e_res.is_some() {
-                return Some(e_res.unwrap() + 1);
+        for position in start..edges.len() {
+            let edge = edges[position];
+            colouriser.remove_edge(edge.0, edge.1);
+            let colourable =
+                Self::edge_resistance_recursive(colouriser, edges, position + 1, max_nesting - 1);
+            colouriser.restore_edge(edge.0, edge.1);
+            if colourable {
+                return true;
             }
-            local_graph.add_edge(edge.from(), edge.to());
         }
-        None
+        false
     }
 
     pub fn vertex_resistance(&self, graph: &G) -> Option<usize> {
-        for i in 0..graph.size() {
-            let v_res = self.vertex_resistance_recursive(graph, i);
-            if v_res.is_some() {
-                return v_res;
-            }
-        }
-        None
+        let mut colouriser = C::Incremental::of_graph(graph);
+        let vertices: Vec<usize> = graph.vertices().map(|vertex| vertex.index()).collect();
+        (0..graph.size())
+            .find(|i| Self::vertex_resistance_recursive(&mut colouriser, &vertices, 0, *i))
     }
 
-    fn vertex_resistance_recursive(&self, graph: &G, max_nesting: usize) -> Option<usize> {
+    ///
+    /// returns true if removal of some max_nesting vertices from vertices[start..] makes graph
+    /// colourable
+    ///
+    fn vertex_resistance_recursive(
+        colouriser: &mut C::Incremental,
+        vertices: &[usize],
+        start: usize,
+        max_nesting: usize,
+    ) -> bool {
         if max_nesting == 0 {
-            let colourable = C::is_colorable(graph);
-            if colourable {
-                return Some(0);
-            }
-            return None;
+            return colouriser.is_colorable();
         }
 
-        for vertex in graph.vertices() {
-            let mut local_graph = graph.clone();
-            local_graph.remove_edges_of_vertex(vertex.index());
-            let v_res = self.vertex_resistance_recursive(&local_graph, max_nesting - 1);
-            if v_res.is_some() {
-                return Some(v_res.unwrap() + 1);
+        for position in start..vertices.len() {
+            let vertex = vertices[position];
+            colouriser.remove_edges_of_vertex(vertex);
+            let colourable = Self::vertex_resistance_recursive(
+                colouriser,
+                vertices,
+                position + 1,
+                max_nesting - 1,
+            );
+            colouriser.restore_edges_of_vertex(vertex);
+            if colourable {
+                return true;
             }
-            // eventually add removed edges of vertex
         }
-        None
+        false
     }
 }
 
@@ -0,0 +1,192 @@
+use crate::graph::edge::Edge;
+use crate::graph::graph::Graph;
+use crate::graph::vertex::Vertex;
+use std::collections::{HashMap, HashSet};
+use std::iter::FromIterator;
+use varisat::{ExtendFormula, Lit, Solver};
+
+///
+/// Resistance computed by SAT solver - edges of graph are 3-edge-coloured except removed edges
+/// (edges of removed vertices) and number of removed edges (vertices) is bounded by sequential
+/// counter. Bound is raised until formula becomes satisfiable, so removed elements of first model
+/// form minimal witness.
+///
+pub struct SATResistance {}
+
+#[allow(dead_code)]
+impl SATResistance {
+    pub fn edge_resistance<G: Graph>(graph: &G) -> usize {
+        Self::edge_resistance_witness(graph).len()
+    }
+
+    pub fn vertex_resistance<G: Graph>(graph: &G) -> usize {
+        Self::vertex_resistance_witness(graph).len()
+    }
+
+    ///
+    /// minimal set of edges whose removal makes graph 3-edge-colourable
+    ///
+    pub fn edge_resistance_witness<G: Graph>(graph: &G) -> Vec<(usize, usize)> {
+        let edges: Vec<(usize, usize)> =
+            graph.edges().map(|edge| (edge.from(), edge.to())).collect();
+        let removing: Vec<Vec<usize>> = (0..edges.len()).map(|edge| vec![edge]).collect();
+        Self::minimal_removal(&edges, edges.len(), &removing)
+            .iter()
+            .map(|edge| edges[*edge])
+            .collect()
+    }
+
+    ///
+    /// minimal set of vertices whose removal makes graph 3-edge-colourable
+    ///
+    pub fn vertex_resistance_witness<G: Graph>(graph: &G) -> Vec<usize> {
+        let vertices: Vec<usize> = graph.vertices().map(|vertex| vertex.index()).collect();
+        let positions: HashMap<usize, usize> = vertices
+            .iter()
+            .enumerate()
+            .map(|(position, vertex)| (*vertex, position))
+            .collect();
+        let edges: Vec<(usize, usize)> =
+            graph.edges().map(|edge| (edge.from(), edge.to())).collect();
+        let removing: Vec<Vec<usize>> = edges
+            .iter()
+            .map(|(from, to)| vec![positions[from], positions[to]])
+            .collect();
+        Self::minimal_removal(&edges, vertices.len(), &removing)
+            .iter()
+            .map(|position| vertices[*position])
+            .collect()
+    }
+
+    ///
+    /// removing[i] - candidates (0..candidates) whose removal removes edge i, returns minimal set
+    /// of candidates whose removal makes graph colourable
+    ///
+    fn minimal_removal(
+        edges: &[(usize, usize)],
+        candidates: usize,
+        removing: &[Vec<usize>],
+    ) -> Vec<usize> {
+        let mut solver = Solver::new();
+        let removed: Vec<Lit> = (0..candidates).map(|_| solver.new_lit()).collect();
+
+        let mut edges_of_vertex: HashMap<usize, Vec<usize>> = HashMap::new();
+        let mut colour_lits = vec![];
+        for (position, (from, to)) in edges.iter().enumerate() {
+            edges_of_vertex.entry(*from).or_default().push(position);
+            edges_of_vertex.entry(*to).or_default().push(position);
+
+            let lits: Vec<Lit> = (0..3).map(|_| solver.new_lit()).collect();
+            // edge which is not removed has at least one colour
+            let mut clause: Vec<Lit> = removing[position]
+                .iter()
+                .map(|candidate| removed[*candidate])
+                .collect();
+            clause.extend(lits.iter());
+            solver.add_clause(&clause);
+            for first in 0..3 {
+                for second in (first + 1)..3 {
+                    solver.add_clause(&[!lits[first], !lits[second]]);
+                }
+            }
+            colour_lits.push(lits);
+        }
+
+        // edges of vertex have different colours (removed edges have no colour)
+        for vertex_edges in edges_of_vertex.values() {
+            for first in 0..vertex_edges.len() {
+                for second in (first + 1)..vertex_edges.len() {
+                    for colour in 0..3 {
+                        solver.add_clause(&[
+                            !colour_lits[vertex_edges[first]][colour],
+                            !colour_lits[vertex_edges[second]][colour],
+                        ]);
+                    }
+                }
+            }
+        }
+
+        let at_least = Self::sequential_counter(&mut solver, &removed);
+        for bound in 0..=candidates {
+            // at_least[bound] - more than bound candidates are removed
+            if bound < candidates {
+                solver.assume(&[!at_least[bound]]);
+            } else {
+                solver.assume(&[]);
+            }
+            if solver.solve().unwrap() {
+                let model: HashSet<Lit> = HashSet::from_iter(solver.model().unwrap());
+                return (0..candidates)
+                    .filter(|candidate| model.contains(&removed[*candidate]))
+                    .collect();
+            }
+        }
+        vec![]
+    }
+
+    ///
+    /// returns lits where lits[j] is implied if at least j + 1 of inputs are true
+    ///
+    fn sequential_counter(solver: &mut Solver, inputs: &[Lit]) -> Vec<Lit> {
+        let mut previous: Vec<Lit> = vec![];
+        for input in inputs.iter() {
+            let current: Vec<Lit> = (0..(previous.len() + 1))
+                .map(|_| solver.new_lit())
+                .collect();
+            solver.add_clause(&[!*input, current[0]]);
+            for (count, previous_lit) in previous.iter().enumerate() {
+                solver.add_clause(&[!*previous_lit, current[count]]);
+                solver.add_clause(&[!*input, !*previous_lit, current[count + 1]]);
+            }
+            previous = current;
+        }
+        previous
+    }
+}
+
+///
+/// TESTS
+///
+#[cfg(test)]
+mod tests {
+    use crate::graph::graph::Graph;
+    use crate::graph::undirected::simple_graph::graph::SimpleGraph;
+    use crate::service::chromatic_properties::resistance_sat::SATResistance;
+    use crate::service::colour::colouriser::Colouriser;
+    use crate::service::colour::sat::sat::SATColourizer;
+    use crate::service::io::reader_g6::G6Reader;
+    use crate::tests::test_data::test_data;
+
+    #[test]
+    fn should_have_resistance_zero() {
+        let graph: SimpleGraph = G6Reader::read_graph(test_data::NO_SNARK_IN_G6_18).unwrap();
+        assert_eq!(SATResistance::edge_resistance(&graph), 0);
+        assert_eq!(SATResistance::vertex_resistance(&graph), 0);
+    }
+
+    #[test]
+    fn should_have_resistance_two() {
+        let mut graph: SimpleGraph =
+            G6Reader::read_graph(test_data::SNARK_IN_G6_26_CRITICAL_1).unwrap();
+        assert_eq!(SATResistance::vertex_resistance(&graph), 2);
+
+        let witness = SATResistance::edge_resistance_witness(&graph);
+        assert_eq!(witness.len(), 2);
+        for (from, to) in witness {
+            graph.remove_edge(from, to);
+        }
+        assert_eq!(SATColourizer::is_colorable(&graph), true);
+    }
+
+    #[test]
+    fn should_have_resistance_three() {
+        let mut graph: SimpleGraph =
+            G6Reader::read_graph(test_data::SNARK_IN_G6_36_STABLE_RES_3).unwrap();
+        let witness = SATResistance::vertex_resistance_witness(&graph);
+        assert_eq!(witness.len(), 3);
+        for vertex in witness {
+            graph.remove_edges_of_vertex(vertex);
+        }
+        assert_eq!(SATColourizer::is_colorable(&graph), true);
+    }
+}
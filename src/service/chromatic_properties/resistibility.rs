@@ -3,6 +3,7 @@ use crate::graph::graph::Graph;
 use crate::graph::vertex::Vertex;
 use crate::service::chromatic_properties::resistance::Resistance;
 use crate::service::colour::colouriser::Colouriser;
+use crate::service::symmetry::automorphisms::AutomorphismGroup;
 use std::collections::HashMap;
 
 pub struct Resistibility<G, C>
@@ -32,16 +33,15 @@ where
 
     pub fn edges_resistibility(&mut self) -> &HashMap<(usize, usize), usize> {
         let mut local_graph = self.graph.clone();
-        for edge in self.graph.edges() {
-            if self
-                .edges_resistibilities
-                .get(&(edge.from(), edge.to()))
-                .is_none()
-            {
-                let edge_resistibility =
-                    self.edge_resistibility(&mut local_graph, edge.from(), edge.to());
-                self.edges_resistibilities
-                    .insert((edge.from(), edge.to()), edge_resistibility);
+        // resistibility is same for all edges of edge orbit
+        let automorphisms = AutomorphismGroup::of_graph(&self.graph);
+        for orbit in automorphisms.edge_orbits() {
+            let edge = orbit[0];
+            if self.edges_resistibilities.get(&edge).is_none() {
+                let edge_resistibility = self.edge_resistibility(&mut local_graph, edge.0, edge.1);
+                for edge in orbit.iter() {
+                    self.edges_resistibilities.insert(*edge, edge_resistibility);
+                }
             }
         }
         &self.edges_resistibilities
@@ -77,12 +77,18 @@ where
     pub fn vertices_resistibility(&mut self) -> Vec<usize> {
         let mut local_graph = self.graph.clone();
         let mut v_resistibilities = vec![0; self.graph.size()];
-        for vertex in self.graph.vertices() {
-            if self.vertex_resistibilities[vertex.index()].is_none() {
-                let vertex_resistibility =
-                    self.vertex_resistibility(&mut local_graph, vertex.index());
-                self.vertex_resistibilities[vertex.index()] = Some(vertex_resistibility);
+        // resistibility is same for all vertices of vertex orbit
+        let automorphisms = AutomorphismGroup::of_graph(&self.graph);
+        for orbit in automorphisms.vertex_orbits() {
+            let vertex = orbit[0];
+            if self.vertex_resistibilities[vertex].is_none() {
+                let vertex_resistibility = self.vertex_resistibility(&mut local_graph, vertex);
+                for vertex in orbit.iter() {
+                    self.vertex_resistibilities[*vertex] = Some(vertex_resistibility);
+                }
             }
+        }
+        for vertex in self.graph.vertices() {
             v_resistibilities[vertex.index()] =
                 self.vertex_resistibilities[vertex.index()].unwrap();
         }
@@ -2,8 +2,9 @@ use crate::graph::graph::Graph;
 use crate::graph::vertex::Vertex;
 use crate::service::chromatic_properties::critical_prop::CriticalPropertiesStruct;
 use crate::service::chromatic_properties::edge_subcriticality_solver::EdgeSubcriticalitySolver;
-use crate::service::chromatic_properties::{critical_prop, CriticalProperties};
+use crate::service::chromatic_properties::CriticalProperties;
 use crate::service::colour::colouriser::Colouriser;
+use crate::service::colour::incremental_colouriser::IncrementalColouriser;
 
 pub struct StableAndCriticalPropertiesSolver<C>
 where
@@ -111,34 +112,30 @@ where
         self.is_stable = true;
         self.is_costable = true;
 
-        let graph = &mut self.properties.graph;
-        for first_vertex in 0..graph.size() {
-            graph.remove_edges_of_vertex(first_vertex);
+        let graph_size = self.properties.untouched_graph.size();
+        let mut colouriser = C::Incremental::of_graph(&self.properties.untouched_graph);
+        for first_vertex in self.properties.first_vertices() {
+            colouriser.remove_edges_of_vertex(first_vertex);
 
-            for second_vertex in 0..graph.size() {
+            for second_vertex in 0..graph_size {
                 if first_vertex == second_vertex {
                     continue;
                 }
 
                 let colourable_opt =
-                    self.properties.colourings[first_vertex * graph.size() + second_vertex];
+                    self.properties.colourings[first_vertex * graph_size + second_vertex];
                 let colourable;
                 if colourable_opt.is_none() {
-                    graph.remove_edges_of_vertex(second_vertex);
+                    colouriser.remove_edges_of_vertex(second_vertex);
 
-                    colourable = C::is_colorable(graph);
+                    colourable = colouriser.is_colorable();
 
-                    self.properties.colourings[first_vertex * graph.size() + second_vertex] =
+                    self.properties.colourings[first_vertex * graph_size + second_vertex] =
                         Some(colourable);
-                    self.properties.colourings[second_vertex * graph.size() + first_vertex] =
+                    self.properties.colourings[second_vertex * graph_size + first_vertex] =
                         Some(colourable);
 
-                    critical_prop::restore_edges_of_vertex_except_for(
-                        &self.properties.untouched_graph,
-                        graph,
-                        second_vertex,
-                        first_vertex,
-                    );
+                    colouriser.restore_edges_of_vertex(second_vertex);
                 }
             }
 
@@ -155,7 +152,7 @@ where
                     let colourings = &self.properties.colourings;
                     let colourable = Self::get_colouring(
                         colourings,
-                        graph.size(),
+                        graph_size,
                         first_vertex,
                         second_vertex.index(),
                     );
@@ -196,11 +193,7 @@ where
                 }
             }
 
-            critical_prop::restore_edges_of_vertex(
-                &self.properties.untouched_graph,
-                graph,
-                first_vertex,
-            );
+            colouriser.restore_edges_of_vertex(first_vertex);
 
             if self.results_obtained {
                 return;
@@ -0,0 +1,146 @@
+use crate::graph::edge::{Edge, EdgeConstructor};
+use crate::graph::graph::Graph;
+use crate::graph::undirected::edge::UndirectedEdge;
+use std::collections::HashMap;
+use std::slice::Iter;
+
+pub const COLOURS: [u8; 3] = [1, 2, 3];
+
+///
+/// 3-edge-colouring of graph - colour of each edge is stored in colour slot of edge
+/// (colours 1, 2 and 3), each of parallel edges has its own position
+///
+#[derive(Debug, Clone, PartialEq)]
+pub struct EdgeColouring {
+    edges: Vec<UndirectedEdge>,
+    // positions of all (parallel) edges between two vertices
+    positions: HashMap<(usize, usize), Vec<usize>>,
+}
+
+impl EdgeColouring {
+    pub fn new() -> Self {
+        EdgeColouring {
+            edges: vec![],
+            positions: HashMap::new(),
+        }
+    }
+
+    ///
+    /// sets colour of (first of parallel) edges between from and to, adds edge if there is none
+    ///
+    pub fn set_colour(&mut self, from: usize, to: usize, colour: u8) {
+        let edge = UndirectedEdge::new_with_colour(from, to, colour);
+        if let Some(positions) = self.positions.get(&(edge.from(), edge.to())) {
+            self.edges[positions[0]].set_color(colour);
+            return;
+        }
+        self.add_colour(from, to, colour);
+    }
+
+    ///
+    /// adds edge at new position even if there is already edge between from and to (parallel
+    /// edge)
+    ///
+    pub fn add_colour(&mut self, from: usize, to: usize, colour: u8) {
+        let edge = UndirectedEdge::new_with_colour(from, to, colour);
+        self.positions
+            .entry((edge.from(), edge.to()))
+            .or_insert_with(Vec::new)
+            .push(self.edges.len());
+        self.edges.push(edge);
+    }
+
+    ///
+    /// colour of (first of parallel) edges between from and to
+    ///
+    pub fn colour(&self, from: usize, to: usize) -> Option<u8> {
+        self.colours(from, to).first().copied()
+    }
+
+    ///
+    /// colours of all parallel edges between from and to
+    ///
+    pub fn colours(&self, from: usize, to: usize) -> Vec<u8> {
+        let key = if from > to { (to, from) } else { (from, to) };
+        match self.positions.get(&key) {
+            Some(positions) => positions
+                .iter()
+                .map(|position| self.edges[*position].color())
+                .collect(),
+            None => vec![],
+        }
+    }
+
+    pub fn edges(&self) -> Iter<'_, UndirectedEdge> {
+        self.edges.iter()
+    }
+
+    pub fn size(&self) -> usize {
+        self.edges.len()
+    }
+
+    ///
+    /// colouring as list of [from, to, colour] triples
+    ///
+    pub fn to_json_value(&self) -> serde_json::Value {
+        let mut edges = self.edges.clone();
+        edges.sort();
+        let edges = edges
+            .iter()
+            .map(|edge| serde_json::json!([edge.from(), edge.to(), edge.color()]))
+            .collect();
+        serde_json::Value::Array(edges)
+    }
+
+    ///
+    /// local graphs of recursive colourisers keep (neighbor, colour) pairs for each vertex
+    /// and use colours 3, 4 and 5
+    ///
+    pub(crate) fn from_local_vertices(vertices: &[[(usize, usize); 3]]) -> Self {
+        let mut colouring = EdgeColouring::new();
+        for (vertex, neighbors) in vertices.iter().enumerate() {
+            for neighbor in neighbors.iter() {
+                if vertex < neighbor.0 && neighbor.1 >= 3 && neighbor.1 <= 5 {
+                    colouring.set_colour(vertex, neighbor.0, (neighbor.1 - 2) as u8);
+                }
+            }
+        }
+        colouring
+    }
+}
+
+///
+/// Checks if colouring is proper 3-edge-colouring of graph - independent of colourisers
+/// (colouring has to contain as many edges between two vertices as graph)
+///
+pub fn is_valid_colouring<G: Graph>(graph: &G, colouring: &EdgeColouring) -> bool {
+    let mut multiplicities: HashMap<(usize, usize), usize> = HashMap::new();
+    for edge in graph.edges() {
+        *multiplicities.entry((edge.from(), edge.to())).or_insert(0) += 1;
+    }
+    if multiplicities.len() != colouring.positions.len() {
+        return false;
+    }
+    for (key, multiplicity) in multiplicities.iter() {
+        match colouring.positions.get(key) {
+            Some(positions) if positions.len() == *multiplicity => {}
+            _ => return false,
+        }
+    }
+
+    let mut used: HashMap<usize, [bool; 3]> = HashMap::new();
+    for edge in colouring.edges() {
+        if !COLOURS.contains(&edge.color()) {
+            return false;
+        }
+        let colour = edge.color() as usize - 1;
+        for vertex in [edge.from(), edge.to()].iter() {
+            let used = used.entry(*vertex).or_insert([false; 3]);
+            if used[colour] {
+                return false;
+            }
+            used[colour] = true;
+        }
+    }
+    true
+}
@@ -1,6 +1,21 @@
 use crate::graph::graph::Graph;
+use crate::service::colour::colouring::EdgeColouring;
+use crate::service::colour::incremental_colouriser::IncrementalColouriser;
 
 pub trait Colouriser: Clone {
+    ///
+    /// colouriser used for repeated colouring of one graph with temporarily removed edges or
+    /// vertices
+    ///
+    type Incremental: IncrementalColouriser;
+
     fn is_colorable<G: Graph>(graph: &G) -> bool;
+
+    ///
+    /// returns 3-edge-colouring of graph as a witness of colourability or None if graph is not
+    /// colourable
+    ///
+    fn colouring<G: Graph>(graph: &G) -> Option<EdgeColouring>;
+
     fn new() -> Self;
 }
@@ -5,6 +5,7 @@ use rand::seq::SliceRandom;
 use crate::graph::edge::Edge;
 use crate::graph::graph::Graph;
 use crate::graph::vertex::Vertex;
+use crate::service::colour::colouring::EdgeColouring;
 use crate::service::graph_traversal::bfs::BfsOfGraph;
 
 pub static NON_COLOURED_EDGE: u8 = 0;
@@ -39,6 +40,26 @@ const R_LIMIT: usize = 50;
 ///
 ///
 pub fn is_colorable<G>(graph: &G) -> Option<bool>
+where
+    G: Graph,
+{
+    coloured_cvd_graph(graph).map(|_| true)
+}
+
+///
+/// colouring found by heuristic, None if heuristic was not successful
+///
+pub fn colouring<G>(graph: &G) -> Option<EdgeColouring>
+where
+    G: Graph,
+{
+    coloured_cvd_graph(graph).map(|graph| graph.edge_colouring())
+}
+
+///
+/// graph coloured by heuristic, None if heuristic was not successful
+///
+fn coloured_cvd_graph<G>(graph: &G) -> Option<CVDGraph<G>>
 where
     G: Graph,
 {
@@ -55,7 +76,7 @@ where
         let colorable = graph.is_colorable(r_limit);
 
         if colorable {
-            return Some(true);
+            return Some(graph);
         }
     }
     None
@@ -129,6 +150,18 @@ impl<'a, G: Graph> CVDGraph<'a, G> {
         true
     }
 
+    fn edge_colouring(&self) -> EdgeColouring {
+        let mut colouring = EdgeColouring::new();
+        for (vertex, neighbors) in self.vertices.iter().enumerate() {
+            for neighbor in neighbors.iter() {
+                if vertex < neighbor.0 && neighbor.1 != NON_COLOURED_EDGE {
+                    colouring.set_colour(vertex, neighbor.0, neighbor.1 - 2);
+                }
+            }
+        }
+        colouring
+    }
+
     fn random_highest_conflicting_vertex(&self) -> usize {
         if self.conflicting_vertices[2].is_empty() {
             return *self.conflicting_vertices[1]
@@ -171,7 +204,7 @@ impl<'a, G: Graph> CVDGraph<'a, G> {
 
         while let Some(vertex) = bfs_graph.next() {
             let mut available = self.available_colors_of_vertex(vertex.index());
-            for neighbor in (*self_ref).vertices[vertex.index()].iter() {
+            for neighbor in (&(*self_ref).vertices)[vertex.index()].iter() {
                 if neighbor.1 == NON_COLOURED_EDGE {
                     let color = available.pop().unwrap();
                     self.set_edge_color(vertex.index(), neighbor.0, color);
@@ -1,6 +1,8 @@
 use crate::graph::graph::Graph;
+use crate::service::colour::colouring::EdgeColouring;
 use crate::service::colour::colouriser::Colouriser;
 use crate::service::colour::cvd::cvd;
+use crate::service::colour::incremental_colouriser::GraphIncrementalColouriser;
 use crate::service::colour::recursive::dfs_improved::DFSColourizer;
 
 // Colorizer for (sub)cubic graphs only
@@ -8,6 +10,8 @@ use crate::service::colour::recursive::dfs_improved::DFSColourizer;
 pub struct CvdDfsColourizer {}
 
 impl Colouriser for CvdDfsColourizer {
+    type Incremental = GraphIncrementalColouriser<Self>;
+
     fn is_colorable<G>(graph: &G) -> bool
     where
         G: Graph,
@@ -21,6 +25,17 @@ impl Colouriser for CvdDfsColourizer {
         result_cvd.unwrap()
     }
 
+    fn colouring<G>(graph: &G) -> Option<EdgeColouring>
+    where
+        G: Graph,
+    {
+        let colouring_cvd = cvd::colouring(graph);
+        if colouring_cvd.is_none() {
+            return DFSColourizer::colouring(graph);
+        }
+        colouring_cvd
+    }
+
     fn new() -> Self {
         CvdDfsColourizer {}
     }
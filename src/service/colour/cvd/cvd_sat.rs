@@ -1,6 +1,8 @@
 use crate::graph::graph::Graph;
+use crate::service::colour::colouring::EdgeColouring;
 use crate::service::colour::colouriser::Colouriser;
 use crate::service::colour::cvd::cvd;
+use crate::service::colour::incremental_colouriser::GraphIncrementalColouriser;
 use crate::service::colour::sat::sat::SATColourizer;
 
 // Colorizer for (sub)cubic graphs only
@@ -8,6 +10,8 @@ use crate::service::colour::sat::sat::SATColourizer;
 pub struct CvdSatColourizer {}
 
 impl Colouriser for CvdSatColourizer {
+    type Incremental = GraphIncrementalColouriser<Self>;
+
     fn is_colorable<G>(graph: &G) -> bool
     where
         G: Graph,
@@ -20,6 +24,17 @@ impl Colouriser for CvdSatColourizer {
         result_cvd.unwrap()
     }
 
+    fn colouring<G>(graph: &G) -> Option<EdgeColouring>
+    where
+        G: Graph,
+    {
+        let colouring_cvd = cvd::colouring(graph);
+        if colouring_cvd.is_none() {
+            return SATColourizer::colouring(graph);
+        }
+        colouring_cvd
+    }
+
     fn new() -> Self {
         CvdSatColourizer {}
     }
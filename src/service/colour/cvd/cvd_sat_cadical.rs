@@ -1,6 +1,8 @@
 use crate::graph::graph::Graph;
+use crate::service::colour::colouring::EdgeColouring;
 use crate::service::colour::colouriser::Colouriser;
 use crate::service::colour::cvd::cvd;
+use crate::service::colour::incremental_colouriser::GraphIncrementalColouriser;
 use crate::service::colour::sat::sat_cadical::SATColourizerCadical;
 
 // Colorizer for (sub)cubic graphs only
@@ -8,6 +10,8 @@ use crate::service::colour::sat::sat_cadical::SATColourizerCadical;
 pub struct CvdSatCadicalColourizer {}
 
 impl Colouriser for CvdSatCadicalColourizer {
+    type Incremental = GraphIncrementalColouriser<Self>;
+
     fn is_colorable<G>(graph: &G) -> bool
     where
         G: Graph,
@@ -19,6 +23,17 @@ impl Colouriser for CvdSatCadicalColourizer {
         result_cvd.unwrap()
     }
 
+    fn colouring<G>(graph: &G) -> Option<EdgeColouring>
+    where
+        G: Graph,
+    {
+        let colouring_cvd = cvd::colouring(graph);
+        if colouring_cvd.is_none() {
+            return SATColourizerCadical::colouring(graph);
+        }
+        colouring_cvd
+    }
+
     fn new() -> Self {
         CvdSatCadicalColourizer {}
     }
@@ -0,0 +1 @@
+pub mod tait_colourings;
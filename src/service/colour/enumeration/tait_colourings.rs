@@ -0,0 +1,172 @@
+use crate::graph::edge::Edge;
+use crate::graph::graph::Graph;
+use crate::graph::vertex::Vertex;
+use crate::service::colour::colouring::EdgeColouring;
+use std::collections::VecDeque;
+
+///
+/// Iterator over all 3-edge-colourings (Tait colourings) of (sub)cubic graph up to permutation
+/// of colours.
+///
+/// Colourings are normalized - edges are coloured in fixed order and each edge can get at most
+/// colour greater by one than maximal colour used on previous edges, so each class of colourings
+/// equivalent under permutation of colours is visited exactly once.
+///
+pub struct TaitColourings {
+    // edges in order of colouring
+    edges: Vec<(usize, usize)>,
+    // vertex -> positions of its edges in self.edges
+    edges_of_vertex: Vec<Vec<usize>>,
+    // colour of edge at position, 0 - not coloured
+    colours: Vec<u8>,
+    // max_before[i] - maximal colour used on edges at positions 0..i
+    max_before: Vec<u8>,
+    position: usize,
+    finished: bool,
+}
+
+impl TaitColourings {
+    pub fn new<G: Graph>(graph: &G) -> Self {
+        let edges = Self::edges_in_bfs_order(graph);
+        let mut max_vertex = 0;
+        for edge in edges.iter() {
+            max_vertex = max_vertex.max(edge.1 + 1);
+        }
+        let mut edges_of_vertex = vec![vec![]; max_vertex];
+        for (position, edge) in edges.iter().enumerate() {
+            edges_of_vertex[edge.0].push(position);
+            edges_of_vertex[edge.1].push(position);
+        }
+        TaitColourings {
+            colours: vec![0; edges.len()],
+            max_before: vec![0; edges.len() + 1],
+            edges,
+            edges_of_vertex,
+            position: 0,
+            finished: false,
+        }
+    }
+
+    ///
+    /// number of 3-edge-colourings of graph up to permutation of colours
+    ///
+    pub fn count<G: Graph>(graph: &G) -> usize {
+        let mut colourings = TaitColourings::new(graph);
+        let mut count = 0;
+        while colourings.next_colouring() {
+            count += 1;
+        }
+        count
+    }
+
+    ///
+    /// moves to next colouring, returns false if there is no other colouring
+    ///
+    fn next_colouring(&mut self) -> bool {
+        if self.finished {
+            return false;
+        }
+        if self.edges.is_empty() {
+            // graph without edges has exactly one (empty) colouring
+            self.finished = true;
+            return true;
+        }
+        loop {
+            let position = self.position;
+            let limit = (self.max_before[position] + 1).min(3);
+            let mut colour = self.colours[position] + 1;
+            while colour <= limit && self.has_conflict(position, colour) {
+                colour += 1;
+            }
+
+            if colour <= limit {
+                self.colours[position] = colour;
+                self.max_before[position + 1] = self.max_before[position].max(colour);
+                if position + 1 == self.edges.len() {
+                    return true;
+                }
+                self.position += 1;
+            } else {
+                self.colours[position] = 0;
+                if position == 0 {
+                    self.finished = true;
+                    return false;
+                }
+                self.position -= 1;
+            }
+        }
+    }
+
+    fn has_conflict(&self, position: usize, colour: u8) -> bool {
+        let edge = self.edges[position];
+        for vertex in [edge.0, edge.1].iter() {
+            for other in self.edges_of_vertex[*vertex].iter() {
+                if *other != position && self.colours[*other] == colour {
+                    return true;
+                }
+            }
+        }
+        false
+    }
+
+    fn current_colouring(&self) -> EdgeColouring {
+        let mut colouring = EdgeColouring::new();
+        for (position, edge) in self.edges.iter().enumerate() {
+            colouring.set_colour(edge.0, edge.1, self.colours[position]);
+        }
+        colouring
+    }
+
+    ///
+    /// order in which edges close to already coloured edges are coloured first - conflicts are
+    /// discovered sooner
+    ///
+    fn edges_in_bfs_order<G: Graph>(graph: &G) -> Vec<(usize, usize)> {
+        let mut max_vertex = 0;
+        for vertex in graph.vertices() {
+            max_vertex = max_vertex.max(vertex.index() + 1);
+        }
+        let mut visited = vec![false; max_vertex];
+        let mut processed = vec![false; max_vertex];
+        let mut edges = vec![];
+        let mut to_visit = VecDeque::new();
+        for vertex in graph.vertices() {
+            if visited[vertex.index()] {
+                continue;
+            }
+            visited[vertex.index()] = true;
+            to_visit.push_back(vertex.index());
+            while let Some(current) = to_visit.pop_front() {
+                for edge in graph.edges_of_vertex(current) {
+                    let neighbor = if edge.from() == current {
+                        edge.to()
+                    } else {
+                        edge.from()
+                    };
+                    // edge was added already when its other end vertex was processed
+                    if processed[neighbor] {
+                        continue;
+                    }
+                    edges.push((edge.from(), edge.to()));
+                    if !visited[neighbor] {
+                        visited[neighbor] = true;
+                        to_visit.push_back(neighbor);
+                    }
+                }
+                processed[current] = true;
+            }
+        }
+        edges
+    }
+}
+
+impl Iterator for TaitColourings {
+    type Item = EdgeColouring;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.next_colouring() {
+            return Some(self.current_colouring());
+        }
+        None
+    }
+}
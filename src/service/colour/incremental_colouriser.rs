@@ -0,0 +1,286 @@
+use crate::graph::edge::Edge;
+use crate::graph::graph::{Graph, GraphConstructor};
+use crate::graph::undirected::multi_graph::graph::MultiGraph;
+use crate::graph::vertex::Vertex;
+use crate::service::colour::colouring::COLOURS;
+use crate::service::colour::colouriser::Colouriser;
+use std::collections::HashMap;
+use std::marker;
+
+///
+/// Colouriser built once per graph which decides colourability of graph with temporarily removed
+/// edges or vertices. Removals can be nested - edge is present again only after each of its
+/// removals (as edge or as edge of removed vertex) was restored.
+///
+pub trait IncrementalColouriser {
+    fn of_graph<G: Graph>(graph: &G) -> Self;
+
+    fn remove_edge(&mut self, from: usize, to: usize);
+    fn restore_edge(&mut self, from: usize, to: usize);
+
+    fn remove_edges_of_vertex(&mut self, vertex: usize);
+    fn restore_edges_of_vertex(&mut self, vertex: usize);
+
+    fn is_colorable(&mut self) -> bool;
+}
+
+///
+/// Keeps track of removed edges of original graph by their positions in graph.edges() - each of
+/// parallel edges has its own position
+///
+#[derive(Debug, Clone)]
+pub(crate) struct EdgeDeletions {
+    edges: Vec<(usize, usize)>,
+    positions: HashMap<(usize, usize), Vec<usize>>,
+    edges_of_vertex: Vec<Vec<usize>>,
+    removals: Vec<usize>,
+}
+
+impl EdgeDeletions {
+    pub fn of_graph<G: Graph>(graph: &G) -> Self {
+        let mut max_vertex = 0;
+        for vertex in graph.vertices() {
+            max_vertex = max_vertex.max(vertex.index() + 1);
+        }
+        let mut deletions = EdgeDeletions {
+            edges: vec![],
+            positions: HashMap::new(),
+            edges_of_vertex: vec![vec![]; max_vertex],
+            removals: vec![],
+        };
+        for edge in graph.edges() {
+            let position = deletions.edges.len();
+            deletions.edges.push((edge.from(), edge.to()));
+            deletions
+                .positions
+                .entry((edge.from(), edge.to()))
+                .or_insert_with(Vec::new)
+                .push(position);
+            deletions.edges_of_vertex[edge.from()].push(position);
+            deletions.edges_of_vertex[edge.to()].push(position);
+            deletions.removals.push(0);
+        }
+        deletions
+    }
+
+    pub fn edges(&self) -> &Vec<(usize, usize)> {
+        &self.edges
+    }
+
+    ///
+    /// position of edge between from and to to be removed - of parallel edges the one removed the
+    /// least times
+    ///
+    pub fn position_to_remove(&self, from: usize, to: usize) -> Option<usize> {
+        self.parallel_positions(from, to)
+            .iter()
+            .min_by_key(|position| self.removals[**position])
+            .copied()
+    }
+
+    ///
+    /// position of removed edge between from and to to be restored - of parallel edges the one
+    /// removed the most times
+    ///
+    pub fn position_to_restore(&self, from: usize, to: usize) -> Option<usize> {
+        self.parallel_positions(from, to)
+            .iter()
+            .max_by_key(|position| self.removals[**position])
+            .copied()
+    }
+
+    fn parallel_positions(&self, from: usize, to: usize) -> &[usize] {
+        let key = if from > to { (to, from) } else { (from, to) };
+        match self.positions.get(&key) {
+            Some(positions) => positions,
+            None => &[],
+        }
+    }
+
+    pub fn edges_of_vertex(&self, vertex: usize) -> Vec<usize> {
+        match self.edges_of_vertex.get(vertex) {
+            Some(edges) => edges.clone(),
+            None => vec![],
+        }
+    }
+
+    pub fn is_removed(&self, position: usize) -> bool {
+        self.removals[position] > 0
+    }
+
+    ///
+    /// returns true if edge was present before removal
+    ///
+    pub fn remove(&mut self, position: usize) -> bool {
+        self.removals[position] += 1;
+        self.removals[position] == 1
+    }
+
+    ///
+    /// returns true if edge is present after restoration
+    ///
+    pub fn restore(&mut self, position: usize) -> bool {
+        if self.removals[position] == 0 {
+            return false;
+        }
+        self.removals[position] -= 1;
+        self.removals[position] == 0
+    }
+}
+
+///
+/// Incremental colouriser for any colouriser - removals are made on local copy of graph. Last
+/// found colouring is kept - it stays valid after removal of edges and restored edges are
+/// coloured greedily, graph which was not colourable stays so after restoration of edges. Only
+/// if neither helps, graph is coloured from scratch.
+///
+pub struct GraphIncrementalColouriser<C: Colouriser> {
+    // multigraph keeps parallel edges of original graph
+    graph: MultiGraph,
+    deletions: EdgeDeletions,
+    // colours of edges by their positions (0 - not coloured), if some colouring was found
+    colouring: Option<Vec<u8>>,
+    // graph was not colourable and no edge was removed since
+    uncolourable: bool,
+    _colouriser: marker::PhantomData<C>,
+}
+
+impl<C: Colouriser> GraphIncrementalColouriser<C> {
+    fn remove_position(&mut self, position: usize) {
+        if self.deletions.remove(position) {
+            let edge = self.deletions.edges()[position];
+            self.graph.remove_edge(edge.0, edge.1);
+            if let Some(colouring) = self.colouring.as_mut() {
+                colouring[position] = 0;
+            }
+            self.uncolourable = false;
+        }
+    }
+
+    fn restore_position(&mut self, position: usize) {
+        if self.deletions.restore(position) {
+            let edge = self.deletions.edges()[position];
+            self.graph.add_edge(edge.0, edge.1);
+        }
+    }
+
+    ///
+    /// colours present non coloured edges by colour free at both of its ends, returns false if
+    /// some edge cannot be coloured this way
+    ///
+    fn extend_colouring(&mut self) -> bool {
+        let deletions = &self.deletions;
+        let colouring = match self.colouring.as_mut() {
+            Some(colouring) => colouring,
+            None => return false,
+        };
+        for position in 0..colouring.len() {
+            if colouring[position] != 0 || deletions.is_removed(position) {
+                continue;
+            }
+            let (from, to) = deletions.edges()[position];
+            let mut used = [false; 4];
+            for other in deletions
+                .edges_of_vertex(from)
+                .into_iter()
+                .chain(deletions.edges_of_vertex(to))
+            {
+                used[colouring[other] as usize] = true;
+            }
+            match COLOURS.iter().find(|colour| !used[**colour as usize]) {
+                Some(colour) => colouring[position] = *colour,
+                None => return false,
+            }
+        }
+        true
+    }
+}
+
+impl<C: Colouriser> IncrementalColouriser for GraphIncrementalColouriser<C> {
+    fn of_graph<G: Graph>(graph: &G) -> Self {
+        // keep also vertices without edges, colourisers expect same vertices as original graph
+        let mut local_graph = MultiGraph::with_vertices_capacity(graph.size());
+        for vertex in graph.vertices() {
+            local_graph.add_vertex_with_index(vertex.index());
+        }
+        for edge in graph.edges() {
+            local_graph.add_edge(edge.from(), edge.to());
+        }
+        GraphIncrementalColouriser {
+            graph: local_graph,
+            deletions: EdgeDeletions::of_graph(graph),
+            colouring: None,
+            uncolourable: false,
+            _colouriser: marker::PhantomData,
+        }
+    }
+
+    fn remove_edge(&mut self, from: usize, to: usize) {
+        if let Some(position) = self.deletions.position_to_remove(from, to) {
+            self.remove_position(position);
+        }
+    }
+
+    fn restore_edge(&mut self, from: usize, to: usize) {
+        if let Some(position) = self.deletions.position_to_restore(from, to) {
+            self.restore_position(position);
+        }
+    }
+
+    fn remove_edges_of_vertex(&mut self, vertex: usize) {
+        for position in self.deletions.edges_of_vertex(vertex) {
+            self.remove_position(position);
+        }
+    }
+
+    fn restore_edges_of_vertex(&mut self, vertex: usize) {
+        for position in self.deletions.edges_of_vertex(vertex) {
+            self.restore_position(position);
+        }
+    }
+
+    fn is_colorable(&mut self) -> bool {
+        if self.uncolourable {
+            return false;
+        }
+        if self.extend_colouring() {
+            return true;
+        }
+        match C::colouring(&self.graph) {
+            Some(colouring) => {
+                // colours of parallel edges are taken in order of their positions
+                let mut parallel_colours: HashMap<(usize, usize), Vec<u8>> = HashMap::new();
+                let edges = self.deletions.edges();
+                self.colouring = Some(
+                    edges
+                        .iter()
+                        .enumerate()
+                        .map(|(position, (from, to))| {
+                            if self.deletions.is_removed(position) {
+                                return 0;
+                            }
+                            let colours = parallel_colours
+                                .entry((*from, *to))
+                                .or_insert_with(|| colouring.colours(*from, *to));
+                            if colours.is_empty() {
+                                return 0;
+                            }
+                            let colour = colours.remove(0);
+                            if COLOURS.contains(&colour) {
+                                colour
+                            } else {
+                                0
+                            }
+                        })
+                        .collect(),
+                );
+                // colouriser should colour all edges, but missing colours are harmless
+                self.extend_colouring()
+            }
+            None => {
+                self.uncolourable = true;
+                false
+            }
+        }
+    }
+}
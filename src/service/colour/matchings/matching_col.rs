@@ -1,6 +1,8 @@
 use crate::graph::edge::Edge;
 use crate::graph::graph::Graph;
+use crate::service::colour::colouring::{EdgeColouring, COLOURS};
 use crate::service::colour::colouriser::Colouriser;
+use crate::service::colour::incremental_colouriser::GraphIncrementalColouriser;
 use crate::service::matching::perfect_matchings::{
     Matching, MatchingGraph, MatchingGraphVerticesIter,
 };
@@ -9,6 +11,8 @@ use crate::service::matching::perfect_matchings::{
 pub struct MatchingColouriser {}
 
 impl Colouriser for MatchingColouriser {
+    type Incremental = GraphIncrementalColouriser<Self>;
+
     fn is_colorable<G: Graph>(graph: &G) -> bool {
         let mut match_graph = MatchingGraph::from_graph(graph);
         let matchings = match_graph.perfect_matchings();
@@ -16,6 +20,22 @@ impl Colouriser for MatchingColouriser {
         is_col
     }
 
+    fn colouring<G: Graph>(graph: &G) -> Option<EdgeColouring> {
+        let mut match_graph = MatchingGraph::from_graph(graph);
+        let matchings = match_graph.perfect_matchings();
+        if let Some(matching) = Self::first_matching_without_odd_cycle(graph, &matchings) {
+            let mut colouring = EdgeColouring::new();
+            let mut local_graph = MatchingGraph::from_graph(graph);
+            for edge in matching.edges.iter() {
+                local_graph.remove_edge(edge.from(), edge.to());
+                colouring.set_colour(edge.from(), edge.to(), COLOURS[0]);
+            }
+            Self::colour_paths_and_cycles(&local_graph, &mut colouring);
+            return Some(colouring);
+        }
+        None
+    }
+
     fn new() -> Self {
         Self {}
     }
@@ -27,6 +47,13 @@ impl MatchingColouriser {
     /// colourable
     ///
     fn is_col<G: Graph>(graph: &G, matchings: &Vec<Matching>) -> bool {
+        Self::first_matching_without_odd_cycle(graph, matchings).is_some()
+    }
+
+    fn first_matching_without_odd_cycle<'m, G: Graph>(
+        graph: &G,
+        matchings: &'m Vec<Matching>,
+    ) -> Option<&'m Matching> {
         let mut local_graph = MatchingGraph::from_graph(graph);
         for matching in matchings {
             // remove matching from graph
@@ -38,7 +65,7 @@ impl MatchingColouriser {
             // check if match_graph has odd cycle - if not - colourable true
             let has_odd_cycle = cd.has_odd_cycle();
             if !has_odd_cycle {
-                return true;
+                return Some(matching);
             }
             // recover removed edges for next iteration
             for edge in matching.edges.iter() {
@@ -46,7 +73,34 @@ impl MatchingColouriser {
                 local_graph.add_edge(edge.from(), edge.to());
             }
         }
-        false
+        None
+    }
+
+    ///
+    /// colours edges of graph of order at most 2 without odd cycles alternately by second and
+    /// third colour
+    ///
+    fn colour_paths_and_cycles(graph: &MatchingGraph, colouring: &mut EdgeColouring) {
+        let mut starts: Vec<usize> = graph.vertices().map(|vertex| *vertex.index()).collect();
+        // start from end vertices of paths first
+        starts.sort_by_key(|vertex| graph.neighbors(*vertex).len());
+        for start in starts {
+            let mut current = start;
+            let mut colour = COLOURS[1];
+            while let Some(next) = graph
+                .neighbors(current)
+                .iter()
+                .find(|neighbor| colouring.colour(current, **neighbor).is_none())
+            {
+                colouring.set_colour(current, *next, colour);
+                colour = if colour == COLOURS[1] {
+                    COLOURS[2]
+                } else {
+                    COLOURS[1]
+                };
+                current = *next;
+            }
+        }
     }
 }
 
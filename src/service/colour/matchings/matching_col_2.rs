@@ -1,6 +1,9 @@
+use crate::graph::edge::Edge;
 use crate::graph::graph::Graph;
 use crate::graph::undirected::edge::UndirectedEdge;
+use crate::service::colour::colouring::{EdgeColouring, COLOURS};
 use crate::service::colour::colouriser::Colouriser;
+use crate::service::colour::incremental_colouriser::GraphIncrementalColouriser;
 use crate::service::matching::perfect_matchings::{Matching, MatchingGraph};
 
 ///
@@ -13,6 +16,8 @@ use crate::service::matching::perfect_matchings::{Matching, MatchingGraph};
 pub struct MatchingColouriser2 {}
 
 impl Colouriser for MatchingColouriser2 {
+    type Incremental = GraphIncrementalColouriser<Self>;
+
     fn is_colorable<G: Graph>(graph: &G) -> bool {
         let mut match_graph = MatchingGraph::from_graph(graph);
         let matchings = match_graph.perfect_matchings();
@@ -20,6 +25,26 @@ impl Colouriser for MatchingColouriser2 {
         is_col
     }
 
+    fn colouring<G: Graph>(graph: &G) -> Option<EdgeColouring> {
+        let mut match_graph = MatchingGraph::from_graph(graph);
+        let matchings = match_graph.perfect_matchings();
+        if let Some((first, second)) = Self::disjoint_pair(&matchings) {
+            // edges of cubic graph not covered by two disjoint perfect matchings form third one
+            let mut colouring = EdgeColouring::new();
+            for edge in graph.edges() {
+                colouring.set_colour(edge.from(), edge.to(), COLOURS[2]);
+            }
+            for edge in first.edges.iter() {
+                colouring.set_colour(edge.from(), edge.to(), COLOURS[0]);
+            }
+            for edge in second.edges.iter() {
+                colouring.set_colour(edge.from(), edge.to(), COLOURS[1]);
+            }
+            return Some(colouring);
+        }
+        None
+    }
+
     fn new() -> Self {
         Self {}
     }
@@ -31,17 +56,21 @@ impl MatchingColouriser2 {
     /// colourable
     ///
     fn is_col(matchings: &Vec<Matching>) -> bool {
+        Self::disjoint_pair(matchings).is_some()
+    }
+
+    fn disjoint_pair(matchings: &Vec<Matching>) -> Option<(&Matching, &Matching)> {
         let mut i = 0;
         for first in matchings.iter() {
             i = i + 1;
             for j in i..matchings.len() {
                 let second = &matchings[j];
                 if Self::are_disjoint(first, second) {
-                    return true;
+                    return Some((first, second));
                 }
             }
         }
-        false
+        None
     }
 
     fn are_disjoint(first: &Matching, second: &Matching) -> bool {
@@ -1,6 +1,9 @@
+pub mod colouring;
 pub mod colouriser;
+pub mod incremental_colouriser;
 
 pub mod cvd;
+pub mod enumeration;
 pub mod matchings;
 pub mod recursive;
 pub mod sat;
@@ -1,7 +1,9 @@
 use crate::graph::edge::Edge;
 use crate::graph::graph::Graph;
 use crate::graph::vertex::Vertex;
+use crate::service::colour::colouring::EdgeColouring;
 use crate::service::colour::colouriser::Colouriser;
+use crate::service::colour::incremental_colouriser::GraphIncrementalColouriser;
 use crate::service::graph_traversal::bfs::BfsOfGraph;
 
 ///
@@ -16,11 +18,21 @@ struct BFSColouriserGraph<'a, G: Graph> {
 }
 
 impl Colouriser for BFSColouriserBasic {
+    type Incremental = GraphIncrementalColouriser<Self>;
+
     fn is_colorable<G: Graph>(graph: &G) -> bool {
         let mut color_graph = BFSColouriserGraph::new(graph);
         color_graph.color()
     }
 
+    fn colouring<G: Graph>(graph: &G) -> Option<EdgeColouring> {
+        let mut color_graph = BFSColouriserGraph::new(graph);
+        if color_graph.color() {
+            return Some(EdgeColouring::from_local_vertices(&color_graph.vertices));
+        }
+        None
+    }
+
     fn new() -> Self {
         BFSColouriserBasic {}
     }
@@ -1,7 +1,9 @@
 use crate::graph::edge::Edge;
 use crate::graph::graph::Graph;
 use crate::graph::vertex::Vertex;
+use crate::service::colour::colouring::EdgeColouring;
 use crate::service::colour::colouriser::Colouriser;
+use crate::service::colour::incremental_colouriser::GraphIncrementalColouriser;
 
 ///
 /// Colorizer for (sub)cubic graphs only
@@ -19,7 +21,28 @@ struct DFSColourizerGraph {
 }
 
 impl Colouriser for DFSColourizer {
+    type Incremental = GraphIncrementalColouriser<Self>;
+
     fn is_colorable<G: Graph>(graph: &G) -> bool {
+        let (mut color_graph, first_vertex) = Self::create_colour_graph(graph);
+        color_graph.color(first_vertex)
+    }
+
+    fn colouring<G: Graph>(graph: &G) -> Option<EdgeColouring> {
+        let (mut color_graph, first_vertex) = Self::create_colour_graph(graph);
+        if color_graph.color(first_vertex) {
+            return Some(EdgeColouring::from_local_vertices(&color_graph.vertices));
+        }
+        None
+    }
+
+    fn new() -> Self {
+        DFSColourizer {}
+    }
+}
+
+impl DFSColourizer {
+    fn create_colour_graph<G: Graph>(graph: &G) -> (DFSColourizerGraph, usize) {
         let mut vertices = Vec::with_capacity(graph.size());
         // create local graph
         for vertex in graph.vertices() {
@@ -90,11 +113,7 @@ impl Colouriser for DFSColourizer {
         color_graph.one_edge_vert = one_edge_vert;
         color_graph.non_colored_edges = non_colored_edges_of_vertex_count;
         color_graph.non_colored_edges_of_graph = non_colored_edges_of_graph;
-        color_graph.color(first_vertex)
-    }
-
-    fn new() -> Self {
-        DFSColourizer {}
+        (color_graph, first_vertex)
     }
 }
 
@@ -1,6 +1,8 @@
 use crate::graph::edge::Edge;
 use crate::graph::graph::Graph;
+use crate::service::colour::colouring::EdgeColouring;
 use crate::service::colour::colouriser::Colouriser;
+use crate::service::colour::incremental_colouriser::GraphIncrementalColouriser;
 
 ///
 /// Should be true copy of C++ implementation
@@ -19,7 +21,28 @@ struct DFSColourizerOriginalGraph {
 }
 
 impl Colouriser for DFSColourizerOriginal {
+    type Incremental = GraphIncrementalColouriser<Self>;
+
     fn is_colorable<G: Graph>(graph: &G) -> bool {
+        let (mut colour_graph, first_vertex) = Self::create_colour_graph(graph);
+        colour_graph.color(first_vertex)
+    }
+
+    fn colouring<G: Graph>(graph: &G) -> Option<EdgeColouring> {
+        let (mut colour_graph, first_vertex) = Self::create_colour_graph(graph);
+        if colour_graph.color(first_vertex) {
+            return Some(colour_graph.edge_colouring());
+        }
+        None
+    }
+
+    fn new() -> Self {
+        Self {}
+    }
+}
+
+impl DFSColourizerOriginal {
+    fn create_colour_graph<G: Graph>(graph: &G) -> (DFSColourizerOriginalGraph, usize) {
         // create local graph
         let graph_matrix = vec![0; graph.size() * graph.size()];
         let mut colour_graph = DFSColourizerOriginalGraph {
@@ -83,16 +106,10 @@ impl Colouriser for DFSColourizerOriginal {
                 colour_graph.one_edge_vert.push(vertex);
             }
         }
-        colour_graph.color(first_vertex)
-    }
-
-    fn new() -> Self {
-        Self {}
+        (colour_graph, first_vertex)
     }
 }
 
-impl DFSColourizerOriginal {}
-
 impl DFSColourizerOriginalGraph {
     fn color(&mut self, vertex: usize) -> bool {
         let color_vars = [(4, 5), (3, 5), (3, 4)];
@@ -287,6 +304,19 @@ impl DFSColourizerOriginalGraph {
         self.graph[to * self.graph_size + from] = color;
     }
 
+    fn edge_colouring(&self) -> EdgeColouring {
+        let mut colouring = EdgeColouring::new();
+        for from in 0..self.graph_size {
+            for to in (from + 1)..self.graph_size {
+                let colour = self.get_edge_color(from, to);
+                if colour >= 3 && colour <= 5 {
+                    colouring.set_colour(from, to, (colour - 2) as u8);
+                }
+            }
+        }
+        colouring
+    }
+
     // mmat_g
     fn get_edge_color(&self, from: usize, to: usize) -> usize {
         self.graph[from * self.graph_size + to]
@@ -1,2 +1,4 @@
 pub mod sat;
 pub mod sat_cadical;
+pub mod sat_cadical_incremental;
+pub mod sat_incremental;
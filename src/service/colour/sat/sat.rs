@@ -1,63 +1,174 @@
 use crate::graph::edge::Edge;
 use crate::graph::graph::Graph;
-use crate::graph::vertex::Vertex;
+use crate::service::colour::colouring::{EdgeColouring, COLOURS};
 use crate::service::colour::colouriser::Colouriser;
-use std::collections::HashMap;
+use crate::service::colour::sat::sat_incremental::SATIncrementalColouriser;
+use std::collections::HashSet;
+use std::io;
+use std::io::Write;
 use std::iter::FromIterator;
-use varisat::solver::Solver;
-use varisat::{CnfFormula, ExtendFormula};
+use varisat::solver::{Solver, SolverError};
+use varisat::{dimacs, CnfFormula, ExtendFormula, Lit, ProofFormat};
+
+///
+/// literals of edges by their positions in graph.edges() (one for each possible colour), so
+/// parallel edges have their own literals
+///
+type EdgeLits = Vec<((usize, usize), (Lit, Lit, Lit))>;
 
 #[derive(Debug, Clone)]
 pub struct SATColourizer {}
 
 impl Colouriser for SATColourizer {
+    type Incremental = SATIncrementalColouriser;
+
     fn is_colorable<G>(graph: &G) -> bool
     where
         G: Graph,
     {
-        let mut solver = Self::graph_to_cnf_sat(graph);
+        let (mut solver, _edge_lits) = Self::graph_to_cnf_sat(graph);
         solver.solve().unwrap()
     }
 
+    fn colouring<G>(graph: &G) -> Option<EdgeColouring>
+    where
+        G: Graph,
+    {
+        let (mut solver, edge_lits) = Self::graph_to_cnf_sat(graph);
+        if !solver.solve().unwrap() {
+            return None;
+        }
+        Some(Self::model_to_colouring(&solver, &edge_lits))
+    }
+
     fn new() -> Self {
         SATColourizer {}
     }
 }
 
 impl SATColourizer {
-    fn graph_to_cnf_sat<G: Graph>(graph: &G) -> Solver {
+    ///
+    /// Colouring of graph, None if graph is not colourable. If graph is not colourable, writes its
+    /// formula in DIMACS CNF format to cnf and DRAT proof of unsatisfiability of the formula to
+    /// proof.
+    ///
+    pub fn colouring_with_proof<G: Graph>(
+        graph: &G,
+        cnf: &mut impl Write,
+        proof: &mut impl Write,
+    ) -> io::Result<Option<EdgeColouring>> {
+        let (formula, edge_lits) = Self::graph_to_cnf(graph);
+        let mut proof_buffer = vec![];
+        let colouring;
+        {
+            let mut solver = Solver::new();
+            solver.write_proof(&mut proof_buffer, ProofFormat::Drat);
+            solver.add_formula(&formula);
+            let colourable = solver.solve().map_err(Self::to_io_error)?;
+            solver.close_proof().map_err(Self::to_io_error)?;
+            colouring = if colourable {
+                Some(Self::model_to_colouring(&solver, &edge_lits))
+            } else {
+                None
+            };
+        }
+        if colouring.is_none() {
+            dimacs::write_dimacs(cnf, &formula)?;
+            proof.write_all(&proof_buffer)?;
+        }
+        Ok(colouring)
+    }
+
+    fn model_to_colouring(solver: &Solver, edge_lits: &EdgeLits) -> EdgeColouring {
+        let model: HashSet<Lit> = HashSet::from_iter(solver.model().unwrap());
+        let mut colouring = EdgeColouring::new();
+        for (edge, lits) in edge_lits.iter() {
+            if model.contains(&lits.0) {
+                colouring.add_colour(edge.0, edge.1, COLOURS[0]);
+            } else if model.contains(&lits.1) {
+                colouring.add_colour(edge.0, edge.1, COLOURS[1]);
+            } else if model.contains(&lits.2) {
+                colouring.add_colour(edge.0, edge.1, COLOURS[2]);
+            }
+        }
+        colouring
+    }
+
+    ///
+    /// Decides whether colouring of given edges (colours 1, 2 and 3) extends to 3-edge-colouring
+    /// of whole graph - colour of parallel edges is fixed for first of them
+    ///
+    pub fn is_colorable_with_fixed_colours<G: Graph>(
+        graph: &G,
+        fixed_colours: &[((usize, usize), u8)],
+    ) -> bool {
+        let (mut formula, edge_lits) = Self::graph_to_cnf(graph);
+        for ((from, to), colour) in fixed_colours.iter() {
+            let key = if from > to {
+                (*to, *from)
+            } else {
+                (*from, *to)
+            };
+            let lits = match edge_lits.iter().find(|(edge, _lits)| *edge == key) {
+                Some((_edge, lits)) => lits,
+                None => continue,
+            };
+            let lit = match colour {
+                1 => lits.0,
+                2 => lits.1,
+                3 => lits.2,
+                _ => return false,
+            };
+            formula.add_clause(&[lit]);
+        }
+        let mut solver = Solver::new();
+        solver.add_formula(&formula);
+        solver.solve().unwrap()
+    }
+
+    fn to_io_error(error: SolverError) -> io::Error {
+        io::Error::new(io::ErrorKind::Other, format!("sat solver error: {}", error))
+    }
+
+    fn graph_to_cnf_sat<G: Graph>(graph: &G) -> (Solver<'static>, EdgeLits) {
+        let (formula, edge_lits) = Self::graph_to_cnf(graph);
         let mut solver = Solver::new();
+        solver.add_formula(&formula);
+        (solver, edge_lits)
+    }
+
+    fn graph_to_cnf<G: Graph>(graph: &G) -> (CnfFormula, EdgeLits) {
+        let mut formula = CnfFormula::new();
 
-        let mut edge_lits = HashMap::new();
+        let mut edge_lits = vec![];
+        // positions of edges of each vertex
+        let mut edges_of_vertex: Vec<Vec<usize>> = vec![];
 
         for edge in graph.edges() {
             // xij1 - if true - given edge is coloured by color 1
-            let (xij1, xij2, xij3) = solver.new_lits();
-            edge_lits.insert((edge.from(), edge.to()), (xij1, xij2, xij3));
+            let (xij1, xij2, xij3) = formula.new_lits();
+            if edges_of_vertex.len() <= edge.to() {
+                edges_of_vertex.resize(edge.to() + 1, vec![]);
+            }
+            edges_of_vertex[edge.from()].push(edge_lits.len());
+            edges_of_vertex[edge.to()].push(edge_lits.len());
+            edge_lits.push(((edge.from(), edge.to()), (xij1, xij2, xij3)));
 
             // every edge has to be colored exactly by one colour
-            let mut formula = CnfFormula::new();
             formula.add_clause(&[xij1, xij2, xij3]);
             formula.add_clause(&[!xij1, !xij2, xij3]);
             formula.add_clause(&[!xij1, xij2, !xij3]);
             formula.add_clause(&[xij1, !xij2, !xij3]);
             formula.add_clause(&[!xij1, !xij2, !xij3]);
-
-            solver.add_formula(&formula);
         }
 
-        for vertex in graph.vertices() {
-            let edges = graph.edges_of_vertex(vertex.index());
-            let edges: Vec<&G::E> = Vec::from_iter(edges);
-
+        for edges in edges_of_vertex.iter() {
             if edges.is_empty() || edges.len() == 1 {
                 continue;
             }
 
-            let lits_first = edge_lits.get(&(edges[0].from(), edges[0].to())).unwrap();
-            let lits_second = edge_lits.get(&(edges[1].from(), edges[1].to())).unwrap();
-
-            let mut formula = CnfFormula::new();
+            let lits_first = &edge_lits[edges[0]].1;
+            let lits_second = &edge_lits[edges[1]].1;
 
             // 0, 1 - edges 0 and 1 of vertex cannot have same colour
             formula.add_clause(&[!lits_first.0, !lits_second.0]);
@@ -65,7 +176,7 @@ impl SATColourizer {
             formula.add_clause(&[!lits_first.2, !lits_second.2]);
 
             if edges.len() == 3 {
-                let lits_third = edge_lits.get(&(edges[2].from(), edges[2].to())).unwrap();
+                let lits_third = &edge_lits[edges[2]].1;
 
                 // 0, 2
                 formula.add_clause(&[!lits_first.0, !lits_third.0]);
@@ -77,8 +188,7 @@ impl SATColourizer {
                 formula.add_clause(&[!lits_second.1, !lits_third.1]);
                 formula.add_clause(&[!lits_second.2, !lits_third.2]);
             }
-            solver.add_formula(&formula);
         }
-        solver
+        (formula, edge_lits)
     }
 }
@@ -1,29 +1,84 @@
 use crate::graph::edge::Edge;
 use crate::graph::graph::Graph;
-use crate::graph::vertex::Vertex;
+use crate::service::colour::colouring::{EdgeColouring, COLOURS};
 use crate::service::colour::colouriser::Colouriser;
-use std::collections::HashMap;
-use std::iter::FromIterator;
-
+use crate::service::colour::sat::sat::SATColourizer;
+use crate::service::colour::sat::sat_cadical_incremental::SATCadicalIncrementalColouriser;
+use std::io;
+use std::io::Write;
+
+///
+/// cadical bindings don't support proof tracing - DRAT proofs of non colourability are produced
+/// by SATColourizer for graphs found non colourable
+///
 #[derive(Debug, Clone)]
 pub struct SATColourizerCadical {}
 
 impl Colouriser for SATColourizerCadical {
+    type Incremental = SATCadicalIncrementalColouriser;
+
     fn is_colorable<G>(graph: &G) -> bool
     where
         G: Graph,
     {
-        let mut solver = Self::graph_to_cnf_sat(graph);
+        let (mut solver, _edge_lits) = Self::graph_to_cnf_sat(graph);
         solver.solve().unwrap()
     }
 
+    fn colouring<G>(graph: &G) -> Option<EdgeColouring>
+    where
+        G: Graph,
+    {
+        let (mut solver, edge_lits) = Self::graph_to_cnf_sat(graph);
+        if !solver.solve().unwrap() {
+            return None;
+        }
+        Some(Self::model_to_colouring(&solver, &edge_lits))
+    }
+
     fn new() -> Self {
         SATColourizerCadical {}
     }
 }
 
 impl SATColourizerCadical {
-    fn graph_to_cnf_sat<G: Graph>(graph: &G) -> cadical::Solver {
+    ///
+    /// Colouring of graph, None if graph is not colourable. If graph is not colourable, writes its
+    /// formula in DIMACS CNF format to cnf and DRAT proof of unsatisfiability of the formula to
+    /// proof - both are produced only after cadical finds graph non colourable.
+    ///
+    pub fn colouring_with_proof<G: Graph>(
+        graph: &G,
+        cnf: &mut impl Write,
+        proof: &mut impl Write,
+    ) -> io::Result<Option<EdgeColouring>> {
+        if let Some(colouring) = Self::colouring(graph) {
+            return Ok(Some(colouring));
+        }
+        if SATColourizer::colouring_with_proof(graph, cnf, proof)?.is_some() {
+            return Err(io::Error::new(
+                io::ErrorKind::Other,
+                "sat solvers disagree on colourability of graph",
+            ));
+        }
+        Ok(None)
+    }
+
+    fn model_to_colouring(solver: &cadical::Solver, edge_lits: &EdgeLits) -> EdgeColouring {
+        let mut colouring = EdgeColouring::new();
+        for (edge, lits) in edge_lits.edge_lits.iter() {
+            if solver.value(lits.0) == Some(true) {
+                colouring.add_colour(edge.0, edge.1, COLOURS[0]);
+            } else if solver.value(lits.1) == Some(true) {
+                colouring.add_colour(edge.0, edge.1, COLOURS[1]);
+            } else if solver.value(lits.2) == Some(true) {
+                colouring.add_colour(edge.0, edge.1, COLOURS[2]);
+            }
+        }
+        colouring
+    }
+
+    fn graph_to_cnf_sat<G: Graph>(graph: &G) -> (cadical::Solver, EdgeLits) {
         let mut solver: cadical::Solver = Default::default();
 
         let mut edge_lits = EdgeLits::new();
@@ -40,20 +95,13 @@ impl SATColourizerCadical {
             solver.add_clause([-xij1, -xij2, -xij3].iter().copied());
         }
 
-        for vertex in graph.vertices() {
-            let edges = graph.edges_of_vertex(vertex.index());
-            let edges: Vec<&G::E> = Vec::from_iter(edges);
-
+        for edges in edge_lits.edges_of_vertex.iter() {
             if edges.is_empty() || edges.len() == 1 {
                 continue;
             }
 
-            let lits_first = edge_lits
-                .lits_of_edge(edges[0].from(), edges[0].to())
-                .unwrap();
-            let lits_second = edge_lits
-                .lits_of_edge(edges[1].from(), edges[1].to())
-                .unwrap();
+            let lits_first = edge_lits.lits_of_edge(edges[0]);
+            let lits_second = edge_lits.lits_of_edge(edges[1]);
 
             // 0, 1 - edges 0 and 1 of vertex cannot have same colour
             solver.add_clause([-lits_first.0, -lits_second.0].iter().copied());
@@ -61,9 +109,7 @@ impl SATColourizerCadical {
             solver.add_clause([-lits_first.2, -lits_second.2].iter().copied());
 
             if edges.len() == 3 {
-                let lits_third = edge_lits
-                    .lits_of_edge(edges[2].from(), edges[2].to())
-                    .unwrap();
+                let lits_third = edge_lits.lits_of_edge(edges[2]);
 
                 // 0, 2
                 solver.add_clause([-lits_first.0, -lits_third.0].iter().copied());
@@ -76,20 +122,24 @@ impl SATColourizerCadical {
                 solver.add_clause([-lits_second.2, -lits_third.2].iter().copied());
             }
         }
-        solver
+        (solver, edge_lits)
     }
 }
 
 struct EdgeLits {
-    // edge -> literals of edge (one for each possible colour)
-    edge_lits: HashMap<(usize, usize), (i32, i32, i32)>,
+    // edge and its literals (one for each possible colour) by position of edge in graph.edges(),
+    // so parallel edges have their own literals
+    edge_lits: Vec<((usize, usize), (i32, i32, i32))>,
+    // positions of edges of each vertex
+    edges_of_vertex: Vec<Vec<usize>>,
     lits: Vec<usize>,
 }
 
 impl EdgeLits {
     pub fn new() -> Self {
         let mut lits = EdgeLits {
-            edge_lits: HashMap::new(),
+            edge_lits: Vec::new(),
+            edges_of_vertex: Vec::new(),
             lits: Vec::new(),
         };
         lits.lits.push(0);
@@ -105,12 +155,17 @@ impl EdgeLits {
         self.lits.push(self.lits.len());
         lits.2 = self.lits.len() as i32;
         self.lits.push(self.lits.len());
-        self.edge_lits.insert((from, to), lits);
+        if self.edges_of_vertex.len() <= from.max(to) {
+            self.edges_of_vertex.resize(from.max(to) + 1, vec![]);
+        }
+        self.edges_of_vertex[from].push(self.edge_lits.len());
+        self.edges_of_vertex[to].push(self.edge_lits.len());
+        self.edge_lits.push(((from, to), lits));
 
         lits
     }
 
-    pub fn lits_of_edge(&self, from: usize, to: usize) -> Option<&(i32, i32, i32)> {
-        self.edge_lits.get(&(from, to))
+    pub fn lits_of_edge(&self, position: usize) -> &(i32, i32, i32) {
+        &self.edge_lits[position].1
     }
 }
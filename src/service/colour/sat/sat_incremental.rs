@@ -0,0 +1,93 @@
+use crate::graph::graph::Graph;
+use crate::graph::vertex::Vertex;
+use crate::service::colour::incremental_colouriser::{EdgeDeletions, IncrementalColouriser};
+use varisat::solver::Solver;
+use varisat::{ExtendFormula, Lit};
+
+///
+/// Incremental colouriser based on varisat - formula is built once and each edge has selector
+/// literal which is assumed for present edges only, so learned clauses are kept between calls
+///
+pub struct SATIncrementalColouriser {
+    solver: Solver<'static>,
+    selectors: Vec<Lit>,
+    deletions: EdgeDeletions,
+}
+
+impl IncrementalColouriser for SATIncrementalColouriser {
+    fn of_graph<G: Graph>(graph: &G) -> Self {
+        let deletions = EdgeDeletions::of_graph(graph);
+        let mut solver = Solver::new();
+        let mut selectors = Vec::with_capacity(deletions.edges().len());
+        let mut edge_lits = Vec::with_capacity(deletions.edges().len());
+
+        for _edge in deletions.edges() {
+            // xij1 - if true - given edge is coloured by color 1
+            let (xij1, xij2, xij3) = solver.new_lits();
+            let selector = solver.new_lit();
+
+            // present edge has to be coloured, every edge by at most one colour
+            solver.add_clause(&[!selector, xij1, xij2, xij3]);
+            solver.add_clause(&[!xij1, !xij2]);
+            solver.add_clause(&[!xij1, !xij3]);
+            solver.add_clause(&[!xij2, !xij3]);
+
+            selectors.push(selector);
+            edge_lits.push([xij1, xij2, xij3]);
+        }
+
+        for vertex in graph.vertices() {
+            let edges = deletions.edges_of_vertex(vertex.index());
+            for (i, first) in edges.iter().enumerate() {
+                for second in edges.iter().skip(i + 1) {
+                    // edges of vertex cannot have same colour
+                    for colour in 0..3 {
+                        solver
+                            .add_clause(&[!edge_lits[*first][colour], !edge_lits[*second][colour]]);
+                    }
+                }
+            }
+        }
+
+        SATIncrementalColouriser {
+            solver,
+            selectors,
+            deletions,
+        }
+    }
+
+    fn remove_edge(&mut self, from: usize, to: usize) {
+        if let Some(position) = self.deletions.position_to_remove(from, to) {
+            self.deletions.remove(position);
+        }
+    }
+
+    fn restore_edge(&mut self, from: usize, to: usize) {
+        if let Some(position) = self.deletions.position_to_restore(from, to) {
+            self.deletions.restore(position);
+        }
+    }
+
+    fn remove_edges_of_vertex(&mut self, vertex: usize) {
+        for position in self.deletions.edges_of_vertex(vertex) {
+            self.deletions.remove(position);
+        }
+    }
+
+    fn restore_edges_of_vertex(&mut self, vertex: usize) {
+        for position in self.deletions.edges_of_vertex(vertex) {
+            self.deletions.restore(position);
+        }
+    }
+
+    fn is_colorable(&mut self) -> bool {
+        let mut assumptions = Vec::with_capacity(self.selectors.len());
+        for (position, selector) in self.selectors.iter().enumerate() {
+            if !self.deletions.is_removed(position) {
+                assumptions.push(*selector);
+            }
+        }
+        self.solver.assume(&assumptions);
+        self.solver.solve().unwrap()
+    }
+}
@@ -1,17 +1,32 @@
+use crate::graph::edge::Edge;
+use crate::graph::graph::{Graph, GraphConstructor};
+use crate::graph::undirected::multi_graph::graph::MultiGraph;
 use crate::graph::undirected::simple_graph::graph::SimpleGraph;
+use crate::service::colour::colouring;
+use crate::service::colour::colouring::EdgeColouring;
 use crate::service::colour::colouriser::Colouriser;
 use crate::service::colour::cvd::cvd;
 use crate::service::colour::cvd::cvd_dfs::CvdDfsColourizer;
+use crate::service::colour::cvd::cvd_sat::CvdSatColourizer;
+use crate::service::colour::cvd::cvd_sat_cadical::CvdSatCadicalColourizer;
+use crate::service::colour::enumeration::tait_colourings::TaitColourings;
+use crate::service::colour::incremental_colouriser::{
+    GraphIncrementalColouriser, IncrementalColouriser,
+};
 use crate::service::colour::matchings::matching_col::MatchingColouriser;
 use crate::service::colour::matchings::matching_col_2::MatchingColouriser2;
 use crate::service::colour::recursive::bfs_basic::BFSColouriserBasic;
 use crate::service::colour::recursive::bfs_improved::BFSColourizerImproved;
 use crate::service::colour::recursive::dfs_improved::DFSColourizer;
+use crate::service::colour::recursive::dfs_orig::DFSColourizerOriginal;
 use crate::service::colour::sat::sat::SATColourizer;
 use crate::service::colour::sat::sat_cadical::SATColourizerCadical;
+use crate::service::colour::sat::sat_cadical_incremental::SATCadicalIncrementalColouriser;
+use crate::service::colour::sat::sat_incremental::SATIncrementalColouriser;
 use crate::service::io::reader_g6::G6Reader;
 use crate::service::io::reader_s6::S6Reader;
 use crate::tests::test_data::test_data;
+use std::sync::atomic::{AtomicUsize, Ordering};
 
 #[test]
 fn should_be_snark_dfs() {
@@ -134,6 +149,325 @@ fn should_be_colourable_bfs_improved() {
     should_be_colourable::<BFSColourizerImproved>();
 }
 
+#[test]
+fn should_return_valid_colouring() {
+    should_return_colouring::<DFSColourizer>();
+    should_return_colouring::<DFSColourizerOriginal>();
+    should_return_colouring::<BFSColourizerImproved>();
+    should_return_colouring::<SATColourizer>();
+    should_return_colouring::<SATColourizerCadical>();
+    should_return_colouring::<CvdDfsColourizer>();
+    should_return_colouring::<CvdSatColourizer>();
+    should_return_colouring::<CvdSatCadicalColourizer>();
+    should_return_colouring::<MatchingColouriser>();
+    should_return_colouring::<MatchingColouriser2>();
+
+    let graph = G6Reader::<SimpleGraph>::read_graph(test_data::NO_SNARK_IN_G6_18).unwrap();
+    let colouring = BFSColouriserBasic::colouring(&graph).unwrap();
+    assert_eq!(colouring::is_valid_colouring(&graph, &colouring), true);
+}
+
+#[test]
+fn should_return_no_colouring_for_snark() {
+    should_return_no_colouring::<DFSColourizer>();
+    should_return_no_colouring::<DFSColourizerOriginal>();
+    should_return_no_colouring::<BFSColouriserBasic>();
+    should_return_no_colouring::<BFSColourizerImproved>();
+    should_return_no_colouring::<SATColourizer>();
+    should_return_no_colouring::<SATColourizerCadical>();
+    should_return_no_colouring::<CvdDfsColourizer>();
+    should_return_no_colouring::<MatchingColouriser>();
+    should_return_no_colouring::<MatchingColouriser2>();
+}
+
+#[test]
+fn should_return_valid_colouring_cvd() {
+    let graph = test_data::get_colorable_graph_20();
+    if let Some(colouring) = cvd::colouring(&graph) {
+        assert_eq!(colouring::is_valid_colouring(&graph, &colouring), true);
+    }
+    let graph = test_data::get_petersen_graph();
+    assert_eq!(cvd::colouring(&graph), None);
+}
+
+#[test]
+fn should_reject_invalid_colouring() {
+    let graph = G6Reader::<SimpleGraph>::read_graph(test_data::NO_SNARK_IN_G6_18).unwrap();
+    let colouring = SATColourizer::colouring(&graph).unwrap();
+
+    // same colour on two adjacent edges
+    let mut invalid = EdgeColouring::new();
+    for edge in colouring.edges() {
+        invalid.set_colour(edge.from(), edge.to(), edge.color());
+    }
+    let first = graph.edges_of_vertex(0).next().unwrap();
+    let second = graph.edges_of_vertex(0).nth(1).unwrap();
+    let colour = colouring.colour(first.from(), first.to()).unwrap();
+    invalid.set_colour(second.from(), second.to(), colour);
+    assert_eq!(colouring::is_valid_colouring(&graph, &invalid), false);
+
+    // missing edge
+    let mut incomplete = EdgeColouring::new();
+    for edge in colouring.edges().skip(1) {
+        incomplete.set_colour(edge.from(), edge.to(), edge.color());
+    }
+    assert_eq!(colouring::is_valid_colouring(&graph, &incomplete), false);
+
+    // colour out of range
+    let mut out_of_range = colouring.clone();
+    out_of_range.set_colour(first.from(), first.to(), 4);
+    assert_eq!(colouring::is_valid_colouring(&graph, &out_of_range), false);
+}
+
+#[test]
+fn should_verify_colouring_of_parallel_edges() {
+    let mut graph = MultiGraph::with_vertices_capacity(4);
+    for (from, to) in [(0, 1), (0, 1), (0, 2), (1, 3), (2, 3), (2, 3)].iter() {
+        graph.add_edge(*from, *to);
+    }
+    let mut colouring = EdgeColouring::new();
+    colouring.add_colour(0, 1, 1);
+    colouring.add_colour(0, 1, 2);
+    colouring.add_colour(0, 2, 3);
+    colouring.add_colour(1, 3, 3);
+    colouring.add_colour(2, 3, 1);
+    colouring.add_colour(2, 3, 2);
+    assert_eq!(colouring.size(), 6);
+    assert_eq!(colouring.colours(1, 0), vec![1, 2]);
+    assert_eq!(colouring::is_valid_colouring(&graph, &colouring), true);
+
+    // parallel edges with same colour
+    let mut invalid = colouring.clone();
+    invalid.set_colour(0, 1, 2);
+    assert_eq!(colouring::is_valid_colouring(&graph, &invalid), false);
+
+    // one of parallel edges is missing
+    let mut incomplete = EdgeColouring::new();
+    for edge in colouring.edges().skip(1) {
+        incomplete.add_colour(edge.from(), edge.to(), edge.color());
+    }
+    assert_eq!(colouring::is_valid_colouring(&graph, &incomplete), false);
+}
+
+#[test]
+fn should_colour_graph_with_parallel_edges_sat() {
+    should_colour_graph_with_parallel_edges::<SATColourizer>();
+    should_colour_graph_with_parallel_edges::<SATColourizerCadical>();
+}
+
+fn should_colour_graph_with_parallel_edges<C: Colouriser>() {
+    let mut graph = MultiGraph::with_vertices_capacity(4);
+    for (from, to) in [(0, 1), (0, 1), (0, 2), (1, 3), (2, 3), (2, 3)].iter() {
+        graph.add_edge(*from, *to);
+    }
+    assert_eq!(C::is_colorable(&graph), true);
+    let colouring = C::colouring(&graph).unwrap();
+    assert_eq!(colouring::is_valid_colouring(&graph, &colouring), true);
+
+    // cubic multigraph with bridge 2-3
+    let mut graph = MultiGraph::with_vertices_capacity(6);
+    for (from, to) in [
+        (0, 1),
+        (0, 1),
+        (0, 2),
+        (1, 2),
+        (2, 3),
+        (3, 4),
+        (3, 5),
+        (4, 5),
+        (4, 5),
+    ]
+    .iter()
+    {
+        graph.add_edge(*from, *to);
+    }
+    assert_eq!(C::is_colorable(&graph), false);
+    assert_eq!(C::colouring(&graph), None);
+}
+
+#[test]
+fn should_write_unsat_proof_sat() {
+    let graph = test_data::get_petersen_graph();
+    let mut cnf = vec![];
+    let mut proof = vec![];
+    let result = SATColourizer::colouring_with_proof(&graph, &mut cnf, &mut proof).unwrap();
+    assert_eq!(result, None);
+    let cnf = String::from_utf8(cnf).unwrap();
+    let proof = String::from_utf8(proof).unwrap();
+    assert_eq!(cnf.starts_with("p cnf 45 "), true);
+    assert_eq!(is_valid_rup_proof(&cnf, &proof), true);
+
+    let graph = G6Reader::<SimpleGraph>::read_graph(test_data::NO_SNARK_IN_G6_18).unwrap();
+    let mut cnf = vec![];
+    let mut proof = vec![];
+    let result = SATColourizer::colouring_with_proof(&graph, &mut cnf, &mut proof).unwrap();
+    assert_eq!(
+        colouring::is_valid_colouring(&graph, &result.unwrap()),
+        true
+    );
+    assert_eq!(cnf.is_empty(), true);
+    assert_eq!(proof.is_empty(), true);
+}
+
+#[test]
+fn should_write_unsat_proof_sat_cadical() {
+    let graph = test_data::get_petersen_graph();
+    let mut cnf = vec![];
+    let mut proof = vec![];
+    let result = SATColourizerCadical::colouring_with_proof(&graph, &mut cnf, &mut proof).unwrap();
+    assert_eq!(result, None);
+    let cnf = String::from_utf8(cnf).unwrap();
+    let proof = String::from_utf8(proof).unwrap();
+    assert_eq!(cnf.starts_with("p cnf 45 "), true);
+    assert_eq!(is_valid_rup_proof(&cnf, &proof), true);
+
+    let graph = G6Reader::<SimpleGraph>::read_graph(test_data::NO_SNARK_IN_G6_18).unwrap();
+    let mut cnf = vec![];
+    let mut proof = vec![];
+    let result = SATColourizerCadical::colouring_with_proof(&graph, &mut cnf, &mut proof).unwrap();
+    assert_eq!(
+        colouring::is_valid_colouring(&graph, &result.unwrap()),
+        true
+    );
+    assert_eq!(cnf.is_empty(), true);
+    assert_eq!(proof.is_empty(), true);
+}
+
+///
+/// simple forward checker of DRAT proofs which contain only RUP lemmas
+///
+fn is_valid_rup_proof(cnf: &str, proof: &str) -> bool {
+    let parse = |line: &str| -> Vec<i64> {
+        line.split_whitespace()
+            .map(|lit| lit.parse::<i64>().unwrap())
+            .take_while(|lit| *lit != 0)
+            .collect()
+    };
+    let mut clauses: Vec<Vec<i64>> = cnf
+        .lines()
+        .filter(|line| !line.starts_with('p') && !line.starts_with('c') && !line.is_empty())
+        .map(|line| parse(line))
+        .collect();
+
+    for line in proof.lines() {
+        if line.starts_with('d') {
+            let clause = parse(&line[1..]);
+            if let Some(position) = clauses.iter().position(|c| c == &clause) {
+                clauses.remove(position);
+            }
+            continue;
+        }
+        let lemma = parse(line);
+        if !has_conflict_by_propagation(&clauses, lemma.iter().map(|lit| -lit).collect()) {
+            return false;
+        }
+        if lemma.is_empty() {
+            return true;
+        }
+        clauses.push(lemma);
+    }
+    false
+}
+
+fn has_conflict_by_propagation(clauses: &Vec<Vec<i64>>, mut assigned: Vec<i64>) -> bool {
+    loop {
+        let mut changed = false;
+        for clause in clauses.iter() {
+            if clause.iter().any(|lit| assigned.contains(lit)) {
+                continue;
+            }
+            let open: Vec<&i64> = clause
+                .iter()
+                .filter(|lit| !assigned.contains(&-**lit))
+                .collect();
+            match open.len() {
+                0 => return true,
+                1 => {
+                    assigned.push(*open[0]);
+                    changed = true;
+                }
+                _ => {}
+            }
+        }
+        if !changed {
+            return false;
+        }
+    }
+}
+
+#[test]
+fn should_count_tait_colourings() {
+    let mut k4 = SimpleGraph::with_capacity(4, 6);
+    for (from, to) in [(0, 1), (0, 2), (0, 3), (1, 2), (1, 3), (2, 3)].iter() {
+        k4.add_edge(*from, *to);
+    }
+    assert_eq!(TaitColourings::count(&k4), 1);
+
+    let mut cube = SimpleGraph::with_capacity(8, 12);
+    for from in 0..8 {
+        for bit in [1, 2, 4].iter() {
+            if from & bit == 0 {
+                cube.add_edge(from, from | bit);
+            }
+        }
+    }
+    assert_eq!(TaitColourings::count(&cube), 4);
+
+    let graph = test_data::get_petersen_graph();
+    assert_eq!(TaitColourings::count(&graph), 0);
+    let graph = G6Reader::<SimpleGraph>::read_graph(test_data::SNARK_IN_G6_20).unwrap();
+    assert_eq!(TaitColourings::count(&graph), 0);
+}
+
+#[test]
+fn should_enumerate_distinct_tait_colourings() {
+    let graph = G6Reader::<SimpleGraph>::read_graph(test_data::NO_SNARK_IN_G6_18).unwrap();
+    let colourings: Vec<EdgeColouring> = TaitColourings::new(&graph).collect();
+    assert_eq!(colourings.len(), TaitColourings::count(&graph));
+    assert_eq!(colourings.is_empty(), false);
+    for (i, colouring) in colourings.iter().enumerate() {
+        assert_eq!(colouring::is_valid_colouring(&graph, colouring), true);
+        for other in colourings.iter().skip(i + 1) {
+            assert_eq!(are_equivalent_colourings(colouring, other), false);
+        }
+    }
+}
+
+///
+/// colourings are equivalent if one can be obtained from the other by permutation of colours
+///
+fn are_equivalent_colourings(first: &EdgeColouring, second: &EdgeColouring) -> bool {
+    let mut permutation = [0; 4];
+    for edge in first.edges() {
+        let colour = second.colour(edge.from(), edge.to()).unwrap();
+        let mapped = &mut permutation[edge.color() as usize];
+        if *mapped == 0 {
+            *mapped = colour;
+        } else if *mapped != colour {
+            return false;
+        }
+    }
+    true
+}
+
+fn should_return_colouring<C: Colouriser>() {
+    let graph = G6Reader::<SimpleGraph>::read_graph(test_data::NO_SNARK_IN_G6_18).unwrap();
+    let colouring = C::colouring(&graph).unwrap();
+    assert_eq!(colouring::is_valid_colouring(&graph, &colouring), true);
+
+    let graph = S6Reader::<SimpleGraph>::read_graph(test_data::NO_SNARK_IN_S6_50).unwrap();
+    let colouring = C::colouring(&graph).unwrap();
+    assert_eq!(colouring::is_valid_colouring(&graph, &colouring), true);
+}
+
+fn should_return_no_colouring<C: Colouriser>() {
+    let graph = test_data::get_petersen_graph();
+    assert_eq!(C::colouring(&graph), None);
+
+    let graph = G6Reader::<SimpleGraph>::read_graph(test_data::SNARK_IN_G6_20).unwrap();
+    assert_eq!(C::colouring(&graph), None);
+}
+
 fn should_be_snark<C: Colouriser>() {
     let graph = test_data::get_petersen_graph();
     let result = C::is_colorable(&graph);
@@ -165,3 +499,194 @@ fn should_be_colourable<C: Colouriser>() {
     let result = C::is_colorable(&graph.unwrap());
     assert_eq!(result, true);
 }
+
+#[test]
+fn should_colour_incrementally_graph() {
+    should_colour_incrementally::<GraphIncrementalColouriser<DFSColourizer>>();
+}
+
+static COUNTED_COLOURINGS: AtomicUsize = AtomicUsize::new(0);
+
+///
+/// DFS colouriser counting its runs
+///
+#[derive(Clone)]
+struct CountingColouriser {}
+
+impl Colouriser for CountingColouriser {
+    type Incremental = GraphIncrementalColouriser<Self>;
+
+    fn is_colorable<G: Graph>(graph: &G) -> bool {
+        COUNTED_COLOURINGS.fetch_add(1, Ordering::SeqCst);
+        DFSColourizer::is_colorable(graph)
+    }
+
+    fn colouring<G: Graph>(graph: &G) -> Option<EdgeColouring> {
+        COUNTED_COLOURINGS.fetch_add(1, Ordering::SeqCst);
+        DFSColourizer::colouring(graph)
+    }
+
+    fn new() -> Self {
+        CountingColouriser {}
+    }
+}
+
+#[test]
+fn should_reuse_colouring_state_incrementally_graph() {
+    let runs = || COUNTED_COLOURINGS.load(Ordering::SeqCst);
+    let graph = test_data::get_petersen_graph();
+    let mut colouriser = GraphIncrementalColouriser::<CountingColouriser>::of_graph(&graph);
+    assert_eq!(colouriser.is_colorable(), false);
+    assert_eq!(runs(), 1);
+    // graph without removals stays uncolourable
+    assert_eq!(colouriser.is_colorable(), false);
+    assert_eq!(runs(), 1);
+
+    // Petersen graph is critical
+    colouriser.remove_edges_of_vertex(0);
+    colouriser.remove_edges_of_vertex(1);
+    assert_eq!(colouriser.is_colorable(), true);
+    assert_eq!(runs(), 2);
+    // colouring stays valid after further removals
+    colouriser.remove_edge(2, 3);
+    assert_eq!(colouriser.is_colorable(), true);
+    assert_eq!(runs(), 2);
+    colouriser.restore_edge(2, 3);
+    assert_eq!(colouriser.is_colorable(), true);
+
+    colouriser.restore_edges_of_vertex(1);
+    colouriser.restore_edges_of_vertex(0);
+    assert_eq!(colouriser.is_colorable(), false);
+    let runs_after_restoration = runs();
+    assert_eq!(colouriser.is_colorable(), false);
+    assert_eq!(runs(), runs_after_restoration);
+}
+
+#[test]
+fn should_colour_incrementally_sat() {
+    should_colour_incrementally::<SATIncrementalColouriser>();
+}
+
+#[test]
+fn should_colour_incrementally_sat_cadical() {
+    should_colour_incrementally::<SATCadicalIncrementalColouriser>();
+}
+
+fn should_colour_incrementally<I: IncrementalColouriser>() {
+    let graph = test_data::get_petersen_graph();
+    let mut incremental = I::of_graph(&graph);
+    assert_eq!(incremental.is_colorable(), false);
+
+    // every pair of vertices of petersen graph
+    for first_vertex in 0..graph.size() {
+        incremental.remove_edges_of_vertex(first_vertex);
+        for second_vertex in (first_vertex + 1)..graph.size() {
+            incremental.remove_edges_of_vertex(second_vertex);
+
+            let mut local_graph = SimpleGraph::from_graph(&graph);
+            local_graph.remove_edges_of_vertex(first_vertex);
+            local_graph.remove_edges_of_vertex(second_vertex);
+            assert_eq!(
+                incremental.is_colorable(),
+                DFSColourizer::is_colorable(&local_graph)
+            );
+
+            incremental.restore_edges_of_vertex(second_vertex);
+        }
+        incremental.restore_edges_of_vertex(first_vertex);
+    }
+    assert_eq!(incremental.is_colorable(), false);
+
+    // nested removals - edge stays removed until all its removals are restored
+    let edge = graph.edges_of_vertex(0).next().unwrap().clone();
+    let neighbor = if edge.from() == 0 {
+        edge.to()
+    } else {
+        edge.from()
+    };
+    incremental.remove_edge(edge.from(), edge.to());
+    assert_eq!(incremental.is_colorable(), false);
+    incremental.remove_edges_of_vertex(0);
+    incremental.remove_edges_of_vertex(neighbor);
+    assert_eq!(incremental.is_colorable(), true);
+    incremental.restore_edges_of_vertex(0);
+    incremental.restore_edges_of_vertex(neighbor);
+
+    let mut local_graph = SimpleGraph::from_graph(&graph);
+    local_graph.remove_edge(edge.from(), edge.to());
+    assert_eq!(
+        incremental.is_colorable(),
+        DFSColourizer::is_colorable(&local_graph)
+    );
+
+    incremental.restore_edge(edge.from(), edge.to());
+    assert_eq!(incremental.is_colorable(), false);
+}
+
+#[test]
+fn should_colour_incrementally_graph_with_parallel_edges() {
+    should_colour_incrementally_parallel_edges::<GraphIncrementalColouriser<SATColourizer>>();
+    should_colour_incrementally_parallel_edges::<SATIncrementalColouriser>();
+    should_colour_incrementally_parallel_edges::<SATCadicalIncrementalColouriser>();
+}
+
+fn should_colour_incrementally_parallel_edges<I: IncrementalColouriser>() {
+    // cubic multigraph with bridge 2-3 and digons 0-1 and 4-5
+    let mut graph = MultiGraph::with_vertices_capacity(6);
+    for (from, to) in [
+        (0, 1),
+        (0, 1),
+        (0, 2),
+        (1, 2),
+        (2, 3),
+        (3, 4),
+        (3, 5),
+        (4, 5),
+        (4, 5),
+    ]
+    .iter()
+    {
+        graph.add_edge(*from, *to);
+    }
+    let edges: Vec<(usize, usize)> = graph.edges().map(|edge| (edge.from(), edge.to())).collect();
+    let mut incremental = I::of_graph(&graph);
+    assert_eq!(incremental.is_colorable(), false);
+
+    // edges are removed one by one (both of parallel edges too) and restored in reverse order,
+    // result has to be the same as colouring of graph from scratch
+    let mut local_graph = graph.clone();
+    for (from, to) in edges.iter() {
+        incremental.remove_edge(*from, *to);
+        local_graph.remove_edge(*from, *to);
+        assert_eq!(
+            incremental.is_colorable(),
+            SATColourizer::is_colorable(&local_graph)
+        );
+    }
+    for (index, (from, to)) in edges.iter().enumerate().rev() {
+        incremental.restore_edge(*from, *to);
+        let mut local_graph = graph.clone();
+        for (from, to) in edges.iter().take(index) {
+            local_graph.remove_edge(*from, *to);
+        }
+        assert_eq!(
+            incremental.is_colorable(),
+            SATColourizer::is_colorable(&local_graph)
+        );
+    }
+
+    // removal of one of parallel edges keeps the other one
+    incremental.remove_edge(0, 1);
+    incremental.remove_edges_of_vertex(3);
+    let mut local_graph = graph.clone();
+    local_graph.remove_edge(0, 1);
+    local_graph.remove_edges_of_vertex(3);
+    assert_eq!(local_graph.edges().count(), 5);
+    assert_eq!(
+        incremental.is_colorable(),
+        SATColourizer::is_colorable(&local_graph)
+    );
+    incremental.restore_edges_of_vertex(3);
+    incremental.restore_edge(0, 1);
+    assert_eq!(incremental.is_colorable(), false);
+}
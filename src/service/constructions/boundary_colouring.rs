@@ -0,0 +1,132 @@
+use crate::graph::graph::Graph;
+use crate::graph::undirected::simple_graph::graph::SimpleGraph;
+use crate::service::colour::colouring::COLOURS;
+use crate::service::colour::sat::sat::SATColourizer;
+use crate::service::constructions::multipole::Multipole;
+use std::collections::BTreeSet;
+
+///
+/// Colours of dangling edges of multipole in their order, normalized modulo permutation of
+/// colours - colours appear for the first time in order 1, 2, 3
+///
+pub type BoundaryColouring = Vec<u8>;
+
+///
+/// Boundary colourings of multipole which extend to 3-edge-colouring of its interior.
+///
+/// Each dangling edge is ended by new vertex of degree 1 and colourability of such graph with
+/// pre-coloured dangling edges is decided by SAT colouriser.
+///
+pub fn boundary_colourings(multipole: &Multipole) -> BTreeSet<BoundaryColouring> {
+    let graph = graph_with_dangling_edges(multipole);
+    let size = multipole.size();
+    let mut colourings = BTreeSet::new();
+    for colouring in candidate_colourings(multipole) {
+        let fixed_colours: Vec<((usize, usize), u8)> = multipole
+            .dangling_edges()
+            .iter()
+            .enumerate()
+            .map(|(index, vertex)| ((*vertex, size + index), colouring[index]))
+            .collect();
+        if SATColourizer::is_colorable_with_fixed_colours(&graph, &fixed_colours) {
+            colourings.insert(colouring);
+        }
+    }
+    colourings
+}
+
+///
+/// Multipoles are colour-equivalent if they have same number of dangling edges and same
+/// boundary colourings - one can replace the other without changing colourability of graph
+///
+pub fn is_colour_equivalent(first: &Multipole, second: &Multipole) -> bool {
+    first.dangling_edges().len() == second.dangling_edges().len()
+        && boundary_colourings(first) == boundary_colourings(second)
+}
+
+///
+/// Multipole is colour-closed if each boundary colouring allowed by parity lemma (for cubic
+/// multipoles) extends to its interior - such substructure never causes uncolourability of graph
+///
+pub fn is_colour_closed(multipole: &Multipole) -> bool {
+    let colourings = boundary_colourings(multipole);
+    candidate_colourings(multipole)
+        .iter()
+        .all(|colouring| colourings.contains(colouring))
+}
+
+///
+/// subsets of vertices of graph inducing colour-closed multipoles
+///
+pub fn colour_closed_substructures<G: Graph>(
+    graph: &G,
+    vertex_sets: &[Vec<usize>],
+) -> Vec<Vec<usize>> {
+    vertex_sets
+        .iter()
+        .filter(
+            |vertices| match Multipole::from_graph_induced(graph, vertices) {
+                Ok(multipole) => is_colour_closed(&multipole),
+                Err(_) => false,
+            },
+        )
+        .cloned()
+        .collect()
+}
+
+///
+/// all normalized boundary colourings, if multipole is cubic only those satisfying parity lemma
+/// (number of dangling edges of each colour has same parity as number of all dangling edges)
+///
+fn candidate_colourings(multipole: &Multipole) -> Vec<BoundaryColouring> {
+    let dangling_edges = multipole.dangling_edges().len();
+    let mut colourings = vec![];
+    normalized_colourings(&mut vec![], dangling_edges, &mut colourings);
+    if is_cubic(multipole) {
+        colourings.retain(|colouring| {
+            COLOURS.iter().all(|colour| {
+                colouring.iter().filter(|other| *other == colour).count() % 2 == dangling_edges % 2
+            })
+        });
+    }
+    colourings
+}
+
+fn normalized_colourings(
+    prefix: &mut BoundaryColouring,
+    length: usize,
+    colourings: &mut Vec<BoundaryColouring>,
+) {
+    if prefix.len() == length {
+        colourings.push(prefix.clone());
+        return;
+    }
+    let used = prefix.iter().max().cloned().unwrap_or(0);
+    for colour in COLOURS.iter().filter(|colour| **colour <= used + 1) {
+        prefix.push(*colour);
+        normalized_colourings(prefix, length, colourings);
+        prefix.pop();
+    }
+}
+
+fn is_cubic(multipole: &Multipole) -> bool {
+    let mut degrees: Vec<usize> = (0..multipole.size())
+        .map(|vertex| multipole.graph().neighbors_of_vertex(vertex).len())
+        .collect();
+    for vertex in multipole.dangling_edges() {
+        degrees[*vertex] += 1;
+    }
+    degrees.iter().all(|degree| *degree == 3)
+}
+
+///
+/// dangling edge i ends in new vertex size + i
+///
+fn graph_with_dangling_edges(multipole: &Multipole) -> SimpleGraph {
+    let mut graph = multipole.graph().clone();
+    let size = multipole.size();
+    for (index, vertex) in multipole.dangling_edges().iter().enumerate() {
+        graph.add_edge(*vertex, size + index);
+    }
+    graph
+}
@@ -0,0 +1,364 @@
+use crate::graph::graph::{Graph, GraphConstructor};
+use crate::graph::undirected::simple_graph::graph::SimpleGraph;
+use crate::service::colour::colouriser::Colouriser;
+use crate::service::colour::recursive::dfs_improved::DFSColourizer;
+use crate::service::constructions::error::ConstructionError;
+use crate::service::property::cyclic_connectivity::cyclic_edge_connectivity;
+use std::cmp::Ordering;
+use std::collections::VecDeque;
+use std::result;
+
+pub type Result<T> = result::Result<T, ConstructionError>;
+
+///
+/// Restrictions of generated cubic graphs
+///
+#[derive(Debug, Clone, PartialEq)]
+pub struct CubicGraphsConfig {
+    /// minimal girth, shorter cycles are pruned during generation
+    pub girth: usize,
+    /// minimal cyclic edge connectivity, partial graphs with already fixed smaller cyclic edge cut
+    /// are pruned during generation
+    pub cyclic_connectivity: Option<usize>,
+    /// if set, only (non-)colourable graphs are generated, checked only for complete graphs
+    pub colourable: Option<bool>,
+    /// res/mod - only part res of search tree split into mod parts is generated
+    pub shard: Option<(usize, usize)>,
+}
+
+impl CubicGraphsConfig {
+    pub fn all() -> Self {
+        CubicGraphsConfig {
+            girth: 3,
+            cyclic_connectivity: None,
+            colourable: None,
+            shard: None,
+        }
+    }
+}
+
+///
+/// Orderly generation of all non-isomorphic connected cubic graphs of given order.
+///
+/// Graphs are built edge by edge in BFS numbering - the smallest vertex of degree lower than 3
+/// gets its neighbors in increasing order, new vertex can be only the next unused one. Whenever
+/// neighborhood of vertex is complete, partial graph is kept only if no other BFS numbering of it
+/// (from any root and with any order of new neighbors) has lexicographically smaller
+/// neighborhoods. So only graph with the smallest BFS numbering of each isomorphism class is
+/// generated and search tree can be split into disjoint parts (res/mod) without communication.
+///
+pub struct CubicGraphs {
+    vertices: usize,
+    config: CubicGraphsConfig,
+}
+
+impl CubicGraphs {
+    pub fn new(vertices: usize, config: CubicGraphsConfig) -> Result<Self> {
+        if vertices < 4 || vertices % 2 == 1 {
+            return Err(ConstructionError::new(format!(
+                "cubic graphs have even number of vertices (at least 4), got {}",
+                vertices
+            )));
+        }
+        if let Some((res, modulo)) = config.shard {
+            if res >= modulo {
+                return Err(ConstructionError::new(format!(
+                    "res {} has to be smaller than mod {}",
+                    res, modulo
+                )));
+            }
+        }
+        Ok(CubicGraphs { vertices, config })
+    }
+
+    ///
+    /// passes generated graphs one by one to consumer
+    ///
+    pub fn generate<E, F>(&self, consumer: F) -> result::Result<(), E>
+    where
+        F: FnMut(SimpleGraph) -> result::Result<(), E>,
+    {
+        let mut search = Search {
+            vertices: self.vertices,
+            config: &self.config,
+            neighbors: vec![Vec::with_capacity(3); self.vertices],
+            reached: 1,
+            edges: 0,
+            split_nodes: 0,
+            consumer,
+        };
+        search.extend()
+    }
+
+    pub fn all(&self) -> Vec<SimpleGraph> {
+        let mut graphs = vec![];
+        let result: result::Result<(), ()> = self.generate(|graph| {
+            graphs.push(graph);
+            Ok(())
+        });
+        result.unwrap();
+        graphs
+    }
+}
+
+struct Search<'a, F> {
+    vertices: usize,
+    config: &'a CubicGraphsConfig,
+    // neighbors of each vertex, always sorted
+    neighbors: Vec<Vec<usize>>,
+    // vertices 0..reached are already part of graph
+    reached: usize,
+    edges: usize,
+    // nodes of search tree at split level visited so far
+    split_nodes: usize,
+    consumer: F,
+}
+
+impl<'a, E, F> Search<'a, F>
+where
+    F: FnMut(SimpleGraph) -> result::Result<(), E>,
+{
+    fn extend(&mut self) -> result::Result<(), E> {
+        if let Some((res, modulo)) = self.config.shard {
+            // each leaf has more than vertices edges, so each leaf is under some split node
+            if self.edges == self.vertices {
+                self.split_nodes += 1;
+                if (self.split_nodes - 1) % modulo != res {
+                    return Ok(());
+                }
+            }
+        }
+        let vertex = match (0..self.reached).find(|vertex| self.neighbors[*vertex].len() < 3) {
+            Some(vertex) => vertex,
+            None => {
+                // otherwise graph would not be connected
+                if self.reached == self.vertices {
+                    return self.leaf();
+                }
+                return Ok(());
+            }
+        };
+        let first_candidate = match self.neighbors[vertex].last() {
+            Some(last) if *last > vertex => last + 1,
+            _ => vertex + 1,
+        };
+        let last_candidate = self.reached.min(self.vertices - 1);
+        for neighbor in first_candidate..=last_candidate {
+            let new_vertex = neighbor == self.reached;
+            if !new_vertex
+                && (self.neighbors[neighbor].len() == 3 || !self.keeps_girth(vertex, neighbor))
+            {
+                continue;
+            }
+            self.neighbors[vertex].push(neighbor);
+            self.neighbors[neighbor].push(vertex);
+            self.edges += 1;
+            if new_vertex {
+                self.reached += 1;
+            }
+
+            let result = if self.neighbors[vertex].len() < 3
+                || (self.is_smallest(self.rows()) && !self.has_small_cyclic_cut())
+            {
+                self.extend()
+            } else {
+                Ok(())
+            };
+
+            if new_vertex {
+                self.reached -= 1;
+            }
+            self.edges -= 1;
+            self.neighbors[neighbor].pop();
+            self.neighbors[vertex].pop();
+            result?;
+        }
+        Ok(())
+    }
+
+    ///
+    /// number of leading vertices with complete neighborhood
+    ///
+    fn rows(&self) -> usize {
+        (0..self.vertices)
+            .find(|vertex| self.neighbors[*vertex].len() < 3)
+            .unwrap_or(self.vertices)
+    }
+
+    fn leaf(&mut self) -> result::Result<(), E> {
+        let mut graph = SimpleGraph::with_vertices_capacity(self.vertices);
+        for (vertex, neighbors) in self.neighbors.iter().enumerate() {
+            for neighbor in neighbors.iter().filter(|neighbor| **neighbor > vertex) {
+                graph.add_edge(vertex, *neighbor);
+            }
+        }
+        if let Some(cyclic_connectivity) = self.config.cyclic_connectivity {
+            if cyclic_edge_connectivity(&graph)
+                .map(|value| value < cyclic_connectivity)
+                .unwrap_or(true)
+            {
+                return Ok(());
+            }
+        }
+        if let Some(colourable) = self.config.colourable {
+            if DFSColourizer::is_colorable(&graph) != colourable {
+                return Ok(());
+            }
+        }
+        (self.consumer)(graph)
+    }
+
+    ///
+    /// some component of vertices with complete neighborhoods has fewer dangling edges than
+    /// required cyclic connectivity and both it and rest of graph have at least as many vertices
+    /// as dangling edges - then both sides contain cycle (cubic forest with s vertices has less
+    /// than s inner edges) and the cut stays cyclic in every completion of partial graph
+    ///
+    fn has_small_cyclic_cut(&self) -> bool {
+        let cyclic_connectivity = match self.config.cyclic_connectivity {
+            Some(cyclic_connectivity) => cyclic_connectivity,
+            None => return false,
+        };
+        let mut visited = vec![false; self.vertices];
+        for start in 0..self.reached {
+            if visited[start] || self.neighbors[start].len() < 3 {
+                continue;
+            }
+            visited[start] = true;
+            let mut stack = vec![start];
+            let mut size = 0;
+            let mut cut = 0;
+            while let Some(current) = stack.pop() {
+                size += 1;
+                for next in self.neighbors[current].iter() {
+                    if self.neighbors[*next].len() < 3 {
+                        cut += 1;
+                    } else if !visited[*next] {
+                        visited[*next] = true;
+                        stack.push(*next);
+                    }
+                }
+            }
+            if cut < cyclic_connectivity
+                && size < self.vertices
+                && size >= cut
+                && self.vertices - size >= cut
+            {
+                return true;
+            }
+        }
+        false
+    }
+
+    ///
+    /// edge between vertex and neighbor would not close cycle shorter than girth
+    ///
+    fn keeps_girth(&self, vertex: usize, neighbor: usize) -> bool {
+        let max_distance = self.config.girth.saturating_sub(2);
+        let mut distances = vec![usize::MAX; self.vertices];
+        let mut queue = VecDeque::new();
+        distances[vertex] = 0;
+        queue.push_back(vertex);
+        while let Some(current) = queue.pop_front() {
+            if distances[current] >= max_distance {
+                continue;
+            }
+            for next in self.neighbors[current].iter() {
+                if distances[*next] == usize::MAX {
+                    if *next == neighbor {
+                        return false;
+                    }
+                    distances[*next] = distances[current] + 1;
+                    queue.push_back(*next);
+                }
+            }
+        }
+        true
+    }
+
+    ///
+    /// no other BFS numbering has smaller neighborhoods of first rows vertices (neighborhoods of
+    /// vertices 0..rows are complete)
+    ///
+    fn is_smallest(&self, rows: usize) -> bool {
+        for root in 0..self.reached {
+            if self.neighbors[root].len() < 3 {
+                continue;
+            }
+            let mut labels = vec![None; self.vertices];
+            labels[root] = Some(0);
+            if self.has_smaller_numbering(&mut labels, &mut vec![root], 0, rows) {
+                return false;
+            }
+        }
+        true
+    }
+
+    ///
+    /// vertices in order get labels 0..order.len(), row is first vertex in order whose
+    /// neighborhood is not compared yet
+    ///
+    fn has_smaller_numbering(
+        &self,
+        labels: &mut Vec<Option<usize>>,
+        order: &mut Vec<usize>,
+        row: usize,
+        rows: usize,
+    ) -> bool {
+        if row == rows || row >= order.len() {
+            return false;
+        }
+        let vertex = order[row];
+        // neighborhood of vertex is not known yet
+        if self.neighbors[vertex].len() < 3 {
+            return false;
+        }
+        let mut labelled = vec![];
+        let mut new_vertices = vec![];
+        for neighbor in self.neighbors[vertex].iter() {
+            match labels[*neighbor] {
+                Some(label) => labelled.push(label),
+                None => new_vertices.push(*neighbor),
+            }
+        }
+        labelled.sort_unstable();
+        let next_label = order.len();
+        labelled.extend(next_label..next_label + new_vertices.len());
+        match labelled.cmp(&self.neighbors[row]) {
+            Ordering::Less => return true,
+            Ordering::Greater => return false,
+            Ordering::Equal => {}
+        }
+        for permutation in permutations(&new_vertices) {
+            for (index, new_vertex) in permutation.iter().enumerate() {
+                labels[*new_vertex] = Some(next_label + index);
+                order.push(*new_vertex);
+            }
+            let smaller = self.has_smaller_numbering(labels, order, row + 1, rows);
+            for new_vertex in permutation.iter() {
+                labels[*new_vertex] = None;
+                order.pop();
+            }
+            if smaller {
+                return true;
+            }
+        }
+        false
+    }
+}
+
+fn permutations(items: &[usize]) -> Vec<Vec<usize>> {
+    if items.len() <= 1 {
+        return vec![items.to_vec()];
+    }
+    let mut result = vec![];
+    for (index, item) in items.iter().enumerate() {
+        let mut rest = items.to_vec();
+        rest.remove(index);
+        for mut permutation in permutations(&rest) {
+            permutation.insert(0, *item);
+            result.push(permutation);
+        }
+    }
+    result
+}
@@ -115,10 +115,21 @@ impl<'a, G: Graph<E = UndirectedEdge> + Clone + GraphConstructor> Iterator for D
     type Item = G;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if self.edge_pair_current.is_none() {
-            self.edge_pair_current = self.non_adjacent_edge_pairs_of_g.next();
-        }
-        if let Some(edges) = self.edge_pair_current {
+        self.next_with_parts().map(|product| product.0)
+    }
+}
+
+impl<'a, G: Graph<E = UndirectedEdge> + Clone + GraphConstructor> DotProducts<'a, G> {
+    ///
+    /// next dot product along with pair of non adjacent edges of first graph and pair of adjacent
+    /// vertices of second graph it was constructed from
+    ///
+    pub fn next_with_parts(&mut self) -> Option<(G, [UndirectedEdge; 2], [usize; 2])> {
+        loop {
+            if self.edge_pair_current.is_none() {
+                self.edge_pair_current = self.non_adjacent_edge_pairs_of_g.next();
+            }
+            let edges = self.edge_pair_current?;
             if let Some(vertices) = self.adjacent_vertex_pairs_of_h.next() {
                 let graph = dot_product(
                     self.graph_g,
@@ -128,13 +139,15 @@ impl<'a, G: Graph<E = UndirectedEdge> + Clone + GraphConstructor> Iterator for D
                     vertices.0,
                     vertices.1,
                 );
-                return Some(graph);
+                return Some((
+                    graph,
+                    [edges.0.clone(), edges.1.clone()],
+                    [vertices.0.index(), vertices.1.index()],
+                ));
             }
             self.edge_pair_current = None;
             self.adjacent_vertex_pairs_of_h = PairsOfAdjacentVertices::new(self.graph_h);
-            return self.next();
         }
-        None
     }
 }
 
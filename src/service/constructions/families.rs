@@ -0,0 +1,354 @@
+use crate::graph::edge::EdgeConstructor;
+use crate::graph::graph::{Graph, GraphConstructor};
+use crate::graph::undirected::edge::UndirectedEdge;
+use crate::service::constructions::dot_product::{dot_product, DotProducts};
+use crate::service::constructions::error::ConstructionError;
+use std::str::FromStr;
+use std::{fmt, result};
+
+pub type Result<T> = result::Result<T, ConstructionError>;
+
+// family names
+pub const GENERALIZED_PETERSEN: &str = "generalized-petersen";
+pub const PRISM: &str = "prism";
+pub const MOBIUS_LADDER: &str = "mobius-ladder";
+pub const FLOWER: &str = "flower";
+pub const GOLDBERG: &str = "goldberg";
+pub const BLANUSA: &str = "blanusa";
+pub const LOUPEKINE: &str = "loupekine";
+pub const SZEKERES: &str = "szekeres";
+pub const DOT_PRODUCT_CHAIN: &str = "dot-product-chain";
+
+///
+/// Parametric families of cubic graphs (mostly snarks) - member of family is given by n
+/// (and k for generalized Petersen graphs)
+///
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum GraphFamily {
+    GeneralizedPetersen,
+    Prism,
+    MobiusLadder,
+    Flower,
+    Goldberg,
+    Blanusa,
+    Loupekine,
+    Szekeres,
+    DotProductChain,
+}
+
+impl GraphFamily {
+    pub fn generate<G: Graph<E = UndirectedEdge> + GraphConstructor + Clone>(
+        &self,
+        n: usize,
+        k: Option<usize>,
+    ) -> Result<G> {
+        match self {
+            GraphFamily::GeneralizedPetersen => match k {
+                Some(k) => generalized_petersen(n, k),
+                None => Err(ConstructionError::new(
+                    "generalized Petersen graph requires parameter k",
+                )),
+            },
+            GraphFamily::Prism => prism(n),
+            GraphFamily::MobiusLadder => mobius_ladder(n),
+            GraphFamily::Flower => flower_snark(n),
+            GraphFamily::Goldberg => goldberg_snark(n),
+            GraphFamily::Blanusa => blanusa_snark(n),
+            GraphFamily::Loupekine => loupekine_snark(n),
+            GraphFamily::Szekeres => szekeres_snark(n),
+            GraphFamily::DotProductChain => dot_product_chain(n),
+        }
+    }
+}
+
+impl fmt::Display for GraphFamily {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            GraphFamily::GeneralizedPetersen => GENERALIZED_PETERSEN,
+            GraphFamily::Prism => PRISM,
+            GraphFamily::MobiusLadder => MOBIUS_LADDER,
+            GraphFamily::Flower => FLOWER,
+            GraphFamily::Goldberg => GOLDBERG,
+            GraphFamily::Blanusa => BLANUSA,
+            GraphFamily::Loupekine => LOUPEKINE,
+            GraphFamily::Szekeres => SZEKERES,
+            GraphFamily::DotProductChain => DOT_PRODUCT_CHAIN,
+        };
+        write!(f, "{}", name)
+    }
+}
+
+impl FromStr for GraphFamily {
+    type Err = ConstructionError;
+
+    fn from_str(input: &str) -> result::Result<GraphFamily, Self::Err> {
+        match input {
+            GENERALIZED_PETERSEN => Ok(GraphFamily::GeneralizedPetersen),
+            PRISM => Ok(GraphFamily::Prism),
+            MOBIUS_LADDER => Ok(GraphFamily::MobiusLadder),
+            FLOWER => Ok(GraphFamily::Flower),
+            GOLDBERG => Ok(GraphFamily::Goldberg),
+            BLANUSA => Ok(GraphFamily::Blanusa),
+            LOUPEKINE => Ok(GraphFamily::Loupekine),
+            SZEKERES => Ok(GraphFamily::Szekeres),
+            DOT_PRODUCT_CHAIN => Ok(GraphFamily::DotProductChain),
+            _ => Err(ConstructionError::new(format!(
+                "unknown graph family: {}",
+                input
+            ))),
+        }
+    }
+}
+
+///
+/// GP(n, k) - outer cycle 0..n, spokes i - n + i and inner vertices n + i and n + (i + k) mod n
+/// adjacent, GP(5, 2) is Petersen graph and GP(8, 3) Möbius–Kantor graph
+///
+pub fn generalized_petersen<G: Graph + GraphConstructor>(n: usize, k: usize) -> Result<G> {
+    if n < 3 || k == 0 || 2 * k >= n {
+        return Err(ConstructionError::new(format!(
+            "generalized Petersen graph GP({}, {}) requires n >= 3 and 0 < k < n/2",
+            n, k
+        )));
+    }
+    let mut graph = G::with_vertices_capacity(2 * n);
+    for i in 0..n {
+        graph.add_edge(i, (i + 1) % n);
+        graph.add_edge(i, n + i);
+        graph.add_edge(n + i, n + (i + k) % n);
+    }
+    Ok(graph)
+}
+
+pub fn prism<G: Graph + GraphConstructor>(n: usize) -> Result<G> {
+    generalized_petersen(n, 1)
+}
+
+///
+/// cycle of length 2n with opposite vertices adjacent
+///
+pub fn mobius_ladder<G: Graph + GraphConstructor>(n: usize) -> Result<G> {
+    if n < 2 {
+        return Err(ConstructionError::new(format!(
+            "Möbius ladder requires n >= 2, got {}",
+            n
+        )));
+    }
+    let mut graph = G::with_vertices_capacity(2 * n);
+    for i in 0..2 * n {
+        graph.add_edge(i, (i + 1) % (2 * n));
+    }
+    for i in 0..n {
+        graph.add_edge(i, i + n);
+    }
+    Ok(graph)
+}
+
+///
+/// Isaacs flower snark J_n (snark for odd n >= 5) - n stars with centers 4i and leaves 4i + 1,
+/// 4i + 2, 4i + 3, leaves 4i + 1 form n-cycle, leaves 4i + 2 and 4i + 3 form 2n-cycle
+///
+pub fn flower_snark<G: Graph + GraphConstructor>(n: usize) -> Result<G> {
+    if n < 3 {
+        return Err(ConstructionError::new(format!(
+            "flower snark requires n >= 3, got {}",
+            n
+        )));
+    }
+    let mut graph = G::with_vertices_capacity(4 * n);
+    for i in 0..n {
+        let next = (i + 1) % n;
+        graph.add_edge(4 * i, 4 * i + 1);
+        graph.add_edge(4 * i, 4 * i + 2);
+        graph.add_edge(4 * i, 4 * i + 3);
+        graph.add_edge(4 * i + 1, 4 * next + 1);
+        if next == 0 {
+            // twist closing 2n-cycle
+            graph.add_edge(4 * i + 2, 3);
+            graph.add_edge(4 * i + 3, 2);
+        } else {
+            graph.add_edge(4 * i + 2, 4 * next + 2);
+            graph.add_edge(4 * i + 3, 4 * next + 3);
+        }
+    }
+    Ok(graph)
+}
+
+///
+/// Block of Goldberg and Loupekine snarks - Petersen graph GP(5, 2) without path 0, 1, 2 as
+/// 7 vertices (3, ..., 9 of Petersen graph shifted by 3) with 5 dangling edges
+///
+struct PetersenBlock {}
+
+impl PetersenBlock {
+    const SIZE: usize = 7;
+    const EDGES: [(usize, usize); 8] = [
+        (0, 1),
+        (0, 5),
+        (1, 6),
+        (2, 4),
+        (4, 6),
+        (6, 3),
+        (3, 5),
+        (5, 2),
+    ];
+    // dangling edges towards removed vertices 0, 1 and 2
+    const FIRST_PAIR: [usize; 2] = [1, 2];
+    const MIDDLE: usize = 3;
+    const SECOND_PAIR: [usize; 2] = [0, 4];
+
+    fn add<G: Graph>(graph: &mut G, offset: usize) {
+        for (from, to) in Self::EDGES.iter() {
+            graph.add_edge(offset + from, offset + to);
+        }
+    }
+}
+
+///
+/// Goldberg snark (snark for odd n >= 5) - n Petersen blocks joined into ring, middle dangling
+/// edges of blocks end in vertices of n-cycle
+///
+pub fn goldberg_snark<G: Graph + GraphConstructor>(n: usize) -> Result<G> {
+    if n < 3 {
+        return Err(ConstructionError::new(format!(
+            "Goldberg snark requires n >= 3, got {}",
+            n
+        )));
+    }
+    let block_size = PetersenBlock::SIZE + 1;
+    let mut graph = G::with_vertices_capacity(block_size * n);
+    for i in 0..n {
+        let offset = block_size * i;
+        let next = block_size * ((i + 1) % n);
+        let cycle_vertex = offset + PetersenBlock::SIZE;
+        PetersenBlock::add(&mut graph, offset);
+        graph.add_edge(offset + PetersenBlock::MIDDLE, cycle_vertex);
+        graph.add_edge(cycle_vertex, next + PetersenBlock::SIZE);
+        for pair in 0..2 {
+            graph.add_edge(
+                offset + PetersenBlock::SECOND_PAIR[pair],
+                next + PetersenBlock::FIRST_PAIR[pair],
+            );
+        }
+    }
+    Ok(graph)
+}
+
+///
+/// first (n = 1) and second (n = 2) Loupekine snark on 22 vertices - three Petersen blocks
+/// joined into ring (second one with crossed join), middle dangling edges end in common vertex
+///
+pub fn loupekine_snark<G: Graph + GraphConstructor>(n: usize) -> Result<G> {
+    if n != 1 && n != 2 {
+        return Err(ConstructionError::new(format!(
+            "Loupekine snark is first (n = 1) or second (n = 2), got {}",
+            n
+        )));
+    }
+    let blocks = 3;
+    let center = PetersenBlock::SIZE * blocks;
+    let mut graph = G::with_vertices_capacity(center + 1);
+    for i in 0..blocks {
+        let offset = PetersenBlock::SIZE * i;
+        let next = PetersenBlock::SIZE * ((i + 1) % blocks);
+        PetersenBlock::add(&mut graph, offset);
+        graph.add_edge(offset + PetersenBlock::MIDDLE, center);
+        let crossed = n == 2 && i == blocks - 1;
+        for pair in 0..2 {
+            let other = if crossed { 1 - pair } else { pair };
+            graph.add_edge(
+                offset + PetersenBlock::SECOND_PAIR[pair],
+                next + PetersenBlock::FIRST_PAIR[other],
+            );
+        }
+    }
+    Ok(graph)
+}
+
+///
+/// Szekeres snark on 50 vertices (n = 1) - five blocks of Petersen graph without two
+/// non-adjacent edges, block i is path (10i, ..., 10i + 8) with vertex 10i + 9 and chords,
+/// blocks are joined by dangling edges 10i -> 10(i + 1) + 8 and 10i + 6 -> 10(i + 2) + 2
+///
+pub fn szekeres_snark<G: Graph + GraphConstructor>(n: usize) -> Result<G> {
+    if n != 1 {
+        return Err(ConstructionError::new(format!(
+            "Szekeres snark is only one (n = 1), got {}",
+            n
+        )));
+    }
+    let blocks = 5;
+    let block_size = 10;
+    let mut graph = G::with_vertices_capacity(block_size * blocks);
+    for i in 0..blocks {
+        let offset = block_size * i;
+        for j in 0..8 {
+            graph.add_edge(offset + j, offset + j + 1);
+        }
+        for j in [1, 4, 7].iter() {
+            graph.add_edge(offset + j, offset + 9);
+        }
+        graph.add_edge(offset, offset + 5);
+        graph.add_edge(offset + 3, offset + 8);
+        graph.add_edge(offset, block_size * ((i + 1) % blocks) + 8);
+        graph.add_edge(offset + 6, block_size * ((i + 2) % blocks) + 2);
+    }
+    Ok(graph)
+}
+
+///
+/// first (n = 1) and second (n = 2) Blanuša snark on 18 vertices - dot products of two Petersen
+/// graphs, removed edges of first one are joined by edge (n = 1) or are at distance 2 (n = 2)
+///
+pub fn blanusa_snark<G: Graph<E = UndirectedEdge> + GraphConstructor + Clone>(
+    n: usize,
+) -> Result<G> {
+    let petersen: G = generalized_petersen(5, 2)?;
+    let (first_edge, second_edge) = match n {
+        // edges 0-1 and 2-3 are joined by edge 1-2
+        1 => (UndirectedEdge::new(0, 1), UndirectedEdge::new(2, 3)),
+        // no edge joins edge 0-1 with spoke 3-8
+        2 => (UndirectedEdge::new(0, 1), UndirectedEdge::new(3, 8)),
+        _ => {
+            return Err(ConstructionError::new(format!(
+                "Blanuša snark is first (n = 1) or second (n = 2), got {}",
+                n
+            )))
+        }
+    };
+    let vertices: Vec<&G::V> = petersen.vertices().take(2).collect();
+    Ok(dot_product(
+        &petersen,
+        &petersen,
+        &first_edge,
+        &second_edge,
+        vertices[0],
+        vertices[1],
+    ))
+}
+
+///
+/// dot product of n Petersen graphs (Isaacs) - each next Petersen graph is joined to result of
+/// previous dot products by its first dot product
+///
+pub fn dot_product_chain<G: Graph<E = UndirectedEdge> + GraphConstructor + Clone>(
+    n: usize,
+) -> Result<G> {
+    if n < 1 {
+        return Err(ConstructionError::new("dot product chain requires n >= 1"));
+    }
+    let petersen: G = generalized_petersen(5, 2)?;
+    let mut graph = petersen.clone();
+    for _ in 1..n {
+        let product = DotProducts::new(&graph, &petersen).next();
+        graph = match product {
+            Some(product) => product,
+            None => {
+                return Err(ConstructionError::new(
+                    "dot product of graphs in chain does not exist",
+                ))
+            }
+        };
+    }
+    Ok(graph)
+}
@@ -40,11 +40,7 @@ impl<'a, G: Graph<E = UndirectedEdge> + Clone, C: Colouriser> Iterator for IExte
     type Item = G;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if let Some(pair) = self.removable_edge_pairs.next() {
-            let graph = i_extension(self.graph, pair.0, pair.1);
-            return Some(graph);
-        }
-        None
+        self.next_with_edges().map(|extension| extension.0)
     }
 }
 
@@ -56,4 +52,13 @@ impl<'a, G: Graph<E = UndirectedEdge> + Clone, C: Colouriser> IExtensions<'a, G,
             removable_edge_pairs: RemovablePairsOfEdges::new(&graph, &colouriser),
         }
     }
+
+    ///
+    /// next I-extension along with pair of edges of graph it was constructed from
+    ///
+    pub fn next_with_edges(&mut self) -> Option<(G, [UndirectedEdge; 2])> {
+        let pair = self.removable_edge_pairs.next()?;
+        let graph = i_extension(self.graph, pair.0, pair.1);
+        Some((graph, [pair.0.clone(), pair.1.clone()]))
+    }
 }
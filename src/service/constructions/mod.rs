@@ -1,7 +1,12 @@
+pub mod boundary_colouring;
+pub mod cubic_graphs;
 pub mod dot_product;
 pub mod error;
+pub mod families;
 pub mod i_extension;
 pub mod isomorphism;
+pub mod multipole;
+pub mod superposition;
 pub mod y_extension;
 
 #[cfg(test)]
@@ -0,0 +1,244 @@
+use crate::graph::edge::Edge;
+use crate::graph::graph::{Graph, GraphConstructor};
+use crate::graph::undirected::simple_graph::graph::SimpleGraph;
+use crate::service::constructions::error::ConstructionError;
+use std::result;
+
+pub type Result<T> = result::Result<T, ConstructionError>;
+
+///
+/// Graph with ordered dangling edges - each dangling edge is incident with single vertex of graph
+/// (vertex can have more of them), its other end is free and waits to be joined with another
+/// dangling edge
+///
+#[derive(Debug, Clone)]
+pub struct Multipole {
+    graph: SimpleGraph,
+    dangling_edges: Vec<usize>,
+}
+
+impl Multipole {
+    ///
+    /// dangling_edges contains vertex of graph incident with each dangling edge
+    ///
+    pub fn new(graph: SimpleGraph, dangling_edges: Vec<usize>) -> Result<Self> {
+        if let Some(vertex) = dangling_edges
+            .iter()
+            .find(|vertex| **vertex >= graph.size())
+        {
+            return Err(ConstructionError::new(format!(
+                "dangling edge of multipole incident with unknown vertex {}",
+                vertex
+            )));
+        }
+        Ok(Multipole {
+            graph,
+            dangling_edges,
+        })
+    }
+
+    ///
+    /// multipole consisting of single vertex with given number of dangling edges
+    ///
+    pub fn vertex(dangling_edges: usize) -> Self {
+        let mut graph = SimpleGraph::with_vertices_capacity(1);
+        graph.add_vertex();
+        Multipole {
+            graph,
+            dangling_edges: vec![0; dangling_edges],
+        }
+    }
+
+    ///
+    /// removes vertex of graph, each edge of removed vertex becomes dangling edge of its neighbor
+    /// (in order of neighbors of removed vertex), vertices after removed one are shifted
+    ///
+    pub fn from_graph_without_vertex<G: Graph>(graph: &G, vertex: usize) -> Result<Self> {
+        if vertex >= graph.size() {
+            return Err(ConstructionError::new(format!(
+                "cannot remove vertex {} of graph with {} vertices",
+                vertex,
+                graph.size()
+            )));
+        }
+        let reindex = |index: usize| if index > vertex { index - 1 } else { index };
+        let mut result = SimpleGraph::with_vertices_capacity(graph.size() - 1);
+        for _ in 0..graph.size() - 1 {
+            result.add_vertex();
+        }
+        for edge in graph.edges() {
+            if edge.from() != vertex && edge.to() != vertex {
+                result.add_edge(reindex(edge.from()), reindex(edge.to()));
+            }
+        }
+        let dangling_edges = graph
+            .neighbors_of_vertex(vertex)
+            .into_iter()
+            .map(reindex)
+            .collect();
+        Multipole::new(result, dangling_edges)
+    }
+
+    ///
+    /// removes edges of graph, each removed edge (from, to) becomes two dangling edges - first
+    /// of vertex from, second of vertex to
+    ///
+    pub fn from_graph_without_edges<G: Graph>(graph: &G, edges: &[(usize, usize)]) -> Result<Self> {
+        let mut result = SimpleGraph::from_graph(graph);
+        while result.size() < graph.size() {
+            result.add_vertex();
+        }
+        let mut dangling_edges = vec![];
+        for (from, to) in edges.iter() {
+            if !result.has_edge(*from, *to) {
+                return Err(ConstructionError::new(format!(
+                    "cannot remove edge ({}, {}) which is not in graph",
+                    from, to
+                )));
+            }
+            result.remove_edge(*from, *to);
+            dangling_edges.push(*from);
+            dangling_edges.push(*to);
+        }
+        Multipole::new(result, dangling_edges)
+    }
+
+    ///
+    /// multipole induced by given vertices of graph (reindexed in given order), each edge leaving
+    /// them becomes dangling edge
+    ///
+    pub fn from_graph_induced<G: Graph>(graph: &G, vertices: &[usize]) -> Result<Self> {
+        let mut positions = vec![None; graph.size()];
+        for (position, vertex) in vertices.iter().enumerate() {
+            if *vertex >= graph.size() || positions[*vertex].is_some() {
+                return Err(ConstructionError::new(format!(
+                    "vertex {} is unknown or repeated",
+                    vertex
+                )));
+            }
+            positions[*vertex] = Some(position);
+        }
+        let mut result = SimpleGraph::with_vertices_capacity(vertices.len());
+        for _ in 0..vertices.len() {
+            result.add_vertex();
+        }
+        let mut dangling_edges = vec![];
+        for (position, vertex) in vertices.iter().enumerate() {
+            for neighbor in graph.neighbors_of_vertex(*vertex) {
+                match positions[neighbor] {
+                    Some(neighbor_position) => result.add_edge(position, neighbor_position),
+                    None => dangling_edges.push(position),
+                }
+            }
+        }
+        Multipole::new(result, dangling_edges)
+    }
+
+    pub fn graph(&self) -> &SimpleGraph {
+        &self.graph
+    }
+
+    pub fn dangling_edges(&self) -> &Vec<usize> {
+        &self.dangling_edges
+    }
+
+    pub fn size(&self) -> usize {
+        self.graph.size()
+    }
+
+    ///
+    /// disjoint union - vertices and dangling edges of other multipole follow those of self
+    ///
+    pub fn union(&self, other: &Multipole) -> Multipole {
+        let offset = self.size();
+        let mut graph = self.graph.clone();
+        for _ in 0..other.size() {
+            graph.add_vertex();
+        }
+        for edge in other.graph.edges() {
+            graph.add_edge(edge.from() + offset, edge.to() + offset);
+        }
+        let mut dangling_edges = self.dangling_edges.clone();
+        dangling_edges.extend(other.dangling_edges.iter().map(|vertex| vertex + offset));
+        Multipole {
+            graph,
+            dangling_edges,
+        }
+    }
+
+    ///
+    /// joins pairs of dangling edges of other multipole with dangling edges of self (given by
+    /// their indices), remaining dangling edges of self are followed by those of other
+    ///
+    pub fn join(&self, other: &Multipole, pairs: &[(usize, usize)]) -> Result<Multipole> {
+        let offset = self.dangling_edges.len();
+        let mut result = self.union(other);
+        let pairs: Vec<(usize, usize)> = pairs
+            .iter()
+            .map(|(first, second)| (*first, second + offset))
+            .collect();
+        if let Some(pair) = pairs.iter().find(|pair| pair.0 >= offset) {
+            return Err(Self::unknown_dangling_edge(pair.0));
+        }
+        result.join_dangling_edges(&pairs)?;
+        Ok(result)
+    }
+
+    ///
+    /// joins each pair of dangling edges (given by their indices) into edge of graph, remaining
+    /// dangling edges keep their order
+    ///
+    pub fn join_dangling_edges(&mut self, pairs: &[(usize, usize)]) -> Result<()> {
+        let mut joined = vec![false; self.dangling_edges.len()];
+        // multipole stays unchanged if any pair cannot be joined
+        let mut graph = self.graph.clone();
+        for (first, second) in pairs.iter() {
+            for index in [*first, *second].iter() {
+                if *index >= joined.len() {
+                    return Err(Self::unknown_dangling_edge(*index));
+                }
+                if joined[*index] {
+                    return Err(ConstructionError::new(format!(
+                        "dangling edge {} of multipole joined more than once",
+                        index
+                    )));
+                }
+                joined[*index] = true;
+            }
+            let from = self.dangling_edges[*first];
+            let to = self.dangling_edges[*second];
+            // joined graph has to stay simple
+            if from == to || graph.has_edge(from, to) {
+                return Err(ConstructionError::new(format!(
+                    "joining dangling edges {} and {} would create loop or parallel edge",
+                    first, second
+                )));
+            }
+            graph.add_edge(from, to);
+        }
+        self.graph = graph;
+        let mut index = 0;
+        self.dangling_edges.retain(|_| {
+            index += 1;
+            !joined[index - 1]
+        });
+        Ok(())
+    }
+
+    ///
+    /// graph of multipole without dangling edges
+    ///
+    pub fn into_graph(self) -> Result<SimpleGraph> {
+        if !self.dangling_edges.is_empty() {
+            return Err(ConstructionError::new(format!(
+                "multipole has {} dangling edges left",
+                self.dangling_edges.len()
+            )));
+        }
+        Ok(self.graph)
+    }
+
+    fn unknown_dangling_edge(index: usize) -> ConstructionError {
+        ConstructionError::new(format!("multipole has no dangling edge {}", index))
+    }
+}
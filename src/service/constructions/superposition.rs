@@ -0,0 +1,227 @@
+use crate::graph::edge::Edge;
+use crate::graph::graph::{Graph, GraphConstructor};
+use crate::graph::undirected::simple_graph::graph::SimpleGraph;
+use crate::service::constructions::error::ConstructionError;
+use crate::service::constructions::multipole::{Multipole, Result};
+use std::collections::HashMap;
+
+///
+/// Multipole replacing vertex of graph - i-th connector (group of dangling edges) is joined
+/// towards i-th neighbor of vertex
+///
+#[derive(Debug, Clone)]
+pub struct Supervertex {
+    multipole: Multipole,
+    connectors: Vec<Vec<usize>>,
+}
+
+///
+/// Multipole replacing edge of graph - first connector is joined towards first vertex of edge,
+/// second towards second one
+///
+#[derive(Debug, Clone)]
+pub struct Superedge {
+    multipole: Multipole,
+    connectors: [Vec<usize>; 2],
+}
+
+///
+/// Superposition of (simple) graph - chosen vertices are replaced by supervertices and chosen
+/// edges by superedges, connectors of adjacent parts are joined dangling edge by dangling edge.
+/// Vertices and edges which are not replaced are kept as they are.
+///
+/// if graph is snark and supervertices and superedges are proper (Kochol), result graph will be
+/// snark as well
+///
+pub struct Superposition<'a, G: Graph> {
+    graph: &'a G,
+    supervertices: HashMap<usize, Supervertex>,
+    superedges: HashMap<(usize, usize), Superedge>,
+}
+
+impl Supervertex {
+    ///
+    /// connectors have to split all dangling edges of multipole
+    ///
+    pub fn new(multipole: Multipole, connectors: Vec<Vec<usize>>) -> Result<Self> {
+        check_connectors(&multipole, &connectors)?;
+        Ok(Supervertex {
+            multipole,
+            connectors,
+        })
+    }
+}
+
+impl Superedge {
+    ///
+    /// connectors have to split all dangling edges of multipole
+    ///
+    pub fn new(multipole: Multipole, connectors: [Vec<usize>; 2]) -> Result<Self> {
+        check_connectors(&multipole, &connectors)?;
+        Ok(Superedge {
+            multipole,
+            connectors,
+        })
+    }
+
+    fn reversed(self) -> Self {
+        let [first, second] = self.connectors;
+        Superedge {
+            multipole: self.multipole,
+            connectors: [second, first],
+        }
+    }
+}
+
+impl<'a, G: Graph> Superposition<'a, G> {
+    pub fn new(graph: &'a G) -> Self {
+        Superposition {
+            graph,
+            supervertices: HashMap::new(),
+            superedges: HashMap::new(),
+        }
+    }
+
+    pub fn replace_vertex(&mut self, vertex: usize, supervertex: Supervertex) -> Result<()> {
+        if vertex >= self.graph.size() {
+            return Err(ConstructionError::new(format!(
+                "cannot replace vertex {} of graph with {} vertices",
+                vertex,
+                self.graph.size()
+            )));
+        }
+        let degree = self.graph.neighbors_of_vertex(vertex).len();
+        if supervertex.connectors.len() != degree {
+            return Err(ConstructionError::new(format!(
+                "supervertex with {} connectors cannot replace vertex {} of degree {}",
+                supervertex.connectors.len(),
+                vertex,
+                degree
+            )));
+        }
+        self.supervertices.insert(vertex, supervertex);
+        Ok(())
+    }
+
+    ///
+    /// first connector of superedge is joined towards vertex from, second towards vertex to
+    ///
+    pub fn replace_edge(&mut self, from: usize, to: usize, superedge: Superedge) -> Result<()> {
+        if !self.graph.has_edge(from, to) {
+            return Err(ConstructionError::new(format!(
+                "cannot replace edge ({}, {}) which is not in graph",
+                from, to
+            )));
+        }
+        if from < to {
+            self.superedges.insert((from, to), superedge);
+        } else {
+            self.superedges.insert((to, from), superedge.reversed());
+        }
+        Ok(())
+    }
+
+    pub fn superpose(&self) -> Result<SimpleGraph> {
+        let mut result = Multipole::new(SimpleGraph::new(), vec![])?;
+
+        // connectors of all parts, indexed by dangling edges of result
+        let mut vertex_connectors = Vec::with_capacity(self.graph.size());
+        for vertex in 0..self.graph.size() {
+            let offset = result.dangling_edges().len();
+            let connectors = match self.supervertices.get(&vertex) {
+                Some(supervertex) => {
+                    result = result.union(&supervertex.multipole);
+                    supervertex.connectors.clone()
+                }
+                None => {
+                    let degree = self.graph.neighbors_of_vertex(vertex).len();
+                    result = result.union(&Multipole::vertex(degree));
+                    (0..degree)
+                        .map(|dangling_edge| vec![dangling_edge])
+                        .collect()
+                }
+            };
+            vertex_connectors.push(shift_connectors(&connectors, offset));
+        }
+
+        let mut pairs = vec![];
+        for edge in self.graph.edges() {
+            let from = self.connector_towards(&vertex_connectors, edge.from(), edge.to())?;
+            let to = self.connector_towards(&vertex_connectors, edge.to(), edge.from())?;
+            match self.superedges.get(&(edge.from(), edge.to())) {
+                Some(superedge) => {
+                    let offset = result.dangling_edges().len();
+                    result = result.union(&superedge.multipole);
+                    let connectors = shift_connectors(&superedge.connectors, offset);
+                    pairs.extend(connector_pairs(from, &connectors[0])?);
+                    pairs.extend(connector_pairs(&connectors[1], to)?);
+                }
+                None => pairs.extend(connector_pairs(from, to)?),
+            }
+        }
+        result.join_dangling_edges(&pairs)?;
+        result.into_graph()
+    }
+
+    fn connector_towards<'c>(
+        &self,
+        vertex_connectors: &'c [Vec<Vec<usize>>],
+        vertex: usize,
+        neighbor: usize,
+    ) -> Result<&'c Vec<usize>> {
+        let position = self
+            .graph
+            .neighbors_of_vertex(vertex)
+            .iter()
+            .position(|other| *other == neighbor);
+        match position {
+            Some(position) => Ok(&vertex_connectors[vertex][position]),
+            None => Err(ConstructionError::new(format!(
+                "vertex {} is not neighbor of vertex {}",
+                neighbor, vertex
+            ))),
+        }
+    }
+}
+
+fn check_connectors(multipole: &Multipole, connectors: &[Vec<usize>]) -> Result<()> {
+    let mut used = vec![false; multipole.dangling_edges().len()];
+    for dangling_edge in connectors.iter().flatten() {
+        if *dangling_edge >= used.len() || used[*dangling_edge] {
+            return Err(ConstructionError::new(format!(
+                "dangling edge {} is unknown or in more connectors",
+                dangling_edge
+            )));
+        }
+        used[*dangling_edge] = true;
+    }
+    if used.iter().any(|used| !used) {
+        return Err(ConstructionError::new(
+            "each dangling edge has to be in some connector",
+        ));
+    }
+    Ok(())
+}
+
+fn shift_connectors(connectors: &[Vec<usize>], offset: usize) -> Vec<Vec<usize>> {
+    connectors
+        .iter()
+        .map(|connector| {
+            connector
+                .iter()
+                .map(|dangling_edge| dangling_edge + offset)
+                .collect()
+        })
+        .collect()
+}
+
+fn connector_pairs(first: &[usize], second: &[usize]) -> Result<Vec<(usize, usize)>> {
+    if first.len() != second.len() {
+        return Err(ConstructionError::new(format!(
+            "cannot join connectors of sizes {} and {}",
+            first.len(),
+            second.len()
+        )));
+    }
+    Ok(first.iter().cloned().zip(second.iter().cloned()).collect())
+}
@@ -4,11 +4,22 @@ use crate::graph::undirected::edge::UndirectedEdge;
 use crate::graph::undirected::simple_graph::graph::SimpleGraph;
 use crate::service::colour::colouriser::Colouriser;
 use crate::service::colour::recursive::dfs_improved::DFSColourizer;
+use crate::service::constructions::boundary_colouring::{
+    boundary_colourings, colour_closed_substructures, is_colour_closed, is_colour_equivalent,
+};
+use crate::service::constructions::cubic_graphs::{CubicGraphs, CubicGraphsConfig};
 use crate::service::constructions::dot_product::DotProducts;
+use crate::service::constructions::families;
 use crate::service::constructions::i_extension::{i_extension, IExtensions};
+use crate::service::constructions::isomorphism::is_isomorphic;
+use crate::service::constructions::multipole::Multipole;
+use crate::service::constructions::superposition::{Superedge, Superposition, Supervertex};
 use crate::service::constructions::y_extension::{y_extension, YExtensions};
 use crate::service::io::reader_g6::G6Reader;
+use crate::service::property::cyclic_connectivity::cyclic_edge_connectivity;
+use crate::service::symmetry::canonical::canonical_g6_string;
 use crate::tests::test_data::test_data;
+use std::collections::HashSet;
 
 #[test]
 fn dot_product_test() {
@@ -156,8 +167,6 @@ fn two_i_extension_test() {
     // TODO
 }
 
-use crate::service::constructions::isomorphism::is_isomorphic;
-
 #[test]
 fn isomorphism_test() {
     let petersen = test_data::get_petersen_graph();
@@ -170,3 +179,263 @@ fn isomorphism_test() {
     let isomorphic = is_isomorphic(&first, &second);
     assert_eq!(isomorphic, false);
 }
+
+fn is_cubic(graph: &SimpleGraph) -> bool {
+    (0..graph.size()).all(|vertex| graph.neighbors_of_vertex(vertex).len() == 3)
+}
+
+#[test]
+fn multipole_self_join_test() {
+    let petersen = test_data::get_petersen_graph();
+    let mut multipole = Multipole::from_graph_without_edges(&petersen, &[(0, 4)]).unwrap();
+    assert_eq!(multipole.dangling_edges(), &vec![0, 4]);
+
+    multipole.join_dangling_edges(&[(0, 1)]).unwrap();
+    let graph = multipole.into_graph().unwrap();
+    assert_eq!(is_isomorphic(&graph, &petersen), true);
+}
+
+#[test]
+fn multipole_join_test() {
+    let petersen = test_data::get_petersen_graph();
+    let first = Multipole::from_graph_without_vertex(&petersen, 0).unwrap();
+    let second = Multipole::from_graph_without_vertex(&petersen, 9).unwrap();
+    assert_eq!(first.size(), 9);
+    assert_eq!(first.dangling_edges().len(), 3);
+
+    let partial = first.join(&second, &[(0, 0)]).unwrap();
+    assert_eq!(partial.dangling_edges().len(), 4);
+    assert_eq!(partial.clone().into_graph().is_err(), true);
+
+    let joined = first.join(&second, &[(0, 0), (1, 1), (2, 2)]).unwrap();
+    let graph = joined.into_graph().unwrap();
+    assert_eq!(graph.size(), 18);
+    assert_eq!(is_cubic(&graph), true);
+    assert_eq!(DFSColourizer::is_colorable(&graph), false);
+}
+
+#[test]
+fn multipole_join_should_keep_graph_simple() {
+    let mut multipole = Multipole::vertex(2);
+    assert_eq!(multipole.join_dangling_edges(&[(0, 1)]).is_err(), true);
+    assert_eq!(multipole.dangling_edges().len(), 2);
+}
+
+#[test]
+fn superposition_test() {
+    let petersen = test_data::get_petersen_graph();
+    let trivial = Superposition::new(&petersen).superpose().unwrap();
+    assert_eq!(is_isomorphic(&trivial, &petersen), true);
+
+    let mut superposition = Superposition::new(&petersen);
+    let multipole = Multipole::from_graph_without_vertex(&petersen, 0).unwrap();
+    let supervertex = Supervertex::new(multipole, vec![vec![0], vec![1], vec![2]]).unwrap();
+    superposition.replace_vertex(0, supervertex).unwrap();
+    let multipole = Multipole::from_graph_without_edges(&petersen, &[(0, 4)]).unwrap();
+    let superedge = Superedge::new(multipole, [vec![0], vec![1]]).unwrap();
+    let edge = petersen.edges().nth(5).unwrap();
+    superposition
+        .replace_edge(edge.to(), edge.from(), superedge)
+        .unwrap();
+
+    let graph = superposition.superpose().unwrap();
+    assert_eq!(graph.size(), 10 - 1 + 9 + 10);
+    assert_eq!(is_cubic(&graph), true);
+    assert_eq!(DFSColourizer::is_colorable(&graph), false);
+}
+
+#[test]
+fn superposition_should_reject_mismatched_connectors() {
+    let petersen = test_data::get_petersen_graph();
+    let mut superposition = Superposition::new(&petersen);
+    let multipole = Multipole::from_graph_without_edges(&petersen, &[(0, 4), (3, 5)]).unwrap();
+    let superedge = Superedge::new(multipole.clone(), [vec![0, 1], vec![2]]);
+    assert_eq!(superedge.is_err(), true);
+
+    let superedge = Superedge::new(multipole, [vec![0, 1], vec![2, 3]]).unwrap();
+    let edge = petersen.edges().next().unwrap();
+    superposition
+        .replace_edge(edge.from(), edge.to(), superedge)
+        .unwrap();
+    assert_eq!(superposition.superpose().is_err(), true);
+}
+
+#[test]
+fn boundary_colourings_test() {
+    let vertex = Multipole::vertex(3);
+    let colourings: Vec<Vec<u8>> = boundary_colourings(&vertex).into_iter().collect();
+    assert_eq!(colourings, vec![vec![1, 2, 3]]);
+
+    // two adjacent vertices - dangling edges of one vertex have same colours as those of other
+    let petersen = test_data::get_petersen_graph();
+    let edge = Multipole::from_graph_induced(&petersen, &[0, 4]).unwrap();
+    let colourings: Vec<Vec<u8>> = boundary_colourings(&edge).into_iter().collect();
+    assert_eq!(colourings, vec![vec![1, 2, 1, 2], vec![1, 2, 2, 1]]);
+
+    // parity lemma leaves only colouring 1, 1 of dangling edges which does not extend
+    let without_edge = Multipole::from_graph_without_edges(&petersen, &[(0, 4)]).unwrap();
+    assert_eq!(boundary_colourings(&without_edge).is_empty(), true);
+
+    let graph = Multipole::new(petersen.clone(), vec![]).unwrap();
+    assert_eq!(boundary_colourings(&graph).is_empty(), true);
+}
+
+#[test]
+fn colour_equivalence_test() {
+    // triangle is colour-equivalent with single vertex
+    let k4 = G6Reader::<SimpleGraph>::read_graph("C~").unwrap();
+    let triangle = Multipole::from_graph_without_vertex(&k4, 0).unwrap();
+    assert_eq!(is_colour_equivalent(&triangle, &Multipole::vertex(3)), true);
+
+    let petersen = test_data::get_petersen_graph();
+    let without_vertex = Multipole::from_graph_without_vertex(&petersen, 0).unwrap();
+    assert_eq!(
+        is_colour_equivalent(&without_vertex, &Multipole::vertex(3)),
+        false
+    );
+}
+
+#[test]
+fn colour_closed_substructures_test() {
+    let petersen = test_data::get_petersen_graph();
+    let cycle = Multipole::from_graph_induced(&petersen, &[0, 4, 5, 1, 6]).unwrap();
+    assert_eq!(is_colour_closed(&Multipole::vertex(3)), true);
+    assert_eq!(is_colour_closed(&cycle), false);
+
+    let vertex_sets = vec![vec![0], vec![0, 4], vec![0, 4, 5, 1, 6]];
+    let closed = colour_closed_substructures(&petersen, &vertex_sets);
+    // dangling edges of one end of edge cannot have same colour
+    assert_eq!(closed, vec![vec![0]]);
+}
+
+#[test]
+fn graph_families_test() {
+    let petersen: SimpleGraph = families::generalized_petersen(5, 2).unwrap();
+    assert_eq!(
+        is_isomorphic(&petersen, &test_data::get_petersen_graph()),
+        true
+    );
+
+    let colourable: Vec<SimpleGraph> = vec![
+        families::prism(5).unwrap(),
+        families::mobius_ladder(4).unwrap(),
+        families::generalized_petersen(8, 3).unwrap(),
+    ];
+    for graph in colourable.iter() {
+        assert_eq!(is_cubic(graph), true);
+        assert_eq!(DFSColourizer::is_colorable(graph), true);
+    }
+
+    let snarks: Vec<(SimpleGraph, usize)> = vec![
+        (families::flower_snark(5).unwrap(), 20),
+        (families::flower_snark(7).unwrap(), 28),
+        (families::goldberg_snark(5).unwrap(), 40),
+        (families::blanusa_snark(1).unwrap(), 18),
+        (families::blanusa_snark(2).unwrap(), 18),
+        (families::loupekine_snark(1).unwrap(), 22),
+        (families::loupekine_snark(2).unwrap(), 22),
+        (families::szekeres_snark(1).unwrap(), 50),
+        (families::dot_product_chain(3).unwrap(), 26),
+    ];
+    for (graph, size) in snarks.iter() {
+        assert_eq!(graph.size(), *size);
+        assert_eq!(is_cubic(graph), true);
+        assert_eq!(DFSColourizer::is_colorable(graph), false);
+    }
+    assert_eq!(is_isomorphic(&snarks[3].0, &snarks[4].0), false);
+    assert_eq!(is_isomorphic(&snarks[5].0, &snarks[6].0), false);
+
+    assert_eq!(
+        families::generalized_petersen::<SimpleGraph>(6, 3).is_err(),
+        true
+    );
+    assert_eq!(families::blanusa_snark::<SimpleGraph>(3).is_err(), true);
+    assert_eq!(families::szekeres_snark::<SimpleGraph>(2).is_err(), true);
+}
+
+fn cubic_graphs(vertices: usize, config: CubicGraphsConfig) -> Vec<SimpleGraph> {
+    CubicGraphs::new(vertices, config).unwrap().all()
+}
+
+#[test]
+fn cubic_graphs_count_test() {
+    let counts: Vec<usize> = [4, 6, 8, 10, 12]
+        .iter()
+        .map(|vertices| cubic_graphs(*vertices, CubicGraphsConfig::all()).len())
+        .collect();
+    assert_eq!(counts, vec![1, 2, 5, 19, 85]);
+
+    let graphs = cubic_graphs(12, CubicGraphsConfig::all());
+    let canonical: HashSet<String> = graphs.iter().map(canonical_g6_string).collect();
+    assert_eq!(canonical.len(), graphs.len());
+    for graph in graphs.iter() {
+        assert_eq!(is_cubic(graph), true);
+    }
+}
+
+#[test]
+fn cubic_graphs_pruning_test() {
+    let mut config = CubicGraphsConfig::all();
+    config.girth = 4;
+    assert_eq!(cubic_graphs(10, config.clone()).len(), 6);
+    config.girth = 5;
+    assert_eq!(cubic_graphs(12, config.clone()).len(), 2);
+    assert_eq!(cubic_graphs(14, config.clone()).len(), 9);
+
+    config.cyclic_connectivity = Some(4);
+    config.colourable = Some(false);
+    let snarks = cubic_graphs(10, config.clone());
+    assert_eq!(snarks.len(), 1);
+    assert_eq!(
+        is_isomorphic(&snarks[0], &test_data::get_petersen_graph()),
+        true
+    );
+    assert_eq!(cubic_graphs(18, config).len(), 2);
+}
+
+#[test]
+fn cubic_graphs_cyclic_connectivity_pruning_test() {
+    let all = cubic_graphs(14, CubicGraphsConfig::all());
+    for cyclic_connectivity in 2..=4 {
+        let mut expected: Vec<String> = all
+            .iter()
+            .filter(|graph| {
+                cyclic_edge_connectivity(*graph)
+                    .map(|value| value >= cyclic_connectivity)
+                    .unwrap_or(false)
+            })
+            .map(canonical_g6_string)
+            .collect();
+        let mut config = CubicGraphsConfig::all();
+        config.cyclic_connectivity = Some(cyclic_connectivity);
+        let mut pruned: Vec<String> = cubic_graphs(14, config)
+            .iter()
+            .map(canonical_g6_string)
+            .collect();
+        expected.sort();
+        pruned.sort();
+        assert_eq!(pruned, expected);
+    }
+}
+
+#[test]
+fn cubic_graphs_shard_test() {
+    let all: HashSet<String> = cubic_graphs(14, CubicGraphsConfig::all())
+        .iter()
+        .map(canonical_g6_string)
+        .collect();
+    assert_eq!(all.len(), 509);
+
+    let mut union = HashSet::new();
+    let mut count = 0;
+    for res in 0..3 {
+        let mut config = CubicGraphsConfig::all();
+        config.shard = Some((res, 3));
+        let shard = cubic_graphs(14, config);
+        assert_eq!(shard.is_empty(), false);
+        count += shard.len();
+        union.extend(shard.iter().map(canonical_g6_string));
+    }
+    assert_eq!(count, 509);
+    assert_eq!(union, all);
+}
@@ -52,11 +52,7 @@ impl<'a, G: Graph<E = UndirectedEdge> + Clone, C: Colouriser> Iterator for YExte
     type Item = G;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if let Some(triplet) = self.removable_edge_triplets.next() {
-            let graph = y_extension(self.graph, triplet.0, triplet.1, triplet.2);
-            return Some(graph);
-        }
-        None
+        self.next_with_edges().map(|extension| extension.0)
     }
 }
 
@@ -68,4 +64,16 @@ impl<'a, G: Graph<E = UndirectedEdge> + Clone, C: Colouriser> YExtensions<'a, G,
             removable_edge_triplets: RemovableTripletsOfEdges::new(&graph, &colouriser),
         }
     }
+
+    ///
+    /// next Y-extension along with triplet of edges of graph it was constructed from
+    ///
+    pub fn next_with_edges(&mut self) -> Option<(G, [UndirectedEdge; 3])> {
+        let triplet = self.removable_edge_triplets.next()?;
+        let graph = y_extension(self.graph, triplet.0, triplet.1, triplet.2);
+        Some((
+            graph,
+            [triplet.0.clone(), triplet.1.clone(), triplet.2.clone()],
+        ))
+    }
 }
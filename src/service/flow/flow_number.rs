@@ -0,0 +1,116 @@
+use crate::graph::graph::Graph;
+use crate::service::flow::nowhere_zero::FlowSolver;
+
+///
+/// every bridgeless graph has nowhere-zero 6-flow (Seymour)
+///
+pub const MAX_FLOW_NUMBER: usize = 6;
+
+///
+/// denominators of fractions tried when bounding circular flow number
+///
+pub const CIRCULAR_FLOW_MAX_DENOMINATOR: usize = 3;
+
+///
+/// smallest k such that graph has nowhere-zero k-flow, None if graph has no nowhere-zero flow
+/// (graph with bridge)
+///
+pub fn flow_number<G: Graph>(graph: &G) -> Option<usize> {
+    (2..=MAX_FLOW_NUMBER).find(|k| FlowSolver::has_integer_flow(graph, *k))
+}
+
+///
+/// Bounds of circular flow number - lower < circular flow number <= upper, circular flow number
+/// equals upper if bounds are equal. Fractions are kept as (numerator, denominator).
+///
+#[derive(Debug, Clone, PartialEq)]
+pub struct CircularFlowBounds {
+    lower: (usize, usize),
+    upper: (usize, usize),
+}
+
+impl CircularFlowBounds {
+    ///
+    /// bounds of graph with nowhere-zero flow, None for graph without it (graph with bridge)
+    ///
+    pub fn of_graph<G: Graph>(graph: &G, max_denominator: usize) -> Option<Self> {
+        let k = flow_number(graph)?;
+        Some(Self::with_flow_number(graph, k, max_denominator))
+    }
+
+    ///
+    /// Circular flow number lies in (k - 1, k] for flow number k. Bounds are narrowed by
+    /// circular p/q-flows for q up to max_denominator - existence of circular r-flow implies
+    /// existence of circular s-flow for every s > r, so fractions can be binary searched.
+    ///
+    pub fn with_flow_number<G: Graph>(graph: &G, k: usize, max_denominator: usize) -> Self {
+        if k == 2 {
+            // circular flow number of graph with edges is at least 2
+            return CircularFlowBounds {
+                lower: (2, 1),
+                upper: (2, 1),
+            };
+        }
+
+        let mut fractions = vec![];
+        for q in 2..=max_denominator {
+            for p in ((k - 1) * q + 1)..(k * q) {
+                if gcd(p, q) == 1 {
+                    fractions.push((p, q));
+                }
+            }
+        }
+        fractions.sort_by(|first, second| (first.0 * second.1).cmp(&(second.0 * first.1)));
+
+        let mut bounds = CircularFlowBounds {
+            lower: (k - 1, 1),
+            upper: (k, 1),
+        };
+        let (mut low, mut high) = (0, fractions.len());
+        while low < high {
+            let middle = (low + high) / 2;
+            let (p, q) = fractions[middle];
+            if FlowSolver::circular_flow(graph, p, q).is_some() {
+                bounds.upper = (p, q);
+                high = middle;
+            } else {
+                bounds.lower = (p, q);
+                low = middle + 1;
+            }
+        }
+        bounds
+    }
+
+    pub fn lower(&self) -> (usize, usize) {
+        self.lower
+    }
+
+    pub fn upper(&self) -> (usize, usize) {
+        self.upper
+    }
+
+    ///
+    /// bounds determine circular flow number (equal to both of them) only if they are equal,
+    /// otherwise it is only known to lie in (lower, upper]
+    ///
+    pub fn is_exact(&self) -> bool {
+        self.lower == self.upper
+    }
+
+    ///
+    /// bounds as {"lower-bound": "p/q", "upper-bound": "p/q"}
+    ///
+    pub fn to_json_value(&self) -> serde_json::Value {
+        serde_json::json!({
+            "lower-bound": format!("{}/{}", self.lower.0, self.lower.1),
+            "upper-bound": format!("{}/{}", self.upper.0, self.upper.1),
+        })
+    }
+}
+
+fn gcd(first: usize, second: usize) -> usize {
+    if second == 0 {
+        return first;
+    }
+    gcd(second, first % second)
+}
@@ -0,0 +1,5 @@
+pub mod flow_number;
+pub mod nowhere_zero;
+
+#[cfg(test)]
+mod tests;
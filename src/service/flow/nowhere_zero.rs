@@ -0,0 +1,260 @@
+use crate::graph::directed::simple_graph::graph::SimpleDirectedGraph;
+use crate::graph::edge::Edge;
+use crate::graph::graph::{Graph, GraphConstructor};
+use crate::graph::vertex::Vertex;
+use std::collections::{HashMap, HashSet};
+use std::iter::FromIterator;
+use varisat::{CnfFormula, ExtendFormula, Lit, Solver};
+
+///
+/// Nowhere-zero flow of graph - orientation of graph and positive value of each oriented edge.
+/// For each vertex, sum of values of outgoing edges equals sum of values of incoming edges
+/// (modulo modulus for group flows).
+///
+#[derive(Debug, Clone)]
+pub struct NowhereZeroFlow {
+    // parallel edges oriented the same way are present only once
+    orientation: SimpleDirectedGraph,
+    // oriented edge and its value by position of edge in graph.edges(), so each of parallel
+    // edges has its own value
+    values: Vec<((usize, usize), usize)>,
+    modulus: Option<usize>,
+}
+
+impl NowhereZeroFlow {
+    pub fn orientation(&self) -> &SimpleDirectedGraph {
+        &self.orientation
+    }
+
+    ///
+    /// value of (first of parallel) edges oriented from -> to
+    ///
+    pub fn value(&self, from: usize, to: usize) -> Option<usize> {
+        self.values
+            .iter()
+            .find(|(edge, _value)| *edge == (from, to))
+            .map(|(_edge, value)| *value)
+    }
+
+    ///
+    /// oriented edge and its value, position is position of edge in graph.edges()
+    ///
+    pub fn value_at(&self, position: usize) -> Option<((usize, usize), usize)> {
+        self.values.get(position).copied()
+    }
+
+    ///
+    /// Some(k) for Z_k flow, None for integer flow
+    ///
+    pub fn modulus(&self) -> Option<usize> {
+        self.modulus
+    }
+
+    ///
+    /// every edge has non-zero value and flow is conserved in every vertex
+    ///
+    pub fn is_valid(&self) -> bool {
+        let mut excess: HashMap<usize, i64> = HashMap::new();
+        for ((from, to), value) in self.values.iter() {
+            let value = *value as i64;
+            let non_zero = match self.modulus {
+                Some(modulus) => value.rem_euclid(modulus as i64) != 0,
+                None => value != 0,
+            };
+            if !non_zero {
+                return false;
+            }
+            *excess.entry(*from).or_insert(0) += value;
+            *excess.entry(*to).or_insert(0) -= value;
+        }
+        excess.values().all(|excess| match self.modulus {
+            Some(modulus) => excess.rem_euclid(modulus as i64) == 0,
+            None => *excess == 0,
+        })
+    }
+
+    ///
+    /// flow as list of [from, to, value] triples
+    ///
+    pub fn to_json_value(&self) -> serde_json::Value {
+        let mut edges = self.values.clone();
+        edges.sort();
+        let edges = edges
+            .iter()
+            .map(|(edge, value)| serde_json::json!([edge.0, edge.1, value]))
+            .collect();
+        serde_json::Value::Array(edges)
+    }
+}
+
+///
+/// Decides existence of nowhere-zero flows by SAT solver. Each edge gets one of allowed values
+/// and flow conservation in each vertex is encoded by partial sums over edges of vertex.
+///
+pub struct FlowSolver {}
+
+impl FlowSolver {
+    ///
+    /// nowhere-zero Z_k flow - values 1..k-1, conservation modulo k
+    ///
+    pub fn group_flow<G: Graph>(graph: &G, k: usize) -> Option<NowhereZeroFlow> {
+        if k < 2 {
+            return None;
+        }
+        let values: Vec<i64> = (1..k as i64).collect();
+        Self::find_flow(graph, &values, Some(k))
+    }
+
+    ///
+    /// nowhere-zero integer k-flow - values -(k-1)..-1 and 1..k-1
+    ///
+    pub fn integer_flow<G: Graph>(graph: &G, k: usize) -> Option<NowhereZeroFlow> {
+        Self::values_in_range(graph, 1, k as i64 - 1)
+    }
+
+    ///
+    /// circular p/q-flow - exists iff graph has integer flow with absolute values in q..p-q
+    ///
+    pub fn circular_flow<G: Graph>(graph: &G, p: usize, q: usize) -> Option<NowhereZeroFlow> {
+        if q == 0 {
+            return None;
+        }
+        Self::values_in_range(graph, q as i64, p as i64 - q as i64)
+    }
+
+    pub fn has_group_flow<G: Graph>(graph: &G, k: usize) -> bool {
+        Self::group_flow(graph, k).is_some()
+    }
+
+    pub fn has_integer_flow<G: Graph>(graph: &G, k: usize) -> bool {
+        Self::integer_flow(graph, k).is_some()
+    }
+
+    fn values_in_range<G: Graph>(graph: &G, min: i64, max: i64) -> Option<NowhereZeroFlow> {
+        if min < 1 || max < min {
+            return None;
+        }
+        let values: Vec<i64> = (min..=max).flat_map(|value| vec![-value, value]).collect();
+        Self::find_flow(graph, &values, None)
+    }
+
+    fn find_flow<G: Graph>(
+        graph: &G,
+        values: &[i64],
+        modulus: Option<usize>,
+    ) -> Option<NowhereZeroFlow> {
+        let (formula, edges, edge_lits) = Self::flow_to_cnf(graph, values, modulus);
+        let mut solver = Solver::new();
+        solver.add_formula(&formula);
+        if !solver.solve().unwrap() {
+            return None;
+        }
+        let model: HashSet<Lit> = HashSet::from_iter(solver.model().unwrap());
+
+        let mut orientation = SimpleDirectedGraph::with_vertices_capacity(graph.size());
+        for vertex in graph.vertices() {
+            orientation.add_vertex_with_index(vertex.index());
+        }
+        let mut flow_values = Vec::with_capacity(edges.len());
+        for (edge, lits) in edges.iter().zip(edge_lits.iter()) {
+            let position = lits.iter().position(|lit| model.contains(lit)).unwrap();
+            let value = values[position];
+            // integer flows are reoriented so that every value is positive
+            let (from, to, value) = if value < 0 {
+                (edge.1, edge.0, -value)
+            } else {
+                (edge.0, edge.1, value)
+            };
+            orientation.add_edge(from, to);
+            flow_values.push(((from, to), value as usize));
+        }
+        Some(NowhereZeroFlow {
+            orientation,
+            values: flow_values,
+            modulus,
+        })
+    }
+
+    ///
+    /// edges are oriented from smaller to bigger vertex, edge_lits[i][j] - edge i has value
+    /// values[j]
+    ///
+    fn flow_to_cnf<G: Graph>(
+        graph: &G,
+        values: &[i64],
+        modulus: Option<usize>,
+    ) -> (CnfFormula, Vec<(usize, usize)>, Vec<Vec<Lit>>) {
+        let mut formula = CnfFormula::new();
+        let mut edges = vec![];
+        let mut edge_lits: Vec<Vec<Lit>> = vec![];
+        let mut edges_of_vertex: HashMap<usize, Vec<(usize, i64)>> = HashMap::new();
+        for edge in graph.edges() {
+            let (from, to) = if edge.from() < edge.to() {
+                (edge.from(), edge.to())
+            } else {
+                (edge.to(), edge.from())
+            };
+            let position = edges.len();
+            edges.push((from, to));
+            edges_of_vertex.entry(from).or_default().push((position, 1));
+            edges_of_vertex.entry(to).or_default().push((position, -1));
+
+            // every edge has exactly one value
+            let lits: Vec<Lit> = (0..values.len()).map(|_| formula.new_lit()).collect();
+            formula.add_clause(&lits);
+            for first in 0..lits.len() {
+                for second in (first + 1)..lits.len() {
+                    formula.add_clause(&[!lits[first], !lits[second]]);
+                }
+            }
+            edge_lits.push(lits);
+        }
+
+        let max_value = values.iter().map(|value| value.abs()).max().unwrap_or(0);
+        let mut vertices: Vec<&usize> = edges_of_vertex.keys().collect();
+        vertices.sort();
+        for vertex in vertices {
+            let vertex_edges = &edges_of_vertex[vertex];
+            let degree = vertex_edges.len() as i64;
+            // sums[value] - literal of partial sum of values of first i edges of vertex
+            let mut sums: HashMap<i64, Lit> = HashMap::new();
+            for (index, (position, sign)) in vertex_edges.iter().enumerate() {
+                let index = index as i64;
+                // partial sum has to be reachable back to zero by remaining edges
+                let domain: Vec<i64> = match modulus {
+                    Some(modulus) if index + 1 < degree => (0..modulus as i64).collect(),
+                    None if index + 1 < degree => {
+                        let bound = (index + 1).min(degree - index - 1) * max_value;
+                        (-bound..=bound).collect()
+                    }
+                    _ => vec![0],
+                };
+                let next_sums: HashMap<i64, Lit> =
+                    domain.iter().map(|sum| (*sum, formula.new_lit())).collect();
+                let previous: Vec<(i64, Option<Lit>)> = if index == 0 {
+                    vec![(0, None)]
+                } else {
+                    sums.iter().map(|(sum, lit)| (*sum, Some(*lit))).collect()
+                };
+                for (sum, sum_lit) in previous.iter() {
+                    for (value_index, value) in values.iter().enumerate() {
+                        let mut next = sum + sign * value;
+                        if let Some(modulus) = modulus {
+                            next = next.rem_euclid(modulus as i64);
+                        }
+                        let mut clause = vec![!edge_lits[*position][value_index]];
+                        if let Some(sum_lit) = sum_lit {
+                            clause.push(!*sum_lit);
+                        }
+                        if let Some(next_lit) = next_sums.get(&next) {
+                            clause.push(*next_lit);
+                        }
+                        formula.add_clause(&clause);
+                    }
+                }
+                sums = next_sums;
+            }
+        }
+        (formula, edges, edge_lits)
+    }
+}
@@ -0,0 +1,123 @@
+use crate::graph::graph::{Graph, GraphConstructor};
+use crate::graph::undirected::multi_graph::graph::MultiGraph;
+use crate::graph::undirected::simple_graph::graph::SimpleGraph;
+use crate::service::flow::flow_number::{flow_number, CircularFlowBounds};
+use crate::service::flow::nowhere_zero::FlowSolver;
+use crate::service::io::reader_g6::G6Reader;
+use crate::tests::test_data::test_data;
+
+fn complete_graph(size: usize) -> SimpleGraph {
+    let mut graph = SimpleGraph::with_capacity(size, size * (size - 1) / 2);
+    for from in 0..size {
+        for to in (from + 1)..size {
+            graph.add_edge(from, to);
+        }
+    }
+    graph
+}
+
+fn cube() -> SimpleGraph {
+    let mut cube = SimpleGraph::with_capacity(8, 12);
+    for vertex in 0..8 {
+        for bit in [1, 2, 4].iter() {
+            cube.add_edge(vertex, vertex ^ bit);
+        }
+    }
+    cube
+}
+
+#[test]
+fn should_find_valid_flows() {
+    let graph = G6Reader::<SimpleGraph>::read_graph(test_data::NO_SNARK_IN_G6_18).unwrap();
+    let group_flow = FlowSolver::group_flow(&graph, 4).unwrap();
+    assert_eq!(group_flow.is_valid(), true);
+    assert_eq!(group_flow.modulus(), Some(4));
+    assert_eq!(group_flow.orientation().edges().count(), 27);
+
+    let integer_flow = FlowSolver::integer_flow(&graph, 4).unwrap();
+    assert_eq!(integer_flow.is_valid(), true);
+    assert_eq!(integer_flow.modulus(), None);
+    for edge in integer_flow.to_json_value().as_array().unwrap() {
+        let value = edge[2].as_u64().unwrap();
+        assert_eq!(value >= 1 && value <= 3, true);
+    }
+}
+
+#[test]
+fn should_find_flow_of_parallel_edges() {
+    // three parallel edges between vertices 0 and 1
+    let mut theta = MultiGraph::with_vertices_capacity(2);
+    for _ in 0..3 {
+        theta.add_edge(0, 1);
+    }
+    assert_eq!(FlowSolver::has_integer_flow(&theta, 2), false);
+    let flow = FlowSolver::integer_flow(&theta, 3).unwrap();
+    assert_eq!(flow.is_valid(), true);
+    let values = flow.to_json_value();
+    assert_eq!(values.as_array().unwrap().len(), 3);
+    // each of parallel edges has its own value, flow out of vertex 0 is zero
+    let mut sum = 0;
+    for position in 0..3 {
+        let ((from, _to), value) = flow.value_at(position).unwrap();
+        sum += if from == 0 {
+            value as i64
+        } else {
+            -(value as i64)
+        };
+    }
+    assert_eq!(sum, 0);
+    assert_eq!(flow.value_at(3), None);
+}
+
+#[test]
+fn should_not_find_4_flow_of_snarks() {
+    let petersen = test_data::get_petersen_graph();
+    assert_eq!(FlowSolver::has_group_flow(&petersen, 4), false);
+    assert_eq!(FlowSolver::has_integer_flow(&petersen, 4), false);
+    let flow = FlowSolver::integer_flow(&petersen, 5).unwrap();
+    assert_eq!(flow.is_valid(), true);
+
+    let snark = G6Reader::<SimpleGraph>::read_graph(test_data::SNARK_IN_G6_20).unwrap();
+    assert_eq!(FlowSolver::has_group_flow(&snark, 4), false);
+    assert_eq!(FlowSolver::group_flow(&snark, 5).unwrap().is_valid(), true);
+}
+
+#[test]
+fn should_compute_flow_number() {
+    assert_eq!(flow_number(&test_data::get_petersen_graph()), Some(5));
+    assert_eq!(flow_number(&complete_graph(4)), Some(4));
+    assert_eq!(flow_number(&cube()), Some(3));
+    assert_eq!(flow_number(&complete_graph(5)), Some(2));
+    assert_eq!(FlowSolver::has_group_flow(&cube(), 3), true);
+    assert_eq!(FlowSolver::has_group_flow(&complete_graph(4), 3), false);
+
+    // two triangles joined by bridge
+    let mut graph = complete_graph(3);
+    graph.add_edge(2, 3);
+    graph.add_edge(3, 4);
+    graph.add_edge(4, 5);
+    graph.add_edge(5, 3);
+    assert_eq!(flow_number(&graph), None);
+    assert_eq!(CircularFlowBounds::of_graph(&graph, 3), None);
+}
+
+#[test]
+fn should_bound_circular_flow_number() {
+    let bounds = CircularFlowBounds::of_graph(&test_data::get_petersen_graph(), 3).unwrap();
+    assert_eq!(bounds.upper(), (5, 1));
+    assert_eq!(bounds.lower(), (14, 3));
+    assert_eq!(
+        bounds.to_json_value(),
+        serde_json::json!({"lower-bound": "14/3", "upper-bound": "5/1"})
+    );
+
+    let graph = test_data::get_petersen_graph();
+    assert_eq!(CircularFlowBounds::with_flow_number(&graph, 5, 3), bounds);
+
+    let bounds = CircularFlowBounds::of_graph(&complete_graph(4), 2).unwrap();
+    assert_eq!(bounds.upper(), (4, 1));
+    assert_eq!(bounds.lower(), (7, 2));
+
+    let bounds = CircularFlowBounds::of_graph(&complete_graph(5), 3).unwrap();
+    assert_eq!(bounds.is_exact(), true);
+}
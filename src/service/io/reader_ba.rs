@@ -128,8 +128,20 @@ where
         let mut graph = G::with_capacity(size, edges);
         for from in 0..size {
             let vec = self.next_numbers_vector()?;
+            // every edge is listed at both of its ends - it is added only once so that parallel
+            // edges of multigraph are not doubled
             for to in vec.iter() {
-                graph.add_edge(from, to.clone());
+                if from == *to {
+                    return Err(ReadError {
+                        message: format!(
+                            "{}: loop at vertex {} is not supported",
+                            WRONG_FORMAT, from
+                        ),
+                    });
+                }
+                if from < *to {
+                    graph.add_edge(from, to.clone());
+                }
             }
         }
         Ok(Some(graph))
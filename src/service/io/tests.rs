@@ -35,6 +35,17 @@ fn should_read_ba() {
     assert_eq!(graph, test_data::get_petersen_graph());
 }
 
+#[test]
+fn should_not_read_ba_with_loop() {
+    let file_path = std::env::temp_dir().join(format!("snark-tool-loop-{}.ba", std::process::id()));
+    std::fs::write(&file_path, "1\n1\n2\n0 1 \n0 \n").unwrap();
+    let file = OpenOptions::new().read(true).open(&file_path).unwrap();
+    let mut reader = BaReader::<SimpleGraph>::new(&file);
+    let result = reader.next().unwrap();
+    std::fs::remove_file(&file_path).unwrap();
+    assert_eq!(result.is_err(), true);
+}
+
 #[test]
 fn should_compare_readers() {
     let g6 = G6Reader::<SimpleGraph>::read_graph(test_data::SNARK_IN_G6_10_PETERSEN).unwrap();
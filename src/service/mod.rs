@@ -25,7 +25,9 @@ pub mod chromatic_properties;
 pub mod colour;
 pub mod component_analysis;
 pub mod constructions;
+pub mod flow;
 pub mod graph_traversal;
 pub mod io;
 pub mod matching;
 pub mod property;
+pub mod symmetry;
@@ -0,0 +1,379 @@
+use crate::graph::edge::Edge;
+use crate::graph::graph::Graph;
+use crate::graph::vertex::Vertex;
+use crate::service::colour::colouring;
+use crate::service::colour::colouring::{EdgeColouring, COLOURS};
+use crate::service::colour::colouriser::Colouriser;
+use crate::service::colour::matchings::matching_col::MatchingColouriser;
+use std::collections::{HashMap, HashSet};
+use std::iter::FromIterator;
+use varisat::{CnfFormula, ExtendFormula, Lit, Solver};
+
+///
+/// bound of number of even subgraphs tried when searching for cycle double cover
+///
+pub const MAX_CDC_SIZE: usize = 7;
+
+///
+/// Cycle double cover - collection of cycles covering each edge of graph exactly twice. Cycles
+/// are grouped into even subgraphs (cycles of one even subgraph are vertex disjoint), k-CDC has
+/// at most k even subgraphs. Each cycle is list of its vertices in order of traversal.
+///
+#[derive(Debug, Clone, PartialEq)]
+pub struct CycleDoubleCover {
+    even_subgraphs: Vec<Vec<Vec<usize>>>,
+}
+
+///
+/// Searches for cycle double covers of graphs of maximum degree 3 (of bridgeless cubic graphs
+/// in particular). 3-CDC of 3-edge-colourable cubic graph is obtained from perfect matching with
+/// complementary 2-factor without odd cycles, other covers are found by SAT solver.
+///
+pub struct CycleDoubleCoverSolver {}
+
+impl CycleDoubleCoverSolver {
+    ///
+    /// CDC with smallest number of even subgraphs up to MAX_CDC_SIZE
+    ///
+    pub fn cdc<G: Graph>(graph: &G) -> Option<CycleDoubleCover> {
+        (3..=MAX_CDC_SIZE).find_map(|size| Self::k_cdc(graph, size))
+    }
+
+    ///
+    /// 5-CDC - conjectured to exist for every bridgeless graph
+    ///
+    pub fn five_cdc<G: Graph>(graph: &G) -> Option<CycleDoubleCover> {
+        Self::k_cdc(graph, 5)
+    }
+
+    ///
+    /// CDC with at most size even subgraphs
+    ///
+    pub fn k_cdc<G: Graph>(graph: &G, size: usize) -> Option<CycleDoubleCover> {
+        if !Self::is_subcubic(graph) {
+            return None;
+        }
+        if size >= 3 {
+            if let Some(cover) = Self::cdc_from_colouring(graph) {
+                return Some(cover);
+            }
+        }
+        Self::cdc_by_sat(graph, size)
+    }
+
+    ///
+    /// None if graph has vertex of degree greater than 3 - such graphs are not searched
+    ///
+    pub fn has_cdc<G: Graph>(graph: &G) -> Option<bool> {
+        if !Self::is_subcubic(graph) {
+            return None;
+        }
+        Some(Self::cdc(graph).is_some())
+    }
+
+    ///
+    /// None if graph has vertex of degree greater than 3 - such graphs are not searched
+    ///
+    pub fn has_five_cdc<G: Graph>(graph: &G) -> Option<bool> {
+        if !Self::is_subcubic(graph) {
+            return None;
+        }
+        Some(Self::five_cdc(graph).is_some())
+    }
+
+    fn is_subcubic<G: Graph>(graph: &G) -> bool {
+        graph
+            .vertices()
+            .all(|vertex| graph.edges_of_vertex(vertex.index()).count() <= 3)
+    }
+
+    ///
+    /// for cubic graph with 3-edge-colouring, unions of pairs of colour classes form 3-CDC -
+    /// colouring which does not tell parallel edges apart is not used
+    ///
+    fn cdc_from_colouring<G: Graph>(graph: &G) -> Option<CycleDoubleCover> {
+        if graph
+            .vertices()
+            .any(|vertex| graph.edges_of_vertex(vertex.index()).count() != 3)
+        {
+            return None;
+        }
+        let colouring: EdgeColouring = MatchingColouriser::colouring(graph)?;
+        if !colouring::is_valid_colouring(graph, &colouring) {
+            return None;
+        }
+        let pairs = [
+            (COLOURS[0], COLOURS[1]),
+            (COLOURS[0], COLOURS[2]),
+            (COLOURS[1], COLOURS[2]),
+        ];
+        let mut even_subgraphs = vec![];
+        for (first, second) in pairs.iter() {
+            let edges: Vec<(usize, usize)> = colouring
+                .edges()
+                .filter(|edge| edge.color() == *first || edge.color() == *second)
+                .map(|edge| (edge.from(), edge.to()))
+                .collect();
+            even_subgraphs.push(Self::cycles_of_even_subgraph(&edges));
+        }
+        Some(CycleDoubleCover { even_subgraphs })
+    }
+
+    fn cdc_by_sat<G: Graph>(graph: &G, size: usize) -> Option<CycleDoubleCover> {
+        let edges: Vec<(usize, usize)> =
+            graph.edges().map(|edge| (edge.from(), edge.to())).collect();
+        let (formula, edge_lits) = Self::cdc_to_cnf(graph, &edges, size);
+        let mut solver = Solver::new();
+        solver.add_formula(&formula);
+        if !solver.solve().unwrap() {
+            return None;
+        }
+        let model: HashSet<Lit> = HashSet::from_iter(solver.model().unwrap());
+
+        let mut even_subgraphs = vec![];
+        for subgraph in 0..size {
+            let subgraph_edges: Vec<(usize, usize)> = edges
+                .iter()
+                .zip(edge_lits.iter())
+                .filter(|(_, lits)| model.contains(&lits[subgraph]))
+                .map(|(edge, _)| *edge)
+                .collect();
+            if !subgraph_edges.is_empty() {
+                even_subgraphs.push(Self::cycles_of_even_subgraph(&subgraph_edges));
+            }
+        }
+        Some(CycleDoubleCover { even_subgraphs })
+    }
+
+    ///
+    /// edge_lits[i][j] - edge i belongs to even subgraph j
+    ///
+    fn cdc_to_cnf<G: Graph>(
+        graph: &G,
+        edges: &[(usize, usize)],
+        size: usize,
+    ) -> (CnfFormula, Vec<Vec<Lit>>) {
+        let mut formula = CnfFormula::new();
+        let mut edge_lits: Vec<Vec<Lit>> = vec![];
+        let mut edges_of_vertex: HashMap<usize, Vec<usize>> = HashMap::new();
+        for (position, (from, to)) in edges.iter().enumerate() {
+            edges_of_vertex.entry(*from).or_default().push(position);
+            edges_of_vertex.entry(*to).or_default().push(position);
+
+            // every edge belongs to exactly two even subgraphs
+            let lits: Vec<Lit> = (0..size).map(|_| formula.new_lit()).collect();
+            for skipped in 0..size {
+                let clause: Vec<Lit> = (0..size)
+                    .filter(|subgraph| *subgraph != skipped)
+                    .map(|subgraph| lits[subgraph])
+                    .collect();
+                formula.add_clause(&clause);
+            }
+            for first in 0..size {
+                for second in (first + 1)..size {
+                    for third in (second + 1)..size {
+                        formula.add_clause(&[!lits[first], !lits[second], !lits[third]]);
+                    }
+                }
+            }
+            edge_lits.push(lits);
+        }
+
+        // every vertex has even degree in every subgraph - odd subsets of edges are forbidden
+        for vertex in graph.vertices() {
+            let vertex_edges = match edges_of_vertex.get(&vertex.index()) {
+                Some(vertex_edges) => vertex_edges,
+                None => continue,
+            };
+            for subset in 0..(1usize << vertex_edges.len()) {
+                if subset.count_ones() % 2 == 0 {
+                    continue;
+                }
+                for subgraph in 0..size {
+                    let clause: Vec<Lit> = vertex_edges
+                        .iter()
+                        .enumerate()
+                        .map(|(index, edge)| {
+                            let lit = edge_lits[*edge][subgraph];
+                            if subset & (1 << index) != 0 {
+                                !lit
+                            } else {
+                                lit
+                            }
+                        })
+                        .collect();
+                    formula.add_clause(&clause);
+                }
+            }
+        }
+        (formula, edge_lits)
+    }
+
+    ///
+    /// splits even subgraph of graph of maximum degree 3 (disjoint union of cycles) to cycles,
+    /// neighbors are kept with multiplicity so pair of parallel edges forms cycle of length 2
+    ///
+    fn cycles_of_even_subgraph(edges: &[(usize, usize)]) -> Vec<Vec<usize>> {
+        let mut neighbors: HashMap<usize, Vec<usize>> = HashMap::new();
+        for (from, to) in edges.iter() {
+            neighbors.entry(*from).or_default().push(*to);
+            neighbors.entry(*to).or_default().push(*from);
+        }
+        let mut starts: Vec<usize> = neighbors.keys().cloned().collect();
+        starts.sort();
+        let mut visited = HashSet::new();
+        let mut cycles = vec![];
+        for start in starts {
+            if visited.contains(&start) {
+                continue;
+            }
+            let cycle = Self::traverse_cycle(&neighbors, start);
+            visited.extend(cycle.iter().cloned());
+            cycles.push(cycle);
+        }
+        cycles
+    }
+
+    ///
+    /// vertices of cycle containing start in order of traversal - next vertex is the other
+    /// neighbor than the one we came from (the same vertex again for parallel edges)
+    ///
+    fn traverse_cycle(neighbors: &HashMap<usize, Vec<usize>>, start: usize) -> Vec<usize> {
+        let mut cycle = vec![start];
+        let mut previous = start;
+        let mut current = neighbors[&start][0];
+        while current != start {
+            cycle.push(current);
+            let current_neighbors = &neighbors[&current];
+            let next = if current_neighbors[0] == previous {
+                current_neighbors[1]
+            } else {
+                current_neighbors[0]
+            };
+            previous = current;
+            current = next;
+        }
+        cycle
+    }
+}
+
+///
+/// TESTS
+///
+#[cfg(test)]
+mod tests {
+    use crate::graph::edge::Edge;
+    use crate::graph::graph::{Graph, GraphConstructor};
+    use crate::graph::undirected::multi_graph::graph::MultiGraph;
+    use crate::graph::undirected::simple_graph::graph::SimpleGraph;
+    use crate::service::io::reader_g6::G6Reader;
+    use crate::service::property::cycle_double_cover::{CycleDoubleCover, CycleDoubleCoverSolver};
+    use crate::tests::test_data::test_data;
+    use std::collections::{HashMap, HashSet};
+
+    ///
+    /// every cycle is cycle of graph, cycles of even subgraph are disjoint and every edge is
+    /// covered exactly twice (cycle of length 2 covers both of parallel edges)
+    ///
+    fn is_valid<G: Graph>(cover: &CycleDoubleCover, graph: &G) -> bool {
+        let mut covered: HashMap<(usize, usize), usize> = HashMap::new();
+        for even_subgraph in cover.even_subgraphs.iter() {
+            let mut used_vertices = HashSet::new();
+            for cycle in even_subgraph.iter() {
+                if cycle.len() < 2 {
+                    return false;
+                }
+                for (position, vertex) in cycle.iter().enumerate() {
+                    if !used_vertices.insert(*vertex) {
+                        return false;
+                    }
+                    let next = cycle[(position + 1) % cycle.len()];
+                    if !graph.has_edge(*vertex, next) {
+                        return false;
+                    }
+                    *covered
+                        .entry((*vertex.min(&next), *vertex.max(&next)))
+                        .or_insert(0) += 1;
+                }
+            }
+        }
+        let mut multiplicities: HashMap<(usize, usize), usize> = HashMap::new();
+        for edge in graph.edges() {
+            *multiplicities.entry((edge.from(), edge.to())).or_insert(0) += 1;
+        }
+        multiplicities.len() == covered.len()
+            && multiplicities
+                .iter()
+                .all(|(edge, multiplicity)| covered.get(edge) == Some(&(2 * multiplicity)))
+    }
+
+    #[test]
+    fn should_find_three_cdc_of_colourable_graph() {
+        let graph: SimpleGraph = G6Reader::read_graph(test_data::NO_SNARK_IN_G6_18).unwrap();
+        let cover = CycleDoubleCoverSolver::cdc(&graph).unwrap();
+        assert_eq!(is_valid(&cover, &graph), true);
+        assert_eq!(cover.even_subgraphs.len(), 3);
+    }
+
+    #[test]
+    fn should_find_five_cdc_of_snarks() {
+        let petersen = test_data::get_petersen_graph();
+        assert_eq!(CycleDoubleCoverSolver::k_cdc(&petersen, 4), None);
+        let cover = CycleDoubleCoverSolver::five_cdc(&petersen).unwrap();
+        assert_eq!(is_valid(&cover, &petersen), true);
+        assert_eq!(cover.even_subgraphs.len(), 5);
+        assert_eq!(CycleDoubleCoverSolver::cdc(&petersen), Some(cover));
+
+        let graph: SimpleGraph =
+            G6Reader::read_graph(test_data::SNARK_IN_G6_26_CRITICAL_1).unwrap();
+        let cover = CycleDoubleCoverSolver::five_cdc(&graph).unwrap();
+        assert_eq!(is_valid(&cover, &graph), true);
+    }
+
+    #[test]
+    fn should_find_cdc_of_graph_with_parallel_edges() {
+        // 4-cycle 0-2-3-1 with doubled edges 0-1 and 2-3
+        let mut graph = MultiGraph::with_capacity(4, 6);
+        graph.add_edge(0, 1);
+        graph.add_edge(0, 1);
+        graph.add_edge(0, 2);
+        graph.add_edge(1, 3);
+        graph.add_edge(2, 3);
+        graph.add_edge(2, 3);
+        let cover = CycleDoubleCoverSolver::cdc(&graph).unwrap();
+        assert_eq!(is_valid(&cover, &graph), true);
+        assert!(cover
+            .even_subgraphs
+            .iter()
+            .any(|even_subgraph| even_subgraph.iter().any(|cycle| cycle.len() == 2)));
+    }
+
+    #[test]
+    fn should_not_find_cdc_of_graph_with_bridge() {
+        // two triangles joined by bridge 2-3
+        let mut graph = SimpleGraph::with_capacity(6, 7);
+        graph.add_edge(0, 1);
+        graph.add_edge(1, 2);
+        graph.add_edge(2, 0);
+        graph.add_edge(2, 3);
+        graph.add_edge(3, 4);
+        graph.add_edge(4, 5);
+        graph.add_edge(5, 3);
+        assert_eq!(CycleDoubleCoverSolver::has_cdc(&graph), Some(false));
+        assert_eq!(CycleDoubleCoverSolver::has_five_cdc(&graph), Some(false));
+    }
+
+    #[test]
+    fn should_not_decide_cdc_of_graph_with_vertex_of_degree_four() {
+        // two triangles sharing vertex 0 - has cycle double cover, but is not searched
+        let mut graph = SimpleGraph::with_capacity(5, 6);
+        graph.add_edge(0, 1);
+        graph.add_edge(1, 2);
+        graph.add_edge(2, 0);
+        graph.add_edge(0, 3);
+        graph.add_edge(3, 4);
+        graph.add_edge(4, 0);
+        assert_eq!(CycleDoubleCoverSolver::has_cdc(&graph), None);
+        assert_eq!(CycleDoubleCoverSolver::has_five_cdc(&graph), None);
+    }
+}
@@ -1,5 +1,3 @@
-mod edge;
 pub mod graph;
-mod vertex;
 
 mod residual_graph_test;
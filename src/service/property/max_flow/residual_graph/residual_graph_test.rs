@@ -1,9 +1,9 @@
 #[cfg(test)]
 mod residual_graph_tests {
-    use crate::graph::edge::EdgeConstructor;
+    use crate::graph::directed::edge::DirectedEdge;
+    use crate::graph::edge::{Edge, EdgeConstructor};
     use crate::graph::graph::{Graph, GraphConstructor};
     use crate::graph::vertex::Vertex;
-    use crate::service::property::max_flow::residual_graph::edge::DirectedFlowEdge;
     use crate::service::property::max_flow::residual_graph::graph::ResidualGraph;
 
     fn get_graph() -> ResidualGraph {
@@ -43,13 +43,16 @@ mod residual_graph_tests {
     fn should_iter_edges() {
         let graph = get_graph();
         let mut edges = vec![];
-        edges.push(DirectedFlowEdge::new_with_capacity(0, 1, 1));
-        edges.push(DirectedFlowEdge::new_with_capacity(2, 0, 1));
-        edges.push(DirectedFlowEdge::new_with_capacity(2, 1, 1));
+        edges.push((DirectedEdge::new(0, 1), 1));
+        edges.push((DirectedEdge::new(2, 0), 1));
+        edges.push((DirectedEdge::new(2, 1), 1));
 
         let mut index = 0;
         for edge in graph.edges() {
-            edges.retain(|edge_temp| edge_temp != edge);
+            let capacity = graph.edge_capacity(edge.from(), edge.to());
+            edges.retain(|(edge_temp, capacity_temp)| {
+                edge_temp != edge || *capacity_temp != capacity
+            });
             index += 1;
         }
         assert_eq!(index, 3);
@@ -60,8 +63,8 @@ mod residual_graph_tests {
     fn should_iter_edges_of_vertex() {
         let graph = get_graph();
         let mut edges = vec![];
-        edges.push(DirectedFlowEdge::new(0, 1));
-        edges.push(DirectedFlowEdge::new(1, 2));
+        edges.push(DirectedEdge::new(0, 1));
+        edges.push(DirectedEdge::new(1, 2));
         let mut index = 0;
         for edge in graph.edges_of_vertex(1) {
             edges.retain(|edge_temp| edge_temp != edge);
@@ -74,13 +77,13 @@ mod residual_graph_tests {
     #[test]
     fn should_remove_edges_of_vertex() {
         let mut graph = get_graph();
-        assert_eq!(graph.vertices[0].edges.len(), 1);
-        assert_eq!(graph.vertices[1].edges.len(), 0);
-        assert_eq!(graph.vertices[2].edges.len(), 2);
+        assert_eq!(graph.edges_of_vertex(0).count(), 1);
+        assert_eq!(graph.edges_of_vertex(1).count(), 0);
+        assert_eq!(graph.edges_of_vertex(2).count(), 2);
         graph.remove_edges_of_vertex(0);
-        assert_eq!(graph.vertices[0].edges.len(), 0);
-        assert_eq!(graph.vertices[1].edges.len(), 0);
-        assert_eq!(graph.vertices[2].edges.len(), 2);
+        assert_eq!(graph.edges_of_vertex(0).count(), 0);
+        assert_eq!(graph.edges_of_vertex(1).count(), 0);
+        assert_eq!(graph.edges_of_vertex(2).count(), 2);
     }
 
     #[test]
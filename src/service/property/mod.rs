@@ -1,5 +1,7 @@
+pub mod cycle_double_cover;
 pub mod cyclic_connectivity;
 pub mod girth;
 pub mod max_flow;
 pub mod oddness;
 pub mod order;
+pub mod perfect_matching_cover;
@@ -1,61 +1,507 @@
-use crate::graph::edge::Edge;
+use crate::graph::edge::{Edge, EdgeConstructor};
 use crate::graph::graph::Graph;
+use crate::graph::undirected::edge::UndirectedEdge;
+use crate::graph::vertex::Vertex;
+use crate::service::colour::colouring::COLOURS;
+use crate::service::colour::colouriser::Colouriser;
 use crate::service::colour::matchings::matching_col::CycleDiscovery;
-use crate::service::matching::perfect_matchings::MatchingGraph;
+use crate::service::colour::sat::sat::SATColourizer;
+use crate::service::matching::perfect_matchings::{Matching, MatchingGraph};
+use std::collections::{HashMap, VecDeque};
+
+///
+/// 2-factor with minimal number of odd cycles - given by perfect matching (complement of
+/// 2-factor) and odd cycles of 2-factor
+///
+#[derive(Debug, Clone, PartialEq)]
+pub struct OddnessWitness {
+    pub matching: Matching,
+    pub odd_cycles: Vec<Vec<usize>>,
+}
+
+impl OddnessWitness {
+    pub fn oddness(&self) -> usize {
+        self.odd_cycles.len()
+    }
+
+    pub fn to_json_value(&self) -> serde_json::Value {
+        serde_json::json!({
+            "perfect-matching": edges_to_json_value(&self.matching.edges),
+            "odd-cycles": self.odd_cycles,
+        })
+    }
+}
+
+///
+/// even factor (spanning subgraph with vertices of degree 0 or 2) with minimal number of odd
+/// components - isolated vertices are odd components too
+///
+#[derive(Debug, Clone, PartialEq)]
+pub struct WeakOddnessWitness {
+    pub even_factor: Vec<UndirectedEdge>,
+    pub odd_components: Vec<Vec<usize>>,
+}
+
+impl WeakOddnessWitness {
+    pub fn weak_oddness(&self) -> usize {
+        self.odd_components.len()
+    }
+
+    pub fn to_json_value(&self) -> serde_json::Value {
+        serde_json::json!({
+            "even-factor": edges_to_json_value(&self.even_factor),
+            "odd-components": self.odd_components,
+        })
+    }
+}
 
 pub struct Oddness {}
 
 impl Oddness {
+    ///
+    /// usize::max_value() if graph has no perfect matching
+    ///
+    #[allow(dead_code)]
     pub fn of_graph<G: Graph>(graph: &G) -> usize {
+        match Self::witness(graph) {
+            Some(witness) => witness.oddness(),
+            None => usize::max_value(),
+        }
+    }
+
+    ///
+    /// None if graph has no perfect matching. 2-factors of cubic graphs are searched by branch
+    /// and bound which stops at first 2-factor with 2 odd cycles (for graphs which are not
+    /// 3-edge-colourable), perfect matchings of other graphs are enumerated.
+    ///
+    pub fn witness<G: Graph>(graph: &G) -> Option<OddnessWitness> {
+        if !is_cubic(graph) {
+            return Self::witness_by_perfect_matchings(graph);
+        }
+        if let Some(colouring) = SATColourizer::colouring(graph) {
+            let matching = Matching {
+                edges: colouring
+                    .edges()
+                    .filter(|edge| edge.color() == COLOURS[0])
+                    .cloned()
+                    .collect(),
+            };
+            return Some(OddnessWitness {
+                matching,
+                odd_cycles: vec![],
+            });
+        }
+
+        let (factor, odd_components) = FactorSearch::new(graph, false).minimal_factor(2)?;
+        // each factor edge stands for one of parallel edges
+        let mut remaining = factor;
+        let mut matching = Matching::new();
+        for edge in graph.edges() {
+            let edge = UndirectedEdge::new(edge.from(), edge.to());
+            match remaining
+                .iter()
+                .position(|factor_edge| *factor_edge == edge)
+            {
+                Some(position) => {
+                    remaining.swap_remove(position);
+                }
+                None => matching.edges.push(edge),
+            }
+        }
+        Some(OddnessWitness {
+            matching,
+            odd_cycles: odd_components,
+        })
+    }
+
+    fn witness_by_perfect_matchings<G: Graph>(graph: &G) -> Option<OddnessWitness> {
         let mut match_graph = MatchingGraph::from_graph(graph);
         let perfect_matchings = match_graph.perfect_matchings();
-        let mut oddness = usize::max_value();
+        let mut witness: Option<OddnessWitness> = None;
         for perfect_matching in perfect_matchings {
             // remove matching from graph
             for edge in perfect_matching.edges.iter() {
                 match_graph.remove_edge(edge.from(), edge.to());
             }
 
-            let odd_cycles_num = Self::num_of_odd_cycles(&match_graph);
-            if odd_cycles_num < oddness {
-                oddness = odd_cycles_num;
-            }
+            let odd_cycles = Self::odd_cycles(&match_graph);
+            let better = match witness {
+                Some(ref witness) => odd_cycles.len() < witness.oddness(),
+                None => true,
+            };
 
             // recover removed edges
             for edge in perfect_matching.edges.iter() {
                 match_graph.add_edge(edge.from(), edge.to());
             }
+            if better {
+                witness = Some(OddnessWitness {
+                    matching: perfect_matching,
+                    odd_cycles,
+                });
+            }
         }
-        oddness
+        witness
     }
 
-    fn num_of_odd_cycles(graph: &MatchingGraph) -> usize {
-        let mut odd_cycles_num = 0;
+    fn odd_cycles(graph: &MatchingGraph) -> Vec<Vec<usize>> {
         let mut cd = CycleDiscovery::new(graph);
-        for cycle in cd.cycles() {
-            if cycle.len() % 2 == 1 {
-                odd_cycles_num += 1;
+        cd.cycles()
+            .into_iter()
+            .filter(|cycle| cycle.len() % 2 == 1)
+            .collect()
+    }
+}
+
+pub struct WeakOddness {}
+
+impl WeakOddness {
+    #[allow(dead_code)]
+    pub fn of_graph<G: Graph>(graph: &G) -> usize {
+        Self::witness(graph).weak_oddness()
+    }
+
+    ///
+    /// even factor always exists (graph without edges has one), search stops at first even
+    /// factor with 2 odd components for cubic graphs which are not 3-edge-colourable
+    ///
+    pub fn witness<G: Graph>(graph: &G) -> WeakOddnessWitness {
+        let lower_bound = if is_cubic(graph) && !SATColourizer::is_colorable(graph) {
+            2
+        } else {
+            0
+        };
+        let (even_factor, odd_components) = FactorSearch::new(graph, true)
+            .minimal_factor(lower_bound)
+            .unwrap();
+        WeakOddnessWitness {
+            even_factor,
+            odd_components,
+        }
+    }
+}
+
+///
+/// Branch and bound search of spanning subgraph with vertices of degree 2 (or 0 if isolated
+/// vertices are allowed) with minimal number of odd components. Vertices are processed in BFS
+/// order, each processed vertex decides which of its edges belong to factor. Components of
+/// already decided vertices are final, so partial factors with at least as many odd final
+/// components as best found factor are pruned. Edges are decided by their index, so each of
+/// parallel edges is decided on its own.
+///
+struct FactorSearch {
+    order: Vec<usize>,
+    edges: Vec<(usize, usize)>,
+    // (neighbor, index of edge) pairs of each vertex
+    neighbors: Vec<Vec<(usize, usize)>>,
+    allow_isolated: bool,
+    decided: HashMap<usize, bool>,
+    best: Option<(usize, Vec<(usize, usize)>)>,
+    lower_bound: usize,
+}
+
+impl FactorSearch {
+    fn new<G: Graph>(graph: &G, allow_isolated: bool) -> Self {
+        let edges: Vec<(usize, usize)> =
+            graph.edges().map(|edge| (edge.from(), edge.to())).collect();
+        let mut neighbors = vec![vec![]; graph.size()];
+        for (index, (from, to)) in edges.iter().enumerate() {
+            neighbors[*from].push((*to, index));
+            neighbors[*to].push((*from, index));
+        }
+
+        let mut order = vec![];
+        let mut visited = vec![false; graph.size()];
+        for vertex in graph.vertices() {
+            if visited[vertex.index()] {
+                continue;
+            }
+            visited[vertex.index()] = true;
+            let mut to_visit = VecDeque::new();
+            to_visit.push_back(vertex.index());
+            while let Some(current) = to_visit.pop_front() {
+                order.push(current);
+                for (neighbor, _) in neighbors[current].iter() {
+                    if !visited[*neighbor] {
+                        visited[*neighbor] = true;
+                        to_visit.push_back(*neighbor);
+                    }
+                }
+            }
+        }
+
+        FactorSearch {
+            order,
+            edges,
+            neighbors,
+            allow_isolated,
+            decided: HashMap::new(),
+            best: None,
+            lower_bound: 0,
+        }
+    }
+
+    ///
+    /// factor edges and odd components of best factor, search stops when factor with
+    /// lower_bound odd components is found
+    ///
+    fn minimal_factor(
+        mut self,
+        lower_bound: usize,
+    ) -> Option<(Vec<UndirectedEdge>, Vec<Vec<usize>>)> {
+        self.lower_bound = lower_bound;
+        self.search(0);
+        let (_, factor) = self.best.take()?;
+        let odd_components = self
+            .components(&factor)
+            .into_iter()
+            .filter(|component| component.len() % 2 == 1)
+            .collect();
+        let factor = factor
+            .iter()
+            .map(|(from, to)| UndirectedEdge::new(*from, *to))
+            .collect();
+        Some((factor, odd_components))
+    }
+
+    ///
+    /// true if search can be stopped
+    ///
+    fn search(&mut self, depth: usize) -> bool {
+        if depth == self.order.len() {
+            let factor: Vec<(usize, usize)> = self
+                .decided
+                .iter()
+                .filter(|(_, in_factor)| **in_factor)
+                .map(|(edge, _)| self.edges[*edge])
+                .collect();
+            let odd_components = self.odd_final_components();
+            self.best = Some((odd_components, factor));
+            return odd_components <= self.lower_bound;
+        }
+
+        let vertex = self.order[depth];
+        let mut degree = 0;
+        let mut undecided = vec![];
+        for (neighbor, edge) in self.neighbors[vertex].iter() {
+            match self.decided.get(edge) {
+                Some(true) => degree += 1,
+                Some(false) => {}
+                None => undecided.push((*neighbor, *edge)),
+            }
+        }
+
+        let mut degrees = vec![2];
+        if self.allow_isolated {
+            degrees.push(0);
+        }
+        for target in degrees {
+            if degree > target || target - degree > undecided.len() {
+                continue;
+            }
+            for chosen in subsets(undecided.len(), target - degree) {
+                for (index, (_, edge)) in undecided.iter().enumerate() {
+                    self.decided.insert(*edge, chosen.contains(&index));
+                }
+                let feasible = undecided
+                    .iter()
+                    .all(|(neighbor, _)| self.feasible(*neighbor));
+                if feasible && self.odd_final_components() < self.best_value() {
+                    if self.search(depth + 1) {
+                        return true;
+                    }
+                }
+                for (_, edge) in undecided.iter() {
+                    self.decided.remove(edge);
+                }
+            }
+        }
+        false
+    }
+
+    ///
+    /// vertex can still get allowed degree
+    ///
+    fn feasible(&self, vertex: usize) -> bool {
+        let mut degree = 0;
+        let mut undecided = 0;
+        for (_, edge) in self.neighbors[vertex].iter() {
+            match self.decided.get(edge) {
+                Some(true) => degree += 1,
+                Some(false) => {}
+                None => undecided += 1,
+            }
+        }
+        if degree > 2 {
+            return false;
+        }
+        degree + undecided >= 2 || (self.allow_isolated && degree == 0)
+    }
+
+    fn best_value(&self) -> usize {
+        match self.best {
+            Some((value, _)) => value,
+            None => usize::max_value(),
+        }
+    }
+
+    ///
+    /// number of odd components of factor which consist of vertices with all edges decided
+    ///
+    fn odd_final_components(&self) -> usize {
+        let mut visited = vec![false; self.neighbors.len()];
+        let mut odd_components = 0;
+        for start in self.order.iter() {
+            if visited[*start] {
+                continue;
+            }
+            visited[*start] = true;
+            let mut to_visit = vec![*start];
+            let mut size = 0;
+            let mut is_final = true;
+            while let Some(vertex) = to_visit.pop() {
+                size += 1;
+                for (neighbor, edge) in self.neighbors[vertex].iter() {
+                    match self.decided.get(edge) {
+                        Some(true) => {
+                            if !visited[*neighbor] {
+                                visited[*neighbor] = true;
+                                to_visit.push(*neighbor);
+                            }
+                        }
+                        Some(false) => {}
+                        None => is_final = false,
+                    }
+                }
+            }
+            if is_final && size % 2 == 1 {
+                odd_components += 1;
             }
         }
-        odd_cycles_num
+        odd_components
+    }
+
+    ///
+    /// components of factor, cycles are listed in order of traversal
+    ///
+    fn components(&self, factor: &[(usize, usize)]) -> Vec<Vec<usize>> {
+        let mut factor_neighbors = vec![vec![]; self.neighbors.len()];
+        for (from, to) in factor.iter() {
+            factor_neighbors[*from].push(*to);
+            factor_neighbors[*to].push(*from);
+        }
+        let mut visited = vec![false; self.neighbors.len()];
+        let mut components = vec![];
+        let mut vertices = self.order.clone();
+        vertices.sort();
+        for start in vertices {
+            if visited[start] {
+                continue;
+            }
+            let mut component = vec![];
+            let mut previous = None;
+            let mut current = start;
+            while !visited[current] {
+                visited[current] = true;
+                component.push(current);
+                let next = factor_neighbors[current]
+                    .iter()
+                    .find(|neighbor| Some(**neighbor) != previous);
+                match next {
+                    Some(next) => {
+                        previous = Some(current);
+                        current = *next;
+                    }
+                    None => break,
+                }
+            }
+            components.push(component);
+        }
+        components
     }
 }
 
+///
+/// all subsets of 0..size with given number of elements
+///
+fn subsets(size: usize, elements: usize) -> Vec<Vec<usize>> {
+    if elements == 0 {
+        return vec![vec![]];
+    }
+    if elements > size {
+        return vec![];
+    }
+    let mut result = subsets(size - 1, elements);
+    for mut subset in subsets(size - 1, elements - 1) {
+        subset.push(size - 1);
+        result.push(subset);
+    }
+    result
+}
+
+fn is_cubic<G: Graph>(graph: &G) -> bool {
+    graph
+        .vertices()
+        .all(|vertex| graph.edges_of_vertex(vertex.index()).count() == 3)
+}
+
+fn edges_to_json_value(edges: &[UndirectedEdge]) -> serde_json::Value {
+    let mut edges: Vec<(usize, usize)> =
+        edges.iter().map(|edge| (edge.from(), edge.to())).collect();
+    edges.sort();
+    serde_json::to_value(edges).unwrap()
+}
+
 ///
 /// TESTS
 ///
 #[cfg(test)]
 mod tests {
+    use crate::graph::edge::Edge;
+    use crate::graph::graph::{Graph, GraphConstructor};
+    use crate::graph::undirected::multi_graph::graph::MultiGraph;
     use crate::graph::undirected::simple_graph::graph::SimpleGraph;
     use crate::service::io::reader_g6::G6Reader;
-    use crate::service::property::oddness::Oddness;
+    use crate::service::property::oddness::{Oddness, OddnessWitness, WeakOddness};
     use crate::tests::test_data::test_data;
 
+    fn assert_valid_witness<G: Graph>(graph: &G, witness: &OddnessWitness) {
+        assert_eq!(witness.matching.edges.len(), graph.size() / 2);
+        let mut matched = vec![false; graph.size()];
+        for edge in witness.matching.edges.iter() {
+            assert_eq!(graph.has_edge(edge.from(), edge.to()), true);
+            assert_eq!(matched[edge.from()] || matched[edge.to()], false);
+            matched[edge.from()] = true;
+            matched[edge.to()] = true;
+        }
+        for cycle in witness.odd_cycles.iter() {
+            assert_eq!(cycle.len() % 2, 1);
+            for (position, vertex) in cycle.iter().enumerate() {
+                let next = cycle[(position + 1) % cycle.len()];
+                assert_eq!(graph.has_edge(*vertex, next), true);
+            }
+        }
+    }
+
     #[test]
     fn should_have_oddness_zero() {
         let graph: SimpleGraph = G6Reader::read_graph(test_data::NO_SNARK_IN_G6_18).unwrap();
         let oddness = Oddness::of_graph(&graph);
         assert_eq!(oddness, 0);
+
+        let witness = Oddness::witness(&graph).unwrap();
+        assert_valid_witness(&graph, &witness);
+        assert_eq!(WeakOddness::of_graph(&graph), 0);
+    }
+
+    #[test]
+    fn should_not_have_oddness_without_perfect_matching() {
+        // star with three leaves
+        let mut graph = SimpleGraph::with_capacity(4, 3);
+        graph.add_edge(0, 1);
+        graph.add_edge(0, 2);
+        graph.add_edge(0, 3);
+        assert_eq!(Oddness::witness(&graph), None);
     }
 
     #[test]
@@ -68,6 +514,10 @@ mod tests {
             G6Reader::read_graph(test_data::SNARK_IN_G6_26_CRITICAL_1).unwrap();
         let oddness = Oddness::of_graph(&graph);
         assert_eq!(oddness, 2);
+
+        let witness = Oddness::witness(&graph).unwrap();
+        assert_valid_witness(&graph, &witness);
+        assert_eq!(witness.oddness(), 2);
     }
 
     #[test]
@@ -76,5 +526,60 @@ mod tests {
             G6Reader::read_graph(test_data::SNARK_IN_G6_36_STABLE_RES_3).unwrap();
         let oddness = Oddness::of_graph(&graph);
         assert_eq!(oddness, 4);
+
+        let witness = Oddness::witness(&graph).unwrap();
+        assert_valid_witness(&graph, &witness);
+        assert_eq!(
+            witness.to_json_value()["odd-cycles"]
+                .as_array()
+                .unwrap()
+                .len(),
+            4
+        );
+    }
+
+    #[test]
+    fn should_have_weak_oddness_two() {
+        let graph = test_data::get_petersen_graph();
+        let witness = WeakOddness::witness(&graph);
+        assert_eq!(witness.weak_oddness(), 2);
+        for edge in witness.even_factor.iter() {
+            assert_eq!(graph.has_edge(edge.from(), edge.to()), true);
+        }
+
+        // weak oddness of snark is at least 2 and at most its oddness
+        let graph: SimpleGraph =
+            G6Reader::read_graph(test_data::SNARK_IN_G6_26_CRITICAL_1).unwrap();
+        assert_eq!(WeakOddness::of_graph(&graph), 2);
+
+        let graph: SimpleGraph =
+            G6Reader::read_graph(test_data::SNARK_IN_G6_36_STABLE_RES_3).unwrap();
+        let weak_oddness = WeakOddness::of_graph(&graph);
+        // resistance 3 <= weak oddness <= oddness 4
+        assert_eq!(weak_oddness, 4);
+    }
+
+    #[test]
+    fn should_find_even_factor_of_graph_with_parallel_edges() {
+        // necklace of digons 0-1, 2-3 and 4-5
+        let mut graph = MultiGraph::with_capacity(6, 9);
+        for (from, to) in [(0, 1), (2, 3), (4, 5)].iter() {
+            graph.add_edge(*from, *to);
+            graph.add_edge(*from, *to);
+        }
+        graph.add_edge(1, 2);
+        graph.add_edge(3, 4);
+        graph.add_edge(5, 0);
+
+        let witness = WeakOddness::witness(&graph);
+        assert_eq!(witness.weak_oddness(), 0);
+        let mut degrees = vec![0; graph.size()];
+        for edge in witness.even_factor.iter() {
+            assert_eq!(graph.has_edge(edge.from(), edge.to()), true);
+            degrees[edge.from()] += 1;
+            degrees[edge.to()] += 1;
+        }
+        assert_eq!(degrees, vec![2; 6]);
+        assert_eq!(Oddness::of_graph(&graph), 0);
     }
 }
@@ -0,0 +1,250 @@
+use crate::graph::edge::{Edge, EdgeConstructor};
+use crate::graph::graph::Graph;
+use crate::graph::undirected::edge::UndirectedEdge;
+use crate::service::matching::perfect_matchings::{Matching, MatchingGraph};
+use std::collections::HashMap;
+
+///
+/// Perfect matching index - minimal number of perfect matchings covering all edges of graph
+/// (3 for 3-edge-colourable cubic graphs, at least 4 for snarks, at most 5 by Berge conjecture).
+///
+pub struct PerfectMatchingIndex {}
+
+impl PerfectMatchingIndex {
+    ///
+    /// None if some edge is not contained in any perfect matching
+    ///
+    pub fn of_graph<G: Graph>(graph: &G) -> Option<usize> {
+        let cover = MatchingCover::of_graph(graph);
+        if cover.edges.is_empty() {
+            return Some(0);
+        }
+        let mut covered = vec![0; cover.edges.len()];
+        (1..=cover.matchings.len()).find(|size| cover.cover_all(&mut covered, *size))
+    }
+}
+
+///
+/// Fulkerson cover - six perfect matchings (not necessarily distinct) covering each edge exactly
+/// twice. Berge-Fulkerson conjecture states that every bridgeless cubic graph has one.
+///
+pub struct FulkersonCover {}
+
+impl FulkersonCover {
+    pub fn of_graph<G: Graph>(graph: &G) -> Option<Vec<Matching>> {
+        let cover = MatchingCover::of_graph(graph);
+        let mut covered = vec![0; cover.edges.len()];
+        let mut chosen = vec![];
+        if !cover.cover_twice(&mut covered, &mut chosen) {
+            return None;
+        }
+        let matchings = chosen
+            .iter()
+            .map(|matching| cover.to_matching(*matching))
+            .collect();
+        Some(matchings)
+    }
+
+    pub fn exists<G: Graph>(graph: &G) -> bool {
+        Self::of_graph(graph).is_some()
+    }
+}
+
+const FULKERSON_COVER_SIZE: usize = 6;
+
+///
+/// perfect matchings of graph as positions of their edges
+///
+struct MatchingCover {
+    edges: Vec<(usize, usize)>,
+    matchings: Vec<Vec<usize>>,
+    // edge position -> matchings containing edge
+    matchings_of_edge: Vec<Vec<usize>>,
+}
+
+impl MatchingCover {
+    fn of_graph<G: Graph>(graph: &G) -> Self {
+        let mut edges = vec![];
+        let mut positions = HashMap::new();
+        for edge in graph.edges() {
+            positions.insert(Self::key(edge.from(), edge.to()), edges.len());
+            edges.push((edge.from(), edge.to()));
+        }
+
+        let mut matching_graph = MatchingGraph::from_graph(graph);
+        let mut matchings = vec![];
+        let mut matchings_of_edge = vec![vec![]; edges.len()];
+        for matching in matching_graph.perfect_matchings() {
+            let mut matching_edges = vec![];
+            for edge in matching.edges.iter() {
+                let position = positions[&Self::key(edge.from(), edge.to())];
+                matchings_of_edge[position].push(matchings.len());
+                matching_edges.push(position);
+            }
+            matchings.push(matching_edges);
+        }
+        MatchingCover {
+            edges,
+            matchings,
+            matchings_of_edge,
+        }
+    }
+
+    ///
+    /// tries to cover uncovered edges by at most size matchings - one of matchings containing
+    /// first uncovered edge has to be used
+    ///
+    fn cover_all(&self, covered: &mut Vec<usize>, size: usize) -> bool {
+        let uncovered = match (0..self.edges.len()).find(|edge| covered[*edge] == 0) {
+            Some(edge) => edge,
+            None => return true,
+        };
+        if size == 0 {
+            return false;
+        }
+        for matching in self.matchings_of_edge[uncovered].iter() {
+            for edge in self.matchings[*matching].iter() {
+                covered[*edge] += 1;
+            }
+            let result = self.cover_all(covered, size - 1);
+            for edge in self.matchings[*matching].iter() {
+                covered[*edge] -= 1;
+            }
+            if result {
+                return true;
+            }
+        }
+        false
+    }
+
+    ///
+    /// tries to extend chosen matchings to Fulkerson cover - branches on edge covered less than
+    /// twice with fewest matchings which can still be added
+    ///
+    fn cover_twice(&self, covered: &mut Vec<usize>, chosen: &mut Vec<usize>) -> bool {
+        let remaining = FULKERSON_COVER_SIZE - chosen.len();
+        let mut branch_edge = None;
+        let mut fewest_candidates = usize::max_value();
+        for edge in 0..self.edges.len() {
+            if covered[edge] == 2 {
+                continue;
+            }
+            if 2 - covered[edge] > remaining {
+                return false;
+            }
+            let candidates = self.matchings_of_edge[edge]
+                .iter()
+                .filter(|matching| self.can_add(covered, **matching))
+                .count();
+            if candidates < fewest_candidates {
+                fewest_candidates = candidates;
+                branch_edge = Some(edge);
+            }
+        }
+        let branch_edge = match branch_edge {
+            Some(edge) => edge,
+            // all edges covered twice - graph has to be covered by exactly six matchings
+            None => return remaining == 0,
+        };
+
+        for matching in self.matchings_of_edge[branch_edge].iter() {
+            if !self.can_add(covered, *matching) {
+                continue;
+            }
+            for edge in self.matchings[*matching].iter() {
+                covered[*edge] += 1;
+            }
+            chosen.push(*matching);
+            if self.cover_twice(covered, chosen) {
+                return true;
+            }
+            chosen.pop();
+            for edge in self.matchings[*matching].iter() {
+                covered[*edge] -= 1;
+            }
+        }
+        false
+    }
+
+    fn can_add(&self, covered: &[usize], matching: usize) -> bool {
+        self.matchings[matching]
+            .iter()
+            .all(|edge| covered[*edge] < 2)
+    }
+
+    fn key(from: usize, to: usize) -> (usize, usize) {
+        (from.min(to), from.max(to))
+    }
+
+    fn to_matching(&self, matching: usize) -> Matching {
+        let mut result = Matching::new();
+        for edge in self.matchings[matching].iter() {
+            let (from, to) = self.edges[*edge];
+            result.edges.push(UndirectedEdge::new(from, to));
+        }
+        result
+    }
+}
+
+///
+/// TESTS
+///
+#[cfg(test)]
+mod tests {
+    use crate::graph::edge::Edge;
+    use crate::graph::graph::{Graph, GraphConstructor};
+    use crate::graph::undirected::simple_graph::graph::SimpleGraph;
+    use crate::service::io::reader_g6::G6Reader;
+    use crate::service::property::perfect_matching_cover::{FulkersonCover, PerfectMatchingIndex};
+    use crate::tests::test_data::test_data;
+    use std::collections::HashMap;
+
+    #[test]
+    fn should_have_perfect_matching_index_three() {
+        let graph: SimpleGraph = G6Reader::read_graph(test_data::NO_SNARK_IN_G6_18).unwrap();
+        assert_eq!(PerfectMatchingIndex::of_graph(&graph), Some(3));
+    }
+
+    #[test]
+    fn should_have_perfect_matching_index_five() {
+        let graph = test_data::get_petersen_graph();
+        assert_eq!(PerfectMatchingIndex::of_graph(&graph), Some(5));
+    }
+
+    #[test]
+    fn should_not_cover_edge_without_perfect_matching() {
+        // two triangles joined by bridge 2-3 - the only perfect matching is {0-1, 2-3, 4-5}, so
+        // edges 1-2, 2-0, 3-4 and 5-3 are in no perfect matching
+        let mut graph = SimpleGraph::with_capacity(6, 7);
+        graph.add_edge(0, 1);
+        graph.add_edge(1, 2);
+        graph.add_edge(2, 0);
+        graph.add_edge(2, 3);
+        graph.add_edge(3, 4);
+        graph.add_edge(4, 5);
+        graph.add_edge(5, 3);
+        assert_eq!(PerfectMatchingIndex::of_graph(&graph), None);
+        assert_eq!(FulkersonCover::exists(&graph), false);
+    }
+
+    #[test]
+    fn should_find_fulkerson_cover() {
+        for graph in vec![
+            test_data::get_petersen_graph(),
+            G6Reader::read_graph(test_data::SNARK_IN_G6_20).unwrap(),
+            G6Reader::read_graph(test_data::NO_SNARK_IN_G6_18).unwrap(),
+        ] {
+            let cover = FulkersonCover::of_graph(&graph).unwrap();
+            assert_eq!(cover.len(), 6);
+            let mut counts = HashMap::new();
+            for matching in cover.iter() {
+                assert_eq!(matching.edges.len(), graph.size() / 2);
+                for edge in matching.edges.iter() {
+                    *counts.entry((edge.from(), edge.to())).or_insert(0) += 1;
+                }
+            }
+            assert_eq!(counts.len(), graph.edges().count());
+            assert_eq!(counts.values().all(|count| *count == 2), true);
+        }
+    }
+}
@@ -0,0 +1,374 @@
+use crate::graph::edge::Edge;
+use crate::graph::graph::Graph;
+use crate::service::symmetry::partition;
+use crate::service::symmetry::partition::Partition;
+use std::collections::HashMap;
+
+///
+/// Automorphism group of graph computed by individualization-refinement search along
+/// stabilizer chain. Vertices of graph are expected to be 0..graph.size().
+///
+/// Automorphisms are represented as permutations - vector in which position i holds image of
+/// vertex i.
+///
+#[derive(Debug, Clone)]
+pub struct AutomorphismGroup {
+    generators: Vec<Vec<usize>>,
+    order: u128,
+    vertex_orbits: Vec<Vec<usize>>,
+    edge_orbits: Vec<Vec<(usize, usize)>>,
+}
+
+impl AutomorphismGroup {
+    pub fn of_graph<G: Graph>(graph: &G) -> Self {
+        let neighbors = partition::neighbors_of_vertices(graph);
+        let search = AutomorphismSearch {
+            neighbors: &neighbors,
+        };
+
+        // base and partitions along stabilizer chain
+        let mut partitions = vec![Partition::unit(graph).refine(&neighbors)];
+        let mut base = vec![];
+        while let Some(cell) = partitions.last().unwrap().first_non_singleton() {
+            let last = partitions.last().unwrap();
+            let vertex = last.cells()[cell][0];
+            base.push(vertex);
+            partitions.push(last.individualize(vertex).refine(&neighbors));
+        }
+
+        // from the deepest level - generators of stabilizer of base[0..level] consist of
+        // generators found on this and deeper levels
+        let mut generators_by_level: Vec<Vec<Vec<usize>>> = vec![vec![]; base.len()];
+        let mut order: u128 = 1;
+        for level in (0..base.len()).rev() {
+            let mut generators: Vec<Vec<usize>> = generators_by_level[level..]
+                .iter()
+                .flatten()
+                .cloned()
+                .collect();
+            let cell_index = partitions[level].cell_of(base[level]).unwrap();
+            let candidates = partitions[level].cells()[cell_index].clone();
+            let mut orbit = orbit_of(base[level], &generators, neighbors.len());
+            for candidate in candidates {
+                if orbit[candidate] {
+                    continue;
+                }
+                let image = partitions[level]
+                    .individualize(candidate)
+                    .refine(&neighbors);
+                if let Some(automorphism) = search.find(&partitions[level + 1], &image) {
+                    generators.push(automorphism.clone());
+                    generators_by_level[level].push(automorphism);
+                    orbit = orbit_of(base[level], &generators, neighbors.len());
+                }
+            }
+            let orbit_size = orbit.iter().filter(|in_orbit| **in_orbit).count();
+            order = order.saturating_mul(orbit_size as u128);
+        }
+
+        let generators: Vec<Vec<usize>> = generators_by_level.into_iter().flatten().collect();
+        let vertex_orbits = Self::vertex_orbits_of(&generators, neighbors.len());
+        let edge_orbits = Self::edge_orbits_of(graph, &generators);
+        AutomorphismGroup {
+            generators,
+            order,
+            vertex_orbits,
+            edge_orbits,
+        }
+    }
+
+    pub fn generators(&self) -> &Vec<Vec<usize>> {
+        &self.generators
+    }
+
+    ///
+    /// order of group (saturated at u128::MAX)
+    ///
+    pub fn order(&self) -> u128 {
+        self.order
+    }
+
+    pub fn vertex_orbits(&self) -> &Vec<Vec<usize>> {
+        &self.vertex_orbits
+    }
+
+    pub fn edge_orbits(&self) -> &Vec<Vec<(usize, usize)>> {
+        &self.edge_orbits
+    }
+
+    ///
+    /// smallest vertex of each vertex orbit
+    ///
+    pub fn vertex_orbit_representatives(&self) -> Vec<usize> {
+        self.vertex_orbits.iter().map(|orbit| orbit[0]).collect()
+    }
+
+    ///
+    /// smallest edge of each edge orbit
+    ///
+    pub fn edge_orbit_representatives(&self) -> Vec<(usize, usize)> {
+        self.edge_orbits.iter().map(|orbit| orbit[0]).collect()
+    }
+
+    fn vertex_orbits_of(generators: &[Vec<usize>], size: usize) -> Vec<Vec<usize>> {
+        let mut components = UnionFind::new(size);
+        for generator in generators.iter() {
+            for (vertex, image) in generator.iter().enumerate() {
+                components.union(vertex, *image);
+            }
+        }
+        components.classes(|index| index)
+    }
+
+    fn edge_orbits_of<G: Graph>(graph: &G, generators: &[Vec<usize>]) -> Vec<Vec<(usize, usize)>> {
+        let mut edges = vec![];
+        let mut positions = HashMap::new();
+        for edge in graph.edges() {
+            if positions.contains_key(&(edge.from(), edge.to())) {
+                continue;
+            }
+            positions.insert((edge.from(), edge.to()), edges.len());
+            edges.push((edge.from(), edge.to()));
+        }
+        let mut components = UnionFind::new(edges.len());
+        for generator in generators.iter() {
+            for (position, edge) in edges.iter().enumerate() {
+                let image = normalized(generator[edge.0], generator[edge.1]);
+                components.union(position, positions[&image]);
+            }
+        }
+        components.classes(|position| edges[position])
+    }
+}
+
+fn normalized(from: usize, to: usize) -> (usize, usize) {
+    if from > to {
+        (to, from)
+    } else {
+        (from, to)
+    }
+}
+
+///
+/// vertices reachable from vertex by generators
+///
+fn orbit_of(vertex: usize, generators: &[Vec<usize>], size: usize) -> Vec<bool> {
+    let mut orbit = vec![false; size];
+    orbit[vertex] = true;
+    let mut to_visit = vec![vertex];
+    while let Some(current) = to_visit.pop() {
+        for generator in generators.iter() {
+            let image = generator[current];
+            if !orbit[image] {
+                orbit[image] = true;
+                to_visit.push(image);
+            }
+        }
+    }
+    orbit
+}
+
+struct AutomorphismSearch<'a> {
+    neighbors: &'a Vec<Vec<usize>>,
+}
+
+impl<'a> AutomorphismSearch<'a> {
+    ///
+    /// searches for automorphism mapping cells of first partition to corresponding cells of
+    /// second partition
+    ///
+    fn find(&self, first: &Partition, second: &Partition) -> Option<Vec<usize>> {
+        if !first.is_compatible(self.neighbors, second, self.neighbors) {
+            return None;
+        }
+        if first.is_discrete() {
+            let mut permutation = vec![0; self.neighbors.len()];
+            for (cell, image_cell) in first.cells().iter().zip(second.cells().iter()) {
+                permutation[cell[0]] = image_cell[0];
+            }
+            if self.is_automorphism(&permutation) {
+                return Some(permutation);
+            }
+            return None;
+        }
+
+        let cell = first.first_non_singleton().unwrap();
+        let vertex = first.cells()[cell][0];
+        let first = first.individualize(vertex).refine(self.neighbors);
+        for image in second.cells()[cell].iter() {
+            let second = second.individualize(*image).refine(self.neighbors);
+            let automorphism = self.find(&first, &second);
+            if automorphism.is_some() {
+                return automorphism;
+            }
+        }
+        None
+    }
+
+    fn is_automorphism(&self, permutation: &[usize]) -> bool {
+        for (vertex, neighbors) in self.neighbors.iter().enumerate() {
+            let mut images: Vec<usize> = neighbors
+                .iter()
+                .map(|neighbor| permutation[*neighbor])
+                .collect();
+            let mut image_neighbors = self.neighbors[permutation[vertex]].clone();
+            images.sort_unstable();
+            image_neighbors.sort_unstable();
+            if images != image_neighbors {
+                return false;
+            }
+        }
+        true
+    }
+}
+
+struct UnionFind {
+    parents: Vec<usize>,
+}
+
+impl UnionFind {
+    fn new(size: usize) -> Self {
+        UnionFind {
+            parents: (0..size).collect(),
+        }
+    }
+
+    fn find(&mut self, element: usize) -> usize {
+        let mut root = element;
+        while self.parents[root] != root {
+            root = self.parents[root];
+        }
+        let mut current = element;
+        while self.parents[current] != root {
+            let next = self.parents[current];
+            self.parents[current] = root;
+            current = next;
+        }
+        root
+    }
+
+    fn union(&mut self, first: usize, second: usize) {
+        let first = self.find(first);
+        let second = self.find(second);
+        // smaller element is root - classes are represented by its smallest element
+        if first < second {
+            self.parents[second] = first;
+        } else if second < first {
+            self.parents[first] = second;
+        }
+    }
+
+    ///
+    /// classes sorted by smallest element, mapped by given function
+    ///
+    fn classes<T, F: Fn(usize) -> T>(&mut self, map: F) -> Vec<Vec<T>> {
+        let mut classes: Vec<Vec<T>> = vec![];
+        let mut class_of_root = HashMap::new();
+        for element in 0..self.parents.len() {
+            let root = self.find(element);
+            let class = *class_of_root.entry(root).or_insert_with(|| {
+                classes.push(vec![]);
+                classes.len() - 1
+            });
+            classes[class].push(map(element));
+        }
+        classes
+    }
+}
+
+///
+/// TESTS
+///
+#[cfg(test)]
+mod tests {
+    use crate::graph::graph::{Graph, GraphConstructor};
+    use crate::graph::undirected::simple_graph::graph::SimpleGraph;
+    use crate::service::io::reader_g6::G6Reader;
+    use crate::service::symmetry::automorphisms::AutomorphismGroup;
+    use crate::tests::test_data::test_data;
+
+    #[test]
+    fn should_compute_automorphisms_of_petersen_graph() {
+        let graph = test_data::get_petersen_graph();
+        let group = AutomorphismGroup::of_graph(&graph);
+        assert_eq!(group.order(), 120);
+        assert_eq!(group.vertex_orbits().len(), 1);
+        assert_eq!(group.edge_orbits().len(), 1);
+        assert_eq!(group.edge_orbits()[0].len(), 15);
+    }
+
+    #[test]
+    fn should_compute_automorphisms_of_cube_and_k4() {
+        let mut cube = SimpleGraph::with_capacity(8, 12);
+        for vertex in 0..8 {
+            for bit in [1, 2, 4].iter() {
+                cube.add_edge(vertex, vertex ^ bit);
+            }
+        }
+        assert_eq!(AutomorphismGroup::of_graph(&cube).order(), 48);
+
+        let mut k4 = SimpleGraph::with_capacity(4, 6);
+        for from in 0..4 {
+            for to in (from + 1)..4 {
+                k4.add_edge(from, to);
+            }
+        }
+        assert_eq!(AutomorphismGroup::of_graph(&k4).order(), 24);
+    }
+
+    #[test]
+    fn should_compute_automorphisms_of_flower_snark() {
+        // flower snark J5 - vertices 4i, 4i + 1, 4i + 2, 4i + 3 form i-th claw
+        let k = 5;
+        let mut flower = SimpleGraph::with_capacity(4 * k, 6 * k);
+        for i in 0..k {
+            let next = (i + 1) % k;
+            flower.add_edge(4 * i, 4 * i + 1);
+            flower.add_edge(4 * i, 4 * i + 2);
+            flower.add_edge(4 * i, 4 * i + 3);
+            flower.add_edge(4 * i + 1, 4 * next + 1);
+            if next == 0 {
+                flower.add_edge(4 * i + 2, 4 * next + 3);
+                flower.add_edge(4 * i + 3, 4 * next + 2);
+            } else {
+                flower.add_edge(4 * i + 2, 4 * next + 2);
+                flower.add_edge(4 * i + 3, 4 * next + 3);
+            }
+        }
+        let group = AutomorphismGroup::of_graph(&flower);
+        assert_eq!(group.order(), 20);
+        assert_eq!(group.vertex_orbits().len(), 3);
+    }
+
+    #[test]
+    fn should_compute_orbits_of_path() {
+        let mut path = SimpleGraph::with_capacity(4, 3);
+        path.add_edge(0, 1);
+        path.add_edge(1, 2);
+        path.add_edge(2, 3);
+        let group = AutomorphismGroup::of_graph(&path);
+        assert_eq!(group.order(), 2);
+        assert_eq!(group.vertex_orbits(), &vec![vec![0, 3], vec![1, 2]]);
+        assert_eq!(
+            group.edge_orbits(),
+            &vec![vec![(0, 1), (2, 3)], vec![(1, 2)]]
+        );
+        assert_eq!(group.vertex_orbit_representatives(), vec![0, 1]);
+    }
+
+    #[test]
+    fn should_map_graph_onto_itself() {
+        let graph: SimpleGraph = G6Reader::read_graph(test_data::NO_SNARK_IN_G6_112).unwrap();
+        let group = AutomorphismGroup::of_graph(&graph);
+        for generator in group.generators() {
+            for from in 0..graph.size() {
+                for to in 0..graph.size() {
+                    assert_eq!(
+                        graph.has_edge(from, to),
+                        graph.has_edge(generator[from], generator[to])
+                    );
+                }
+            }
+        }
+    }
+}
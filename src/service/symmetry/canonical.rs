@@ -0,0 +1,329 @@
+use crate::graph::graph::{Graph, GraphConstructor};
+use crate::graph::undirected::simple_graph::graph::SimpleGraph;
+use crate::service::io::writer_g6::G6Writer;
+use crate::service::io::writer_s6::S6Writer;
+use crate::service::symmetry::automorphisms::AutomorphismGroup;
+use crate::service::symmetry::partition;
+use crate::service::symmetry::partition::Partition;
+
+///
+/// Canonical labelling of graph - isomorphic graphs relabelled by their canonical labellings
+/// are identical. Vertices of graph are expected to be 0..graph.size().
+///
+/// Returned vector holds new label of each vertex.
+///
+pub fn canonical_labelling<G: Graph>(graph: &G) -> Vec<usize> {
+    let neighbors = partition::neighbors_of_vertices(graph);
+    let mut search = CanonicalSearch {
+        neighbors: &neighbors,
+        generators: AutomorphismGroup::of_graph(graph).generators().clone(),
+        best: None,
+    };
+    let root = Partition::unit(graph).refine(&neighbors);
+    search.search(&root, &mut vec![]);
+
+    match search.best {
+        Some(best) => best.labelling,
+        None => vec![],
+    }
+}
+
+///
+/// graph relabelled by its canonical labelling - simple graph, so parallel edges of multigraph
+/// are merged (use canonical_string to tell such multigraphs apart)
+///
+pub fn canonical_form<G: Graph>(graph: &G) -> SimpleGraph {
+    simple_graph(graph.size(), &canonical_edges(graph))
+}
+
+///
+/// same string for all isomorphic graphs (multigraphs) - canonical graph6 string, for
+/// multigraph followed by ':' and multiple edges of canonical form as from-to*multiplicity
+///
+pub fn canonical_string<G: Graph>(graph: &G) -> String {
+    let edges = canonical_edges(graph);
+    let g6 = G6Writer::graph_to_g6_string(&simple_graph(graph.size(), &edges));
+    let mut multiple_edges = vec![];
+    let mut index = 0;
+    while index < edges.len() {
+        let multiplicity = edges[index..]
+            .iter()
+            .take_while(|edge| **edge == edges[index])
+            .count();
+        let (from, to) = edges[index];
+        if multiplicity > 1 || from == to {
+            multiple_edges.push(format!("{}-{}*{}", from, to, multiplicity));
+        }
+        index += multiplicity;
+    }
+    if multiple_edges.is_empty() {
+        return g6;
+    }
+    format!("{}:{}", g6, multiple_edges.join(","))
+}
+
+///
+/// same string for all isomorphic graphs
+///
+pub fn canonical_g6_string<G: Graph>(graph: &G) -> String {
+    G6Writer::graph_to_g6_string(&canonical_form(graph))
+}
+
+///
+/// same string for all isomorphic graphs
+///
+pub fn canonical_s6_string<G: Graph>(graph: &G) -> String {
+    S6Writer::graph_to_s6_string(&canonical_form(graph))
+}
+
+///
+/// sorted edges (with repetitions) of graph relabelled by its canonical labelling
+///
+fn canonical_edges<G: Graph>(graph: &G) -> Vec<(usize, usize)> {
+    let labelling = canonical_labelling(graph);
+    let mut edges = vec![];
+    for (vertex, neighbors) in partition::neighbors_of_vertices(graph).iter().enumerate() {
+        for neighbor in neighbors.iter() {
+            if labelling[vertex] <= labelling[*neighbor] {
+                edges.push((labelling[vertex], labelling[*neighbor]));
+            }
+        }
+    }
+    edges.sort_unstable();
+    edges
+}
+
+fn simple_graph(size: usize, edges: &[(usize, usize)]) -> SimpleGraph {
+    let mut graph = SimpleGraph::with_vertices_capacity(size);
+    for vertex in 0..size {
+        graph.add_vertex_with_index(vertex);
+    }
+    for (from, to) in edges.iter() {
+        graph.add_edge(*from, *to);
+    }
+    graph
+}
+
+struct Leaf {
+    labelling: Vec<usize>,
+    // sorted edges of relabelled graph
+    certificate: Vec<(usize, usize)>,
+}
+
+///
+/// Individualization-refinement search tree - leaf with smallest certificate determines
+/// canonical labelling. Subtrees equivalent under known automorphisms are skipped.
+///
+struct CanonicalSearch<'a> {
+    neighbors: &'a Vec<Vec<usize>>,
+    generators: Vec<Vec<usize>>,
+    best: Option<Leaf>,
+}
+
+impl<'a> CanonicalSearch<'a> {
+    fn search(&mut self, partition: &Partition, path: &mut Vec<usize>) {
+        let cell = match partition.first_non_singleton() {
+            Some(cell) => cell,
+            None => {
+                self.visit_leaf(partition);
+                return;
+            }
+        };
+
+        let mut explored: Vec<usize> = vec![];
+        for vertex in partition.cells()[cell].clone() {
+            if self.is_equivalent_to_explored(vertex, &explored, path) {
+                continue;
+            }
+            let child = partition.individualize(vertex).refine(self.neighbors);
+            path.push(vertex);
+            self.search(&child, path);
+            path.pop();
+            explored.push(vertex);
+        }
+    }
+
+    fn visit_leaf(&mut self, partition: &Partition) {
+        let mut labelling = vec![0; self.neighbors.len()];
+        for (label, cell) in partition.cells().iter().enumerate() {
+            labelling[cell[0]] = label;
+        }
+        let mut certificate = vec![];
+        for (vertex, neighbors) in self.neighbors.iter().enumerate() {
+            for neighbor in neighbors.iter() {
+                if labelling[vertex] <= labelling[*neighbor] {
+                    certificate.push((labelling[vertex], labelling[*neighbor]));
+                }
+            }
+        }
+        certificate.sort_unstable();
+
+        let leaf = Leaf {
+            labelling,
+            certificate,
+        };
+        match &self.best {
+            None => self.best = Some(leaf),
+            Some(best) => {
+                if leaf.certificate < best.certificate {
+                    self.best = Some(leaf);
+                } else if leaf.certificate == best.certificate {
+                    // both labellings give same graph - their composition is automorphism
+                    let mut inverse = vec![0; best.labelling.len()];
+                    for (vertex, label) in best.labelling.iter().enumerate() {
+                        inverse[*label] = vertex;
+                    }
+                    let automorphism = leaf.labelling.iter().map(|label| inverse[*label]).collect();
+                    self.generators.push(automorphism);
+                }
+            }
+        }
+    }
+
+    ///
+    /// vertex is in orbit of some explored vertex under automorphisms fixing path pointwise
+    ///
+    fn is_equivalent_to_explored(&self, vertex: usize, explored: &[usize], path: &[usize]) -> bool {
+        if explored.is_empty() {
+            return false;
+        }
+        let generators: Vec<&Vec<usize>> = self
+            .generators
+            .iter()
+            .filter(|generator| path.iter().all(|fixed| generator[*fixed] == *fixed))
+            .collect();
+        let mut orbit = vec![false; self.neighbors.len()];
+        orbit[vertex] = true;
+        let mut to_visit = vec![vertex];
+        while let Some(current) = to_visit.pop() {
+            if explored.contains(&current) {
+                return true;
+            }
+            for generator in generators.iter() {
+                let image = generator[current];
+                if !orbit[image] {
+                    orbit[image] = true;
+                    to_visit.push(image);
+                }
+            }
+        }
+        false
+    }
+}
+
+///
+/// TESTS
+///
+#[cfg(test)]
+mod tests {
+    use crate::graph::graph::{Graph, GraphConstructor};
+    use crate::graph::undirected::multi_graph::graph::MultiGraph;
+    use crate::graph::undirected::simple_graph::graph::SimpleGraph;
+    use crate::service::io::reader_g6::G6Reader;
+    use crate::service::io::writer_g6::G6Writer;
+    use crate::service::symmetry::canonical;
+    use crate::tests::test_data::test_data;
+
+    fn relabelled(graph: &SimpleGraph, shift: usize) -> SimpleGraph {
+        let size = graph.size();
+        let mut result = SimpleGraph::with_vertices_capacity(size);
+        for vertex in 0..size {
+            result.add_vertex_with_index(vertex);
+        }
+        for from in 0..size {
+            for to in (from + 1)..size {
+                if graph.has_edge(from, to) {
+                    // permutation of vertices - multiplication by shift coprime with size
+                    result.add_edge((from * shift + 1) % size, (to * shift + 1) % size);
+                }
+            }
+        }
+        result
+    }
+
+    #[test]
+    fn should_give_same_string_for_isomorphic_graphs() {
+        for graph_g6 in [
+            test_data::SNARK_IN_G6_10_PETERSEN,
+            test_data::SNARK_IN_G6_20,
+            test_data::SNARK_IN_G6_26_CRITICAL_1,
+            test_data::NO_SNARK_IN_G6_18,
+        ]
+        .iter()
+        {
+            let graph: SimpleGraph = G6Reader::read_graph(graph_g6).unwrap();
+            let permuted = relabelled(&graph, 7);
+            assert_ne!(
+                G6Writer::graph_to_g6_string(&graph),
+                G6Writer::graph_to_g6_string(&permuted)
+            );
+            assert_eq!(
+                canonical::canonical_g6_string(&graph),
+                canonical::canonical_g6_string(&permuted)
+            );
+            assert_eq!(
+                canonical::canonical_s6_string(&graph),
+                canonical::canonical_s6_string(&permuted)
+            );
+        }
+    }
+
+    #[test]
+    fn should_give_different_strings_for_non_isomorphic_graphs() {
+        let first: SimpleGraph =
+            G6Reader::read_graph(test_data::SNARK_IN_G6_26_CRITICAL_1).unwrap();
+        let second: SimpleGraph =
+            G6Reader::read_graph(test_data::SNARK_IN_G6_26_CRITICAL_2).unwrap();
+        assert_ne!(
+            canonical::canonical_g6_string(&first),
+            canonical::canonical_g6_string(&second)
+        );
+    }
+
+    #[test]
+    fn should_distinguish_multigraphs_with_same_underlying_graph() {
+        // path 0-1-2-3 with doubled end edge, doubled middle edge and other doubled end edge
+        let path_with_double_edge = |from: usize, to: usize| {
+            let mut graph = MultiGraph::with_vertices_capacity(4);
+            for vertex in 0..3 {
+                graph.add_edge(vertex, vertex + 1);
+            }
+            graph.add_edge(from, to);
+            graph
+        };
+        let end = path_with_double_edge(0, 1);
+        let middle = path_with_double_edge(1, 2);
+        let other_end = path_with_double_edge(2, 3);
+
+        assert_ne!(
+            canonical::canonical_string(&end),
+            canonical::canonical_string(&middle)
+        );
+        assert_eq!(
+            canonical::canonical_string(&end),
+            canonical::canonical_string(&other_end)
+        );
+
+        let simple: SimpleGraph = G6Reader::read_graph(test_data::SNARK_IN_G6_36).unwrap();
+        assert_eq!(
+            canonical::canonical_string(&simple),
+            canonical::canonical_g6_string(&simple)
+        );
+    }
+
+    #[test]
+    fn should_relabel_to_isomorphic_graph() {
+        let graph: SimpleGraph = G6Reader::read_graph(test_data::SNARK_IN_G6_36).unwrap();
+        let labelling = canonical::canonical_labelling(&graph);
+        let canonical = canonical::canonical_form(&graph);
+        assert_eq!(canonical.size(), graph.size());
+        for from in 0..graph.size() {
+            for to in 0..graph.size() {
+                assert_eq!(
+                    graph.has_edge(from, to),
+                    canonical.has_edge(labelling[from], labelling[to])
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,3 @@
+pub mod automorphisms;
+pub mod canonical;
+pub mod partition;
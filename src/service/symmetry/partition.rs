@@ -0,0 +1,177 @@
+use crate::graph::edge::Edge;
+use crate::graph::graph::Graph;
+
+///
+/// Ordered partition of vertices of graph used for individualization-refinement. Refinement is
+/// invariant under relabelling of graph - isomorphism maps refined partition of one graph to
+/// refined partition of the other one.
+///
+#[derive(Debug, Clone, PartialEq)]
+pub struct Partition {
+    cells: Vec<Vec<usize>>,
+}
+
+impl Partition {
+    ///
+    /// partition with single cell containing vertices 0..graph.size()
+    ///
+    pub fn unit<G: Graph>(graph: &G) -> Self {
+        let cell: Vec<usize> = (0..graph.size()).collect();
+        let cells = if cell.is_empty() { vec![] } else { vec![cell] };
+        Partition { cells }
+    }
+
+    pub fn cells(&self) -> &Vec<Vec<usize>> {
+        &self.cells
+    }
+
+    pub fn is_discrete(&self) -> bool {
+        self.cells.iter().all(|cell| cell.len() == 1)
+    }
+
+    ///
+    /// index of first cell with more than one vertex
+    ///
+    pub fn first_non_singleton(&self) -> Option<usize> {
+        self.cells.iter().position(|cell| cell.len() > 1)
+    }
+
+    pub fn cell_of(&self, vertex: usize) -> Option<usize> {
+        self.cells.iter().position(|cell| cell.contains(&vertex))
+    }
+
+    ///
+    /// splits vertex from its cell - singleton cell [vertex] is placed before rest of the cell
+    ///
+    pub fn individualize(&self, vertex: usize) -> Self {
+        let mut cells = Vec::with_capacity(self.cells.len() + 1);
+        for cell in self.cells.iter() {
+            if cell.len() > 1 && cell.contains(&vertex) {
+                cells.push(vec![vertex]);
+                cells.push(cell.iter().copied().filter(|v| *v != vertex).collect());
+            } else {
+                cells.push(cell.clone());
+            }
+        }
+        Partition { cells }
+    }
+
+    ///
+    /// splits cells by number of neighbors in each cell until partition is equitable
+    ///
+    pub fn refine(&self, neighbors: &[Vec<usize>]) -> Self {
+        let mut cells = self.cells.clone();
+        loop {
+            let mut cell_of = vec![0; neighbors.len()];
+            for (index, cell) in cells.iter().enumerate() {
+                for vertex in cell.iter() {
+                    cell_of[*vertex] = index;
+                }
+            }
+
+            let mut refined = Vec::with_capacity(cells.len());
+            for cell in cells.iter() {
+                if cell.len() == 1 {
+                    refined.push(cell.clone());
+                    continue;
+                }
+                let mut signatures: Vec<(Vec<usize>, usize)> = cell
+                    .iter()
+                    .map(|vertex| (Self::signature(*vertex, neighbors, &cell_of), *vertex))
+                    .collect();
+                // stable sort keeps order of vertices inside of new cells
+                signatures.sort_by(|first, second| first.0.cmp(&second.0));
+                let mut new_cell = vec![signatures[0].1];
+                for pair in signatures.windows(2) {
+                    if pair[0].0 != pair[1].0 {
+                        refined.push(new_cell);
+                        new_cell = vec![];
+                    }
+                    new_cell.push(pair[1].1);
+                }
+                refined.push(new_cell);
+            }
+
+            if refined.len() == cells.len() {
+                return Partition { cells: refined };
+            }
+            cells = refined;
+        }
+    }
+
+    ///
+    /// sorted indices of cells of neighbors of vertex (with repetitions)
+    ///
+    fn signature(vertex: usize, neighbors: &[Vec<usize>], cell_of: &[usize]) -> Vec<usize> {
+        let mut signature: Vec<usize> = neighbors[vertex]
+            .iter()
+            .map(|neighbor| cell_of[*neighbor])
+            .collect();
+        signature.sort_unstable();
+        signature
+    }
+
+    ///
+    /// checks if partitions of two graphs (or of one graph) can be mapped one to another -
+    /// cells have same sizes and vertices of corresponding cells have same number of neighbors
+    /// in corresponding cells (partitions are expected to be equitable)
+    ///
+    pub fn is_compatible(
+        &self,
+        neighbors: &[Vec<usize>],
+        other: &Partition,
+        other_neighbors: &[Vec<usize>],
+    ) -> bool {
+        if self.cells.len() != other.cells.len() {
+            return false;
+        }
+        for (cell, other_cell) in self.cells.iter().zip(other.cells.iter()) {
+            if cell.len() != other_cell.len() {
+                return false;
+            }
+        }
+        let cell_of = self.cell_indices(neighbors.len());
+        let other_cell_of = other.cell_indices(other_neighbors.len());
+        for (cell, other_cell) in self.cells.iter().zip(other.cells.iter()) {
+            if Self::signature(cell[0], neighbors, &cell_of)
+                != Self::signature(other_cell[0], other_neighbors, &other_cell_of)
+            {
+                return false;
+            }
+        }
+        true
+    }
+
+    fn cell_indices(&self, size: usize) -> Vec<usize> {
+        let mut cell_of = vec![0; size];
+        for (index, cell) in self.cells.iter().enumerate() {
+            for vertex in cell.iter() {
+                cell_of[*vertex] = index;
+            }
+        }
+        cell_of
+    }
+}
+
+///
+/// sorted neighbors of vertices 0..graph.size() - neighbor joined by parallel edges is repeated,
+/// so refinement and automorphisms respect multiplicity of edges
+///
+pub fn neighbors_of_vertices<G: Graph>(graph: &G) -> Vec<Vec<usize>> {
+    (0..graph.size())
+        .map(|vertex| {
+            let mut neighbors: Vec<usize> = graph
+                .edges_of_vertex(vertex)
+                .map(|edge| {
+                    if edge.from() == vertex {
+                        edge.to()
+                    } else {
+                        edge.from()
+                    }
+                })
+                .collect();
+            neighbors.sort_unstable();
+            neighbors
+        })
+        .collect()
+}